@@ -0,0 +1,392 @@
+//! NDJSON driver for the oveo optimizer.
+//!
+//! Each input line is a JSON object `{ source, moduleType, fileName, map }` and
+//! each output line is either `{ code, map, stats, warnings }` or `{ error }`.
+//! Output line N always corresponds to input line N.
+use std::{
+    io::{self, BufRead, Write},
+    process::ExitCode,
+};
+
+use oveo::{
+    GlobalCategory, OptimizerOptions, PropertyMap, PropertyNaming, SourceOptions,
+    externs::ExternMap, finalize_chunk, finalize_module, optimize_chunk, optimize_module,
+    optimize_module_single_phase,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Request {
+    source: String,
+    #[serde(default)]
+    module_type: Option<String>,
+    #[serde(default)]
+    file_name: Option<String>,
+    /// Source map of the input source text.
+    #[serde(default)]
+    map: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+enum Response {
+    Output {
+        code: String,
+        map: String,
+        stats: Stats,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        warnings: Vec<String>,
+    },
+    Error {
+        error: String,
+    },
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Stats {
+    input_bytes: usize,
+    output_bytes: usize,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    used_externs: Vec<(String, String)>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    hoist_log: Vec<String>,
+    #[serde(skip_serializing_if = "is_zero")]
+    duplicates_collapsed: u32,
+    #[serde(skip_serializing_if = "is_zero")]
+    globals_hoisted: u32,
+    #[serde(skip_serializing_if = "is_zero")]
+    properties_renamed: u32,
+}
+
+fn is_zero(v: &u32) -> bool {
+    *v == 0
+}
+
+#[derive(Default)]
+struct Args {
+    options: OptimizerOptions,
+    externs: Vec<String>,
+    pattern: Option<String>,
+    property_map: Option<String>,
+    single_phase: bool,
+    deterministic: bool,
+    reserved: Vec<String>,
+    keep_names: Vec<String>,
+    naming: PropertyNaming,
+    max_mangled_len: Option<usize>,
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            let _ = writeln!(io::stderr(), "oveo-ndjson: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run() -> Result<(), String> {
+    let args = parse_args(std::env::args().skip(1))?;
+
+    let mut extern_map = ExternMap::new();
+    for path in &args.externs {
+        let data = std::fs::read(path).map_err(|err| format!("{path}: {err}"))?;
+        extern_map.merge_from_json(&data).map_err(|err| format!("{path}: {err}"))?;
+    }
+
+    let pattern = match &args.pattern {
+        Some(p) => Some(regex::Regex::new(p).map_err(|err| err.to_string())?),
+        None => None,
+    };
+    let mut property_map = PropertyMap::new(pattern)
+        .with_deterministic(args.deterministic)
+        .with_reserved(&args.reserved)
+        .with_keep_names(&args.keep_names)
+        .with_naming(args.naming)
+        .with_max_mangled_len(args.max_mangled_len);
+    if let Some(path) = &args.property_map {
+        // Missing property map is fine when new names are generated dynamically.
+        match std::fs::read(path) {
+            Ok(data) => property_map.import(&data).map_err(|err| format!("{path}: {err}"))?,
+            Err(err) if args.pattern.is_none() => return Err(format!("{path}: {err}")),
+            Err(_) => {}
+        }
+    }
+
+    let stdin = io::stdin().lock();
+    let mut stdout = io::BufWriter::new(io::stdout().lock());
+    if args.deterministic {
+        // New property names are assigned when all chunks are transformed.
+        let responses = stdin
+            .lines()
+            .map(|line| {
+                line.map(|line| match parse_request(&line) {
+                    Ok(request) => (
+                        Placeholders::from_request(&request, &args),
+                        transform_request(&request, &args, &extern_map, &property_map),
+                    ),
+                    Err(error) => (Placeholders::None, Response::Error { error }),
+                })
+            })
+            .collect::<io::Result<Vec<_>>>()
+            .map_err(|err| err.to_string())?;
+        property_map.finalize();
+        for (placeholders, response) in responses {
+            write_response(&mut stdout, &finalize_response(response, placeholders, &property_map))
+                .map_err(|err| err.to_string())?;
+        }
+    } else {
+        transform_lines(stdin, &mut stdout, &args, &extern_map, &property_map)
+            .map_err(|err| err.to_string())?;
+    }
+    stdout.flush().map_err(|err| err.to_string())?;
+
+    if let Some(path) = &args.property_map {
+        if property_map.is_dirty() {
+            std::fs::write(path, property_map.export()).map_err(|err| format!("{path}: {err}"))?;
+        }
+    }
+
+    Ok(())
+}
+
+fn transform_lines<R: BufRead, W: Write>(
+    input: R,
+    output: &mut W,
+    args: &Args,
+    extern_map: &ExternMap,
+    property_map: &PropertyMap,
+) -> io::Result<()> {
+    for line in input.lines() {
+        let response = transform_line(&line?, args, extern_map, property_map);
+        write_response(output, &response)?;
+    }
+    Ok(())
+}
+
+fn write_response<W: Write>(output: &mut W, response: &Response) -> io::Result<()> {
+    serde_json::to_writer(&mut *output, response)?;
+    output.write_all(b"\n")
+}
+
+/// Outputs that contain property name placeholders in deterministic mode.
+enum Placeholders {
+    None,
+    Chunk,
+    /// Single-phase module output with a module type.
+    Module(String),
+}
+
+impl Placeholders {
+    fn from_request(request: &Request, args: &Args) -> Self {
+        match &request.module_type {
+            None => Self::Chunk,
+            Some(module_type) if args.single_phase => Self::Module(module_type.clone()),
+            // Properties aren't renamed in the module phase.
+            Some(_) => Self::None,
+        }
+    }
+}
+
+fn finalize_response(
+    response: Response,
+    placeholders: Placeholders,
+    property_map: &PropertyMap,
+) -> Response {
+    match response {
+        Response::Output { code, map, stats, warnings } => {
+            let output = match placeholders {
+                Placeholders::None => {
+                    return Response::Output { code, map, stats, warnings };
+                }
+                Placeholders::Chunk => finalize_chunk(&code, &map, property_map),
+                Placeholders::Module(module_type) => {
+                    finalize_module(&code, &module_type, &map, property_map)
+                }
+            };
+            match output {
+                Ok(output) => Response::Output {
+                    stats: Stats { output_bytes: output.code.len(), ..stats },
+                    code: output.code,
+                    map: output.map,
+                    warnings,
+                },
+                Err(err) => Response::Error { error: err.to_string() },
+            }
+        }
+        response => response,
+    }
+}
+
+fn transform_line(
+    line: &str,
+    args: &Args,
+    extern_map: &ExternMap,
+    property_map: &PropertyMap,
+) -> Response {
+    match parse_request(line) {
+        Ok(request) => transform_request(&request, args, extern_map, property_map),
+        Err(error) => Response::Error { error },
+    }
+}
+
+fn parse_request(line: &str) -> Result<Request, String> {
+    serde_json::from_str::<Request>(line).map_err(|err| format!("Invalid request: {err}"))
+}
+
+fn transform_request(
+    request: &Request,
+    args: &Args,
+    extern_map: &ExternMap,
+    property_map: &PropertyMap,
+) -> Response {
+    let options = &args.options;
+    let source = SourceOptions {
+        file_name: request.file_name.as_deref().unwrap_or_default(),
+        input_map: request.map.as_deref(),
+        on_warning: None,
+    };
+    let result = match &request.module_type {
+        Some(module_type) if args.single_phase => optimize_module_single_phase(
+            &request.source,
+            module_type,
+            options,
+            extern_map,
+            property_map,
+            &source,
+        ),
+        Some(module_type) => {
+            optimize_module(&request.source, module_type, options, extern_map, &source)
+        }
+        None => optimize_chunk(&request.source, options, property_map, &source),
+    };
+
+    match result {
+        Ok(output) => Response::Output {
+            stats: Stats {
+                input_bytes: request.source.len(),
+                output_bytes: output.code.len(),
+                used_externs: output.stats.used_externs,
+                hoist_log: output.stats.hoist_log,
+                duplicates_collapsed: output.stats.duplicates_collapsed,
+                globals_hoisted: output.stats.globals_hoisted,
+                properties_renamed: output.stats.properties_renamed,
+            },
+            code: output.code,
+            map: output.map,
+            warnings: output.warnings,
+        },
+        Err(err) => Response::Error {
+            error: match &request.file_name {
+                Some(file_name) => format!("{file_name}: {err}"),
+                None => err.to_string(),
+            },
+        },
+    }
+}
+
+fn parse_args<I: Iterator<Item = String>>(mut args: I) -> Result<Args, String> {
+    let mut r = Args::default();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            // Replaces all options, flags after `--config` override its values.
+            "--config" => {
+                let path = expect_value(&arg, args.next())?;
+                let data = std::fs::read(&path).map_err(|err| format!("{path}: {err}"))?;
+                r.options =
+                    OptimizerOptions::from_json(&data).map_err(|err| format!("{path}: {err}"))?;
+            }
+            "--hoist" => r.options.hoist = true,
+            "--no-hoist-in-async-generators" => r.options.no_hoist_in_async_generators = true,
+            "--debug-hoist" => r.options.debug_hoist = true,
+            "--dedupe" => r.options.dedupe = true,
+            "--dedupe-isolate-module-wrappers" => r.options.dedupe_isolate_module_wrappers = true,
+            "--dedupe-sibling-scopes" => r.options.dedupe_sibling_scopes = true,
+            "--dedupe-calls" => r.options.dedupe_calls = true,
+            "--pure" => r.options.pure = true,
+            "--dce" => r.options.dce = true,
+            "--const-propagation" => r.options.const_propagation = true,
+            "--inline" => r.options.inline = true,
+            "--mark-pure-constructors" => r.options.mark_pure_constructors = true,
+            "--globals" => {
+                let v = expect_value(&arg, args.next())?;
+                r.options.globals.include = GlobalCategory::from(v.split(','));
+            }
+            "--globals-hoist" => r.options.globals.hoist = true,
+            "--globals-singletons" => r.options.globals.singletons = true,
+            "--globals-auto-hoist-literals" => r.options.globals.auto_hoist_literals = true,
+            "--globals-no-hoist-in-top-scope" => r.options.globals.no_hoist_in_top_scope = true,
+            "--globals-scope" => {
+                let v = expect_value(&arg, args.next())?;
+                r.options.globals.scope = v.parse().map_err(|err| format!("{err}"))?;
+            }
+            "--globals-min-uses" => {
+                let v = expect_value(&arg, args.next())?;
+                r.options.globals.min_uses =
+                    v.parse().map_err(|_| format!("invalid value for '{arg}'"))?;
+            }
+            "--globals-singleton" => {
+                r.options.globals.singletons = true;
+                r.options.globals.singleton_constructors.push(expect_value(&arg, args.next())?);
+            }
+            "--externs" => r.externs.push(expect_value(&arg, args.next())?),
+            "--inline-const-values" => r.options.externs.inline_const_values = true,
+            "--max-inline-size" => {
+                let v = expect_value(&arg, args.next())?;
+                r.options.externs.max_inline_size =
+                    Some(v.parse().map_err(|_| format!("invalid value for '{arg}'"))?);
+            }
+            "--rename-properties" => {
+                r.options.rename_properties = true;
+                r.pattern = Some(expect_value(&arg, args.next())?);
+            }
+            "--rename-deterministic" => r.deterministic = true,
+            "--rename-naming" => {
+                let v = expect_value(&arg, args.next())?;
+                r.naming = v.parse().map_err(|err| format!("{err}"))?;
+            }
+            "--rename-max-len" => {
+                let v = expect_value(&arg, args.next())?;
+                r.max_mangled_len =
+                    Some(v.parse().map_err(|_| format!("invalid value for '{arg}'"))?);
+            }
+            "--rename-reserved" => {
+                let v = expect_value(&arg, args.next())?;
+                r.reserved.extend(v.split(',').map(String::from));
+            }
+            "--rename-keep-names" => {
+                let v = expect_value(&arg, args.next())?;
+                r.keep_names.extend(v.split(',').map(String::from));
+            }
+            "--rename-key-objects-only" => r.options.rename_key_objects_only = true,
+            "--property-map" => {
+                r.options.rename_properties = true;
+                r.property_map = Some(expect_value(&arg, args.next())?);
+            }
+            "--stringify-large-literals" => r.options.stringify_large_literals = true,
+            "--stringify-threshold" => {
+                let v = expect_value(&arg, args.next())?;
+                r.options.stringify_threshold =
+                    v.parse().map_err(|_| format!("invalid value for '{arg}'"))?;
+            }
+            "--single-phase" => r.single_phase = true,
+            "--base-url" => r.options.url = Some(expect_value(&arg, args.next())?),
+            "--debug-comments" => r.options.debug_comments = true,
+            "--debug-names" => r.options.debug_names = true,
+            "--keep-annotations" => r.options.keep_annotations = true,
+            "--preserve-comments" => {
+                let v = expect_value(&arg, args.next())?;
+                r.options.preserve_comments = v.parse().map_err(|err| format!("{err}"))?;
+            }
+            _ => return Err(format!("unknown argument '{arg}'")),
+        }
+    }
+    Ok(r)
+}
+
+fn expect_value(arg: &str, value: Option<String>) -> Result<String, String> {
+    value.ok_or_else(|| format!("missing value for '{arg}'"))
+}
@@ -16,10 +16,40 @@ impl Annotation {
         Self { flags: Self::KEY }
     }
 
+    pub fn pure() -> Self {
+        Self { flags: Self::PURE }
+    }
+
+    pub fn key_object() -> Self {
+        Self { flags: Self::KEY_OBJECT }
+    }
+
+    pub fn inline() -> Self {
+        Self { flags: Self::INLINE }
+    }
+
+    pub fn freeze() -> Self {
+        Self { flags: Self::FREEZE }
+    }
+
+    pub fn hoisted_dedupe() -> Self {
+        Self { flags: Self::DEDUPE | Self::HOISTED }
+    }
+
     /// Dedupe Expression
     pub const DEDUPE: u32 = 1 << 0;
     /// Property Key
     pub const KEY: u32 = 1 << 1;
+    /// Pure Expression
+    pub const PURE: u32 = 1 << 2;
+    /// Object with renamed property keys
+    pub const KEY_OBJECT: u32 = 1 << 3;
+    /// Inlined function call
+    pub const INLINE: u32 = 1 << 4;
+    /// Frozen object `Object.freeze(expr)`
+    pub const FREEZE: u32 = 1 << 5;
+    /// Hoisted expression that is deduplicated when it is possible
+    pub const HOISTED: u32 = 1 << 6;
 
     pub fn is_dedupe(&self) -> bool {
         self.flags & Self::DEDUPE != 0
@@ -29,5 +59,25 @@ impl Annotation {
         self.flags & Self::KEY != 0
     }
 
+    pub fn is_pure(&self) -> bool {
+        self.flags & Self::PURE != 0
+    }
+
+    pub fn is_key_object(&self) -> bool {
+        self.flags & Self::KEY_OBJECT != 0
+    }
+
+    pub fn is_inline(&self) -> bool {
+        self.flags & Self::INLINE != 0
+    }
+
+    pub fn is_freeze(&self) -> bool {
+        self.flags & Self::FREEZE != 0
+    }
+
+    pub fn is_hoisted(&self) -> bool {
+        self.flags & Self::HOISTED != 0
+    }
+
     pub const ID_NAME: &'static str = "__oveo__";
 }
@@ -0,0 +1,117 @@
+use oxc_allocator::{Address, CloneIn, GetAddress};
+use oxc_ast::ast::*;
+use oxc_semantic::{ScopeId, SymbolId};
+use oxc_traverse::Ancestor;
+use rustc_hash::FxHashMap;
+
+use crate::{context::TraverseCtx, statements::Statements};
+
+/// Propagates local `const` bindings with literal initializers that are read
+/// only once, e.g. `const x = 5; use(x);` is transformed into `use(5);`.
+///
+/// Bindings are propagated only when the read is in the same function and
+/// after the declaration, so that closures and TDZ errors aren't affected.
+#[derive(Default)]
+pub struct ConstPropagation<'a> {
+    values: FxHashMap<SymbolId, ConstValue<'a>>,
+}
+
+struct ConstValue<'a> {
+    value: Expression<'a>,
+    /// Declaration statement that is removed when the value is propagated.
+    statement: Address,
+    function_scope_id: ScopeId,
+}
+
+impl<'a> ConstPropagation<'a> {
+    /// Registers `const x = literal;` declarations.
+    pub fn exit_statement(&mut self, node: &Statement<'a>, ctx: &TraverseCtx<'a>) {
+        let Statement::VariableDeclaration(decl) = node else {
+            return;
+        };
+        if decl.kind != VariableDeclarationKind::Const || decl.declarations.len() != 1 {
+            return;
+        }
+        // Declarations in switch cases can be skipped by jumping to another
+        // case, reads after them would throw TDZ errors.
+        if matches!(ctx.parent(), Ancestor::SwitchCaseConsequent(_)) {
+            return;
+        }
+        let declarator = &decl.declarations[0];
+        let BindingPattern::BindingIdentifier(id) = &declarator.id else {
+            return;
+        };
+        let Some(init) = &declarator.init else {
+            return;
+        };
+        if !is_literal(init) {
+            return;
+        }
+        let symbol_id = id.symbol_id();
+        let scoping = ctx.scoping();
+        let [reference_id] = scoping.get_resolved_reference_ids(symbol_id) else {
+            return;
+        };
+        let reference = scoping.get_reference(*reference_id);
+        if !reference.is_read() || reference.is_write() {
+            return;
+        }
+        if scoping
+            .scope_ancestors(ctx.current_scope_id())
+            .any(|scope_id| scoping.scope_flags(scope_id).contains_direct_eval())
+        {
+            return;
+        }
+        self.values.insert(
+            symbol_id,
+            ConstValue {
+                value: init.clone_in(ctx.ast.allocator),
+                statement: node.address(),
+                function_scope_id: function_scope_id(ctx.current_scope_id(), ctx),
+            },
+        );
+    }
+
+    /// Replaces the read of a registered binding with its value.
+    pub fn exit_expression(
+        &mut self,
+        node: &mut Expression<'a>,
+        statements: &Statements<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) {
+        let Expression::Identifier(id) = node else {
+            return;
+        };
+        let Some(symbol_id) = ctx.scoping().get_reference(id.reference_id()).symbol_id() else {
+            return;
+        };
+        let Some(value) = self.values.remove(&symbol_id) else {
+            return;
+        };
+        if value.function_scope_id != function_scope_id(ctx.current_scope_id(), ctx) {
+            return;
+        }
+        *node = value.value;
+        statements.remove_address(value.statement);
+    }
+}
+
+fn is_literal(expr: &Expression) -> bool {
+    matches!(
+        expr,
+        Expression::BooleanLiteral(_)
+            | Expression::NullLiteral(_)
+            | Expression::NumericLiteral(_)
+            | Expression::BigIntLiteral(_)
+            | Expression::StringLiteral(_)
+    )
+}
+
+/// Nearest function scope or the root scope.
+fn function_scope_id(scope_id: ScopeId, ctx: &TraverseCtx) -> ScopeId {
+    let scoping = ctx.scoping();
+    scoping
+        .scope_ancestors(scope_id)
+        .find(|id| scoping.scope_flags(*id).is_function())
+        .unwrap_or_else(|| scoping.root_scope_id())
+}
@@ -0,0 +1,132 @@
+//! Removes unreachable statements.
+//!
+use oxc_allocator::{FromIn, GetAddress, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::{ScopeId, SymbolFlags};
+use oxc_span::{GetSpan, SPAN};
+use oxc_str::Ident;
+
+use crate::{context::TraverseCtx, statements::Statements};
+
+/// Removes statements after the first `return`, `throw`, `break` or
+/// `continue` statement in a statement list.
+///
+/// Function declarations are hoisted and can still be invoked, so they are
+/// preserved. Lexical declarations referenced from preserved functions are
+/// also preserved, so that the references still throw in the temporal dead
+/// zone instead of resolving to global bindings. Statements that declare `var`
+/// bindings are replaced with uninitialized `var` declarations.
+pub fn remove_unreachable_statements<'a>(
+    stmts: &ArenaVec<'a, Statement<'a>>,
+    statements: &Statements<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    let Some(index) = stmts.iter().position(|stmt| {
+        matches!(
+            stmt,
+            Statement::ReturnStatement(_)
+                | Statement::ThrowStatement(_)
+                | Statement::BreakStatement(_)
+                | Statement::ContinueStatement(_)
+        )
+    }) else {
+        return;
+    };
+
+    let functions: Vec<_> = stmts
+        .iter()
+        .skip(index + 1)
+        .filter_map(|stmt| match stmt {
+            Statement::FunctionDeclaration(func) => Some(func.scope_id()),
+            _ => None,
+        })
+        .collect();
+    for stmt in stmts.iter().skip(index + 1) {
+        if matches!(stmt, Statement::FunctionDeclaration(_))
+            || is_referenced_lexical_declaration(stmt, &functions, ctx)
+        {
+            continue;
+        }
+        match hoisted_var_declaration(stmt, ctx) {
+            Some(decl) => statements.replace(stmt, decl),
+            None => statements.remove_address(stmt.address()),
+        }
+    }
+}
+
+/// Checks if the statement declares `let`, `const` or `class` bindings that
+/// are referenced from the function scopes.
+fn is_referenced_lexical_declaration(
+    stmt: &Statement<'_>,
+    functions: &[ScopeId],
+    ctx: &TraverseCtx<'_>,
+) -> bool {
+    if functions.is_empty() {
+        return false;
+    }
+    let is_lexical = match stmt {
+        Statement::VariableDeclaration(decl) => decl.kind.is_lexical(),
+        Statement::ClassDeclaration(_) => true,
+        _ => false,
+    };
+    if !is_lexical {
+        return false;
+    }
+    let span = stmt.span();
+    let scoping = ctx.scoping();
+    scoping.iter_bindings_in(ctx.current_scope_id()).any(|symbol_id| {
+        span.contains_inclusive(scoping.symbol_span(symbol_id))
+            && scoping.get_resolved_references(symbol_id).any(|reference| {
+                scoping
+                    .scope_ancestors(reference.scope_id())
+                    .any(|scope_id| functions.contains(&scope_id))
+            })
+    })
+}
+
+/// Creates `var a, b;` declaration for `var` bindings declared in the
+/// statement, including bindings in nested blocks.
+fn hoisted_var_declaration<'a>(
+    stmt: &Statement<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Option<Statement<'a>> {
+    let span = stmt.span();
+    let scoping = ctx.scoping();
+    let mut symbols: Vec<_> = scoping
+        .iter_bindings_in(ctx.current_hoist_scope_id())
+        .filter(|&symbol_id| {
+            scoping.symbol_flags(symbol_id).contains(SymbolFlags::FunctionScopedVariable)
+                && span.contains_inclusive(scoping.symbol_span(symbol_id))
+        })
+        .collect();
+    if symbols.is_empty() {
+        return None;
+    }
+    // Bindings are stored in a hash map, declarations are emitted in the
+    // source order.
+    symbols.sort_unstable_by_key(|&symbol_id| scoping.symbol_span(symbol_id).start);
+    let names: Vec<_> = symbols
+        .into_iter()
+        .map(|symbol_id| Ident::from_in(scoping.symbol_name(symbol_id), ctx.ast.allocator))
+        .collect();
+
+    let mut declarations = ArenaVec::with_capacity_in(names.len(), ctx);
+    for name in names {
+        declarations.push(VariableDeclarator::new(
+            SPAN,
+            VariableDeclarationKind::Var,
+            BindingPattern::BindingIdentifier(BindingIdentifier::boxed(SPAN, name, ctx)),
+            NONE,
+            None,
+            false,
+            ctx,
+        ));
+    }
+    Some(Statement::VariableDeclaration(VariableDeclaration::boxed(
+        SPAN,
+        VariableDeclarationKind::Var,
+        declarations,
+        false,
+        ctx,
+    )))
+}
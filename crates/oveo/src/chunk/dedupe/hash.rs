@@ -16,6 +16,69 @@ pub fn dedupe_hash<'a>(
     Some(())
 }
 
+/// Hashes `Object.freeze(expr)` created by the `freeze()` intrinsic, it
+/// doesn't have side effects and it is hashed even when calls aren't
+/// deduplicated.
+pub fn dedupe_hash_frozen<'a>(
+    state: &mut DedupeState,
+    node: &Expression<'a>,
+    scoping: &Scoping,
+) -> Option<()> {
+    let Expression::CallExpression(call) = node else {
+        return None;
+    };
+    walk_call(state, None, Tag::Call, &call.callee, &call.arguments, true, scoping, node.address())
+}
+
+/// Hashes arguments of singleton constructors `new C("en", { a: 1 })`,
+/// returns `None` when arguments aren't constant.
+pub fn constant_arguments_hash<'a>(
+    arguments: &[Argument<'a>],
+    scoping: &Scoping,
+) -> Option<[u8; 20]> {
+    // Nested literals are added to the scratch state, they aren't
+    // deduplicated.
+    let mut state = DedupeState::new(false, false);
+    let mut h = Sha1::default();
+    h.update(arguments.len().to_ne_bytes());
+    for arg in arguments {
+        let expr = arg.as_expression()?;
+        if !is_constant(expr) {
+            return None;
+        }
+        walk_expr(&mut state, Some(&mut h), expr, scoping, expr.address())?;
+    }
+    Some(h.finalize().into())
+}
+
+/// Literals and object/array literals that contain only literals, regexps
+/// are excluded because they are stateful.
+fn is_constant(expr: &Expression<'_>) -> bool {
+    match expr {
+        Expression::BooleanLiteral(_)
+        | Expression::NullLiteral(_)
+        | Expression::NumericLiteral(_)
+        | Expression::BigIntLiteral(_)
+        | Expression::StringLiteral(_) => true,
+        Expression::TemplateLiteral(tpl) => tpl.expressions.is_empty(),
+        Expression::UnaryExpression(expr) => {
+            expr.operator == UnaryOperator::UnaryNegation
+                && matches!(expr.argument, Expression::NumericLiteral(_))
+        }
+        Expression::ArrayExpression(expr) => {
+            expr.elements.iter().all(|e| e.as_expression().is_some_and(is_constant))
+        }
+        Expression::ObjectExpression(expr) => expr.properties.iter().all(|p| {
+            matches!(p, ObjectPropertyKind::ObjectProperty(p)
+                if p.kind == PropertyKind::Init
+                    && !p.computed
+                    && !p.method
+                    && is_constant(&p.value))
+        }),
+        _ => false,
+    }
+}
+
 fn walk_expr<'a>(
     state: &mut DedupeState,
     w: Option<&mut Sha1>,
@@ -33,8 +96,9 @@ fn walk_expr<'a>(
         Expression::TemplateLiteral(node) => {
             walk_template_literal(state, w, node, scoping, address)
         }
-        Expression::Identifier(node) => walk_identifier_reference(w, node, scoping),
+        Expression::Identifier(node) => walk_identifier_reference(state, w, node, scoping),
         Expression::CallExpression(node) => walk_call_expression(state, w, node, scoping, address),
+        Expression::NewExpression(node) => walk_new_expression(state, w, node, scoping, address),
         Expression::ArrayExpression(node) => {
             walk_array_expression(state, w, node, scoping, address)
         }
@@ -50,22 +114,25 @@ fn walk_expr<'a>(
         Expression::StaticMemberExpression(node) => {
             walk_static_member_expression(state, w, node, scoping, address)
         }
+        Expression::ConditionalExpression(node) => {
+            walk_conditional_expression(state, w, node, scoping, address)
+        }
+        Expression::ArrowFunctionExpression(node) => {
+            walk_arrow_function_expression(state, w, node, scoping, address)
+        }
+        Expression::BinaryExpression(node) => walk_binary_expression(state, w, node, scoping),
+        Expression::LogicalExpression(node) => walk_logical_expression(state, w, node, scoping),
+        Expression::UnaryExpression(node) => walk_unary_expression(state, w, node, scoping),
         Expression::MetaProperty(_)
         | Expression::Super(_)
-        | Expression::ArrowFunctionExpression(_)
         | Expression::AssignmentExpression(_)
         | Expression::AwaitExpression(_)
-        | Expression::BinaryExpression(_)
         | Expression::ChainExpression(_)
         | Expression::ClassExpression(_)
-        | Expression::ConditionalExpression(_)
         | Expression::FunctionExpression(_)
         | Expression::ImportExpression(_)
-        | Expression::LogicalExpression(_)
-        | Expression::NewExpression(_)
         | Expression::SequenceExpression(_)
         | Expression::ThisExpression(_)
-        | Expression::UnaryExpression(_)
         | Expression::UpdateExpression(_)
         | Expression::YieldExpression(_)
         | Expression::PrivateInExpression(_)
@@ -89,11 +156,46 @@ fn walk_call_expression(
     scoping: &Scoping,
     address: Address,
 ) -> Option<()> {
+    walk_call(state, w, Tag::Call, &node.callee, &node.arguments, node.pure, scoping, address)
+}
+
+/// `new` expressions are hashed only when they are annotated with
+/// `/* @__PURE__ */`.
+fn walk_new_expression(
+    state: &mut DedupeState,
+    w: Option<&mut Sha1>,
+    node: &NewExpression,
+    scoping: &Scoping,
+    address: Address,
+) -> Option<()> {
+    if !node.pure {
+        return None;
+    }
+    walk_call(state, w, Tag::New, &node.callee, &node.arguments, true, scoping, address)
+}
+
+#[expect(clippy::too_many_arguments)]
+fn walk_call<'a>(
+    state: &mut DedupeState,
+    w: Option<&mut Sha1>,
+    tag: Tag,
+    callee: &Expression<'a>,
+    arguments: &[Argument<'a>],
+    pure: bool,
+    scoping: &Scoping,
+    address: Address,
+) -> Option<()> {
+    // Calls with side effects can't be shared unless `dedupe_calls` is
+    // enabled.
+    if !pure && !state.calls {
+        return None;
+    }
+    let local_refs = state.local_refs;
     let mut h = Sha1::default();
-    h.update(Tag::Call.to_ne_bytes());
-    walk_expr(state, Some(&mut h), &node.callee, scoping, node.callee.address())?;
-    h.update(node.arguments.len().to_ne_bytes());
-    for arg in &node.arguments {
+    h.update(tag.to_ne_bytes());
+    walk_expr(state, Some(&mut h), callee, scoping, callee.address())?;
+    h.update(arguments.len().to_ne_bytes());
+    for arg in arguments {
         if let Some(expr) = arg.as_expression() {
             walk_expr(state, Some(&mut h), expr, scoping, expr.address())?;
         } else {
@@ -101,7 +203,69 @@ fn walk_call_expression(
         }
     }
     let hash = h.finalize();
-    state.add(address, hash.into());
+    // Calls can have side effects, nested calls are deduplicated separately
+    // only when they are annotated with `/* @__PURE__ */`. Root expressions
+    // (without a parent hasher) are annotated with `dedupe()`.
+    if pure || w.is_none() {
+        state.add(address, hash.into(), state.local_refs == local_refs);
+    }
+
+    if let Some(w) = w {
+        w.update(Tag::Hash.to_ne_bytes());
+        w.update(hash);
+    }
+    Some(())
+}
+
+fn walk_conditional_expression<'a>(
+    state: &mut DedupeState,
+    w: Option<&mut Sha1>,
+    node: &ConditionalExpression<'a>,
+    scoping: &Scoping,
+    address: Address,
+) -> Option<()> {
+    let local_refs = state.local_refs;
+    let mut h = Sha1::default();
+    h.update(Tag::ConditionalTest.to_ne_bytes());
+    walk_expr(state, Some(&mut h), &node.test, scoping, node.test.address())?;
+    h.update(Tag::ConditionalConsequent.to_ne_bytes());
+    walk_expr(state, Some(&mut h), &node.consequent, scoping, node.consequent.address())?;
+    h.update(Tag::ConditionalAlternate.to_ne_bytes());
+    walk_expr(state, Some(&mut h), &node.alternate, scoping, node.alternate.address())?;
+
+    let hash = h.finalize();
+    state.add(address, hash.into(), state.local_refs == local_refs);
+
+    if let Some(w) = w {
+        w.update(Tag::Hash.to_ne_bytes());
+        w.update(hash);
+    }
+    Some(())
+}
+
+/// Arrow functions are hashed when they don't capture local bindings, their
+/// bodies can reference only parameters and globals.
+fn walk_arrow_function_expression<'a>(
+    state: &mut DedupeState,
+    w: Option<&mut Sha1>,
+    node: &ArrowFunctionExpression<'a>,
+    scoping: &Scoping,
+    address: Address,
+) -> Option<()> {
+    let local_refs = state.local_refs;
+    let mut h = Sha1::default();
+    h.update(Tag::ArrowFunctionExpression.to_ne_bytes());
+    h.update((node.r#async as u8).to_ne_bytes());
+
+    let params_len = state.arrow_params.len();
+    state.arrows += 1;
+    let result = walk_arrow_function(state, &mut h, node, scoping);
+    state.arrows -= 1;
+    state.arrow_params.truncate(params_len);
+    result?;
+
+    let hash = h.finalize();
+    state.add(address, hash.into(), state.local_refs == local_refs);
 
     if let Some(w) = w {
         w.update(Tag::Hash.to_ne_bytes());
@@ -110,6 +274,89 @@ fn walk_call_expression(
     Some(())
 }
 
+fn walk_arrow_function<'a>(
+    state: &mut DedupeState,
+    w: &mut Sha1,
+    node: &ArrowFunctionExpression<'a>,
+    scoping: &Scoping,
+) -> Option<()> {
+    // Only simple parameters `(a, b) => {}`
+    if node.params.rest.is_some() {
+        return None;
+    }
+    w.update(node.params.items.len().to_ne_bytes());
+    for param in &node.params.items {
+        if param.initializer.is_some() {
+            return None;
+        }
+        let BindingPattern::BindingIdentifier(id) = &param.pattern else {
+            return None;
+        };
+        state.arrow_params.push(id.symbol_id());
+    }
+
+    if !node.body.directives.is_empty() {
+        return None;
+    }
+    if node.expression {
+        // `() => expr`
+        let Some(Statement::ExpressionStatement(stmt)) = node.body.statements.first() else {
+            return None;
+        };
+        w.update(Tag::ArrowFunctionExpressionBody.to_ne_bytes());
+        walk_expr(state, Some(w), &stmt.expression, scoping, stmt.expression.address())
+    } else if node.body.statements.is_empty() {
+        // `() => {}`
+        w.update(Tag::ArrowFunctionEmptyBody.to_ne_bytes());
+        Some(())
+    } else {
+        None
+    }
+}
+
+/// Binary, logical and unary expressions are hashed only as a part of other
+/// expressions.
+fn walk_binary_expression<'a>(
+    state: &mut DedupeState,
+    w: Option<&mut Sha1>,
+    node: &BinaryExpression<'a>,
+    scoping: &Scoping,
+) -> Option<()> {
+    let w = w?;
+    w.update(Tag::BinaryExpression.to_ne_bytes());
+    w.update(node.operator.as_str().as_bytes());
+    walk_expr(state, Some(w), &node.left, scoping, node.left.address())?;
+    walk_expr(state, Some(w), &node.right, scoping, node.right.address())
+}
+
+fn walk_logical_expression<'a>(
+    state: &mut DedupeState,
+    w: Option<&mut Sha1>,
+    node: &LogicalExpression<'a>,
+    scoping: &Scoping,
+) -> Option<()> {
+    let w = w?;
+    w.update(Tag::LogicalExpression.to_ne_bytes());
+    w.update(node.operator.as_str().as_bytes());
+    walk_expr(state, Some(w), &node.left, scoping, node.left.address())?;
+    walk_expr(state, Some(w), &node.right, scoping, node.right.address())
+}
+
+fn walk_unary_expression<'a>(
+    state: &mut DedupeState,
+    w: Option<&mut Sha1>,
+    node: &UnaryExpression<'a>,
+    scoping: &Scoping,
+) -> Option<()> {
+    let w = w?;
+    if node.operator == UnaryOperator::Delete {
+        return None;
+    }
+    w.update(Tag::UnaryExpression.to_ne_bytes());
+    w.update(node.operator.as_str().as_bytes());
+    walk_expr(state, Some(w), &node.argument, scoping, node.argument.address())
+}
+
 fn walk_array_expression<'a>(
     state: &mut DedupeState,
     w: Option<&mut Sha1>,
@@ -117,6 +364,7 @@ fn walk_array_expression<'a>(
     scoping: &Scoping,
     address: Address,
 ) -> Option<()> {
+    let local_refs = state.local_refs;
     let mut h = Sha1::default();
     h.update(Tag::ArrayExpression.to_ne_bytes());
     h.update(node.elements.len().to_ne_bytes());
@@ -124,7 +372,7 @@ fn walk_array_expression<'a>(
         walk_array_expression_element(state, &mut h, item, scoping)?;
     }
     let hash = h.finalize();
-    state.add(address, hash.into());
+    state.add(address, hash.into(), state.local_refs == local_refs);
 
     if let Some(w) = w {
         w.update(Tag::Hash.to_ne_bytes());
@@ -198,6 +446,7 @@ fn walk_object_expression<'a>(
     scoping: &Scoping,
     address: Address,
 ) -> Option<()> {
+    let local_refs = state.local_refs;
     let mut h = Sha1::default();
     h.update(Tag::ObjectExpression.to_ne_bytes());
     h.update(node.properties.len().to_ne_bytes());
@@ -205,7 +454,7 @@ fn walk_object_expression<'a>(
         walk_object_property_kind(state, &mut h, item, scoping)?;
     }
     let hash = h.finalize();
-    state.add(address, hash.into());
+    state.add(address, hash.into(), state.local_refs == local_refs);
 
     if let Some(w) = w {
         w.update(Tag::Hash.to_ne_bytes());
@@ -258,7 +507,7 @@ fn walk_property_key<'a>(
         PropertyKey::TemplateLiteral(node) => {
             walk_template_literal(state, w, node, scoping, address)
         }
-        PropertyKey::Identifier(node) => walk_identifier_reference(w, node, scoping),
+        PropertyKey::Identifier(node) => walk_identifier_reference(state, w, node, scoping),
         PropertyKey::MetaProperty(_)
         | PropertyKey::Super(_)
         | PropertyKey::ArrayExpression(_)
@@ -304,6 +553,7 @@ fn walk_template_literal<'a>(
     scoping: &Scoping,
     address: Address,
 ) -> Option<()> {
+    let local_refs = state.local_refs;
     let mut h = Sha1::default();
     h.update(Tag::TemplateLiteral.to_ne_bytes());
     h.update(node.quasis.len().to_ne_bytes());
@@ -315,7 +565,7 @@ fn walk_template_literal<'a>(
         walk_expr(state, Some(&mut h), item, scoping, item.address())?;
     }
     let hash = h.finalize();
-    state.add(address, hash.into());
+    state.add(address, hash.into(), state.local_refs == local_refs);
 
     if let Some(w) = w {
         w.update(Tag::Hash.to_ne_bytes());
@@ -339,6 +589,7 @@ fn walk_tagged_template_expression<'a>(
     scoping: &Scoping,
     address: Address,
 ) -> Option<()> {
+    let local_refs = state.local_refs;
     let mut h = Sha1::default();
     h.update(Tag::TaggedTemplateExpression.to_ne_bytes());
     walk_expr(state, Some(&mut h), &node.tag, scoping, address)?;
@@ -352,7 +603,7 @@ fn walk_tagged_template_expression<'a>(
     }
 
     let hash = h.finalize();
-    state.add(address, hash.into());
+    state.add(address, hash.into(), state.local_refs == local_refs);
 
     if let Some(w) = w {
         w.update(Tag::Hash.to_ne_bytes());
@@ -368,13 +619,14 @@ fn walk_static_member_expression<'a>(
     scoping: &Scoping,
     address: Address,
 ) -> Option<()> {
+    let local_refs = state.local_refs;
     let mut h = Sha1::default();
     h.update(Tag::StaticMemberExpression.to_ne_bytes());
     walk_expr(state, Some(&mut h), &node.object, scoping, address)?;
     walk_identifier_name(Some(&mut h), &node.property)?;
 
     let hash = h.finalize();
-    state.add(address, hash.into());
+    state.add(address, hash.into(), state.local_refs == local_refs);
 
     if let Some(w) = w {
         w.update(Tag::Hash.to_ne_bytes());
@@ -429,7 +681,7 @@ fn walk_string_literal<'a>(
         h.update(s.as_bytes());
 
         let hash = h.finalize();
-        state.add(address, hash.into());
+        state.add(address, hash.into(), true);
 
         if let Some(w) = w {
             w.update(Tag::Hash.to_ne_bytes());
@@ -456,7 +708,7 @@ fn walk_big_int_literal<'a>(
     h.update(s.as_bytes());
 
     let hash = h.finalize();
-    state.add(address, hash.into());
+    state.add(address, hash.into(), true);
 
     if let Some(w) = w {
         w.update(Tag::Hash.to_ne_bytes());
@@ -481,7 +733,7 @@ fn walk_reg_exp_literal<'a>(
     h.update(s.as_bytes());
 
     let hash = h.finalize();
-    state.add(address, hash.into());
+    state.add(address, hash.into(), true);
 
     if let Some(w) = w {
         w.update(Tag::Hash.to_ne_bytes());
@@ -508,20 +760,38 @@ fn walk_elision(w: &mut Sha1) -> Option<()> {
 }
 
 fn walk_identifier_reference<'a>(
+    state: &mut DedupeState,
     w: Option<&mut Sha1>,
     node: &IdentifierReference<'a>,
     scoping: &Scoping,
 ) -> Option<()> {
-    if let Some(h) = w {
-        let r = scoping.get_reference(node.reference_id());
-        if let Some(s) = r.symbol_id() {
-            h.update(Tag::IdentifierReferenceSymbol.to_ne_bytes());
-            h.update(s.index().to_ne_bytes());
-        } else {
-            h.update(Tag::IdentifierReferenceGlobal.to_ne_bytes());
-            let s = &node.name;
-            h.update(s.len().to_ne_bytes());
-            h.update(s.as_bytes());
+    let r = scoping.get_reference(node.reference_id());
+    match r.symbol_id() {
+        // References to arrow function parameters are hashed by their
+        // position, so that identical arrow functions have the same hash.
+        Some(s) if state.arrows > 0 => {
+            let index = state.arrow_params.iter().position(|p| *p == s)?;
+            if let Some(h) = w {
+                h.update(Tag::ArrowFunctionParam.to_ne_bytes());
+                h.update(index.to_ne_bytes());
+            }
+        }
+        Some(s) => {
+            state.local_refs += 1;
+            if let Some(h) = w {
+                h.update(Tag::IdentifierReferenceSymbol.to_ne_bytes());
+                h.update(s.index().to_ne_bytes());
+            }
+        }
+        // `arguments` in arrow functions is bound to the enclosing function.
+        None if state.arrows > 0 && node.name == "arguments" => return None,
+        None => {
+            if let Some(h) = w {
+                h.update(Tag::IdentifierReferenceGlobal.to_ne_bytes());
+                let s = &node.name;
+                h.update(s.len().to_ne_bytes());
+                h.update(s.as_bytes());
+            }
         }
     }
     Some(())
@@ -569,6 +839,17 @@ enum Tag {
     SpreadElement,
     Elision,
     Call,
+    New,
+    ConditionalTest,
+    ConditionalConsequent,
+    ConditionalAlternate,
+    ArrowFunctionExpression,
+    ArrowFunctionExpressionBody,
+    ArrowFunctionEmptyBody,
+    ArrowFunctionParam,
+    BinaryExpression,
+    LogicalExpression,
+    UnaryExpression,
     Hash,
 }
 
@@ -1,15 +1,35 @@
 use oxc_allocator::Address;
-use rustc_hash::FxHashMap;
+use oxc_semantic::SymbolId;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 mod hash;
 
-pub use hash::dedupe_hash;
+pub use hash::{constant_arguments_hash, dedupe_hash, dedupe_hash_frozen};
 
 #[derive(Default)]
 pub struct DedupeState {
     pub scopes: Vec<FxHashMap<[u8; 20], Address>>,
+    /// Indexes of the first visible scope in isolated module wrappers.
+    pub isolated: Vec<usize>,
+    /// Expressions without references to local bindings, they are visible
+    /// in sibling scopes when hoisted to the module top level.
+    module: FxHashMap<[u8; 20], Address>,
+    sibling_scopes: bool,
+    /// Call expressions without `/* @__PURE__ */` annotations are hashed.
+    calls: bool,
+    /// Originals that have duplicates outside of their scope chain and
+    /// should be hoisted to the module top level.
+    pub hoisted: FxHashSet<Address>,
+    /// Number of hashed references to local bindings.
+    local_refs: u32,
+    /// Depth of arrow functions that are currently hashed.
+    arrows: u32,
+    /// Parameters of arrow functions that are currently hashed.
+    arrow_params: Vec<SymbolId>,
     pub expressions: FxHashMap<Address, DedupeKind>,
     pub duplicates: u32,
+    /// Expressions in the order they were added.
+    log: Vec<Address>,
 }
 
 pub enum DedupeKind {
@@ -18,25 +38,76 @@ pub enum DedupeKind {
 }
 
 impl DedupeState {
-    pub fn add(&mut self, address: Address, hash: [u8; 20]) {
-        let mut original = true;
-        for scope in &mut self.scopes {
-            if let Some(original_address) = scope.get(&hash) {
-                self.duplicates += 1;
-                if let Some(DedupeKind::Original(duplicates_count)) =
-                    self.expressions.get_mut(original_address)
-                {
-                    *duplicates_count += 1;
-                }
-                self.expressions.insert(address, DedupeKind::Duplicate(*original_address));
-                original = false;
-                break;
+    pub fn new(sibling_scopes: bool, calls: bool) -> Self {
+        Self { sibling_scopes, calls, ..Default::default() }
+    }
+
+    /// Adds an expression hash, `global` expressions don't reference local
+    /// bindings and can be deduped across sibling scopes.
+    pub fn add(&mut self, address: Address, hash: [u8; 20], global: bool) {
+        // Expressions in arrow function bodies can reference parameters, they
+        // are deduplicated only as a part of the whole arrow function.
+        if self.arrows > 0 {
+            return;
+        }
+        let start = self.isolated.last().copied().unwrap_or(0);
+        let mut original_address =
+            self.scopes[start..].iter().find_map(|scope| scope.get(&hash)).copied();
+        // Module wrappers are isolated from each other, so expressions aren't
+        // hoisted out of them.
+        let global = global && self.sibling_scopes && self.isolated.is_empty();
+        if original_address.is_none() && global {
+            original_address = self.module.get(&hash).copied();
+            if let Some(original_address) = original_address {
+                self.hoisted.insert(original_address);
+            }
+        }
+
+        if let Some(original_address) = original_address {
+            self.duplicates += 1;
+            if let Some(DedupeKind::Original(duplicates_count)) =
+                self.expressions.get_mut(&original_address)
+            {
+                *duplicates_count += 1;
             }
+            self.expressions.insert(address, DedupeKind::Duplicate(original_address));
+            self.log.push(address);
+        } else if let Some(scope) = self.scopes.last_mut() {
+            scope.insert(hash, address);
+            if global {
+                self.module.insert(hash, address);
+            }
+            self.expressions.insert(address, DedupeKind::Original(0));
+            self.log.push(address);
         }
-        if original {
-            if let Some(scope) = self.scopes.last_mut() {
-                scope.insert(hash, address);
-                self.expressions.insert(address, DedupeKind::Original(0));
+    }
+
+    pub fn checkpoint(&self) -> usize {
+        self.log.len()
+    }
+
+    /// Removes expressions added after the checkpoint, it is used when
+    /// statements are removed, so that removed expressions aren't used as
+    /// originals.
+    pub fn rollback(&mut self, checkpoint: usize) {
+        for address in self.log.drain(checkpoint..).rev() {
+            match self.expressions.remove(&address) {
+                Some(DedupeKind::Original(_)) => {
+                    for scope in &mut self.scopes {
+                        scope.retain(|_, original_address| *original_address != address);
+                    }
+                    self.module.retain(|_, original_address| *original_address != address);
+                    self.hoisted.remove(&address);
+                }
+                Some(DedupeKind::Duplicate(original_address)) => {
+                    self.duplicates -= 1;
+                    if let Some(DedupeKind::Original(duplicates_count)) =
+                        self.expressions.get_mut(&original_address)
+                    {
+                        *duplicates_count -= 1;
+                    }
+                }
+                None => {}
             }
         }
     }
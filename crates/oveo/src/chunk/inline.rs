@@ -0,0 +1,143 @@
+use oxc_allocator::CloneIn;
+use oxc_ast::ast::*;
+use oxc_semantic::ReferenceFlags;
+use oxc_span::SPAN;
+use oxc_str::Ident;
+
+use crate::context::TraverseCtx;
+
+/// Arrow function that can be inlined at `inline()` call sites.
+///
+/// Only single-expression arrow functions with simple parameters are
+/// inlined, and their bodies can't have any free variables, calls or
+/// assignments.
+pub struct InlineFunction<'a> {
+    params: Vec<Ident<'a>>,
+    body: Expression<'a>,
+}
+
+impl<'a> InlineFunction<'a> {
+    // (a, b) => a + b
+    pub fn from_arrow(expr: &ArrowFunctionExpression<'a>, ctx: &TraverseCtx<'a>) -> Option<Self> {
+        if !expr.expression || expr.r#async || expr.params.rest.is_some() {
+            return None;
+        }
+        let mut params = Vec::with_capacity(expr.params.items.len());
+        for p in &expr.params.items {
+            let BindingPattern::BindingIdentifier(id) = &p.pattern else {
+                return None;
+            };
+            if p.initializer.is_some() {
+                return None;
+            }
+            params.push(id.name);
+        }
+        let Some(Statement::ExpressionStatement(stmt)) = expr.body.statements.first() else {
+            return None;
+        };
+        if !is_inlinable(&stmt.expression, &params) {
+            return None;
+        }
+        Some(Self { params, body: stmt.expression.clone_in(ctx.ast.allocator) })
+    }
+
+    /// Substitutes function body with arguments.
+    ///
+    /// Arguments should be literals or identifiers, so that they can be
+    /// duplicated or removed without changing side effects.
+    pub fn inline(
+        &self,
+        args: &[Argument<'a>],
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        if args.len() != self.params.len()
+            || !args.iter().all(|arg| {
+                arg.as_expression()
+                    .is_some_and(|arg| arg.is_literal() || arg.is_identifier_reference())
+            })
+        {
+            return None;
+        }
+        let mut body = self.body.clone_in(ctx.ast.allocator);
+        substitute(&mut body, &self.params, args, ctx);
+        for arg in args.iter() {
+            if let Argument::Identifier(id) = arg {
+                ctx.delete_reference_for_identifier(id);
+            }
+        }
+        Some(body)
+    }
+}
+
+fn is_inlinable(expr: &Expression<'_>, params: &[Ident<'_>]) -> bool {
+    match expr {
+        Expression::BooleanLiteral(_)
+        | Expression::NullLiteral(_)
+        | Expression::NumericLiteral(_)
+        | Expression::BigIntLiteral(_)
+        | Expression::StringLiteral(_) => true,
+        Expression::Identifier(id) => params.contains(&id.name),
+        Expression::ParenthesizedExpression(expr) => is_inlinable(&expr.expression, params),
+        Expression::UnaryExpression(expr) => {
+            expr.operator != UnaryOperator::Delete && is_inlinable(&expr.argument, params)
+        }
+        Expression::BinaryExpression(expr) => {
+            is_inlinable(&expr.left, params) && is_inlinable(&expr.right, params)
+        }
+        Expression::LogicalExpression(expr) => {
+            is_inlinable(&expr.left, params) && is_inlinable(&expr.right, params)
+        }
+        Expression::ConditionalExpression(expr) => {
+            is_inlinable(&expr.test, params)
+                && is_inlinable(&expr.consequent, params)
+                && is_inlinable(&expr.alternate, params)
+        }
+        Expression::TemplateLiteral(tpl) => tpl.expressions.iter().all(|e| is_inlinable(e, params)),
+        _ => false,
+    }
+}
+
+// Body was checked with `is_inlinable()`, so all identifiers are parameters.
+fn substitute<'a>(
+    expr: &mut Expression<'a>,
+    params: &[Ident<'a>],
+    args: &[Argument<'a>],
+    ctx: &mut TraverseCtx<'a>,
+) {
+    match expr {
+        Expression::Identifier(id) => {
+            if let Some(i) = params.iter().position(|p| *p == id.name) {
+                let arg = args[i].to_expression();
+                *expr = if let Expression::Identifier(arg) = arg {
+                    let symbol_id = ctx.scoping().get_reference(arg.reference_id()).symbol_id();
+                    ctx.create_ident_expr(SPAN, arg.name, symbol_id, ReferenceFlags::Read)
+                } else {
+                    arg.clone_in(ctx.ast.allocator)
+                };
+            }
+        }
+        Expression::ParenthesizedExpression(expr) => {
+            substitute(&mut expr.expression, params, args, ctx);
+        }
+        Expression::UnaryExpression(expr) => substitute(&mut expr.argument, params, args, ctx),
+        Expression::BinaryExpression(expr) => {
+            substitute(&mut expr.left, params, args, ctx);
+            substitute(&mut expr.right, params, args, ctx);
+        }
+        Expression::LogicalExpression(expr) => {
+            substitute(&mut expr.left, params, args, ctx);
+            substitute(&mut expr.right, params, args, ctx);
+        }
+        Expression::ConditionalExpression(expr) => {
+            substitute(&mut expr.test, params, args, ctx);
+            substitute(&mut expr.consequent, params, args, ctx);
+            substitute(&mut expr.alternate, params, args, ctx);
+        }
+        Expression::TemplateLiteral(tpl) => {
+            for e in tpl.expressions.iter_mut() {
+                substitute(e, params, args, ctx);
+            }
+        }
+        _ => {}
+    }
+}
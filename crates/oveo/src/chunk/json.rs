@@ -0,0 +1,130 @@
+//! Serializes object and array literals into JSON strings.
+//!
+use oxc_ast::{AstBuilder, ast::*};
+use oxc_syntax::operator::UnaryOperator;
+
+use crate::property_names::LocalPropertyMap;
+
+/// Serializes an object or array literal into a JSON string.
+///
+/// Returns `None` when literal contains values that can't be represented in
+/// JSON (functions, `undefined`, regexps, identifiers, holes, etc).
+pub fn literal_to_json<'a>(
+    node: &Expression<'a>,
+    property_map: Option<&mut LocalPropertyMap<'a, '_>>,
+    ast: &AstBuilder<'a>,
+) -> Option<String> {
+    let mut w = JsonWriter { out: String::new(), property_map, ast };
+    w.write_expr(node)?;
+    Some(w.out)
+}
+
+struct JsonWriter<'a, 'b, 'ctx> {
+    out: String,
+    property_map: Option<&'b mut LocalPropertyMap<'a, 'ctx>>,
+    ast: &'b AstBuilder<'a>,
+}
+
+impl<'a> JsonWriter<'a, '_, '_> {
+    fn write_expr(&mut self, node: &Expression<'a>) -> Option<()> {
+        match node {
+            Expression::NullLiteral(_) => self.out.push_str("null"),
+            Expression::BooleanLiteral(node) => {
+                self.out.push_str(if node.value { "true" } else { "false" });
+            }
+            Expression::NumericLiteral(node) => self.write_number(node.value)?,
+            Expression::StringLiteral(node) => {
+                if node.lone_surrogates {
+                    return None;
+                }
+                self.write_string(node.value.as_str());
+            }
+            // `-1`
+            Expression::UnaryExpression(node) => {
+                if node.operator != UnaryOperator::UnaryNegation {
+                    return None;
+                }
+                let Expression::NumericLiteral(n) = &node.argument else {
+                    return None;
+                };
+                // `JSON.parse("-0")` is `-0`, but it is simpler to just skip it.
+                if n.value == 0.0 {
+                    return None;
+                }
+                self.write_number(-n.value)?;
+            }
+            Expression::ArrayExpression(node) => {
+                self.out.push('[');
+                for (i, item) in node.elements.iter().enumerate() {
+                    if i > 0 {
+                        self.out.push(',');
+                    }
+                    // Spread elements and holes aren't supported
+                    self.write_expr(item.as_expression()?)?;
+                }
+                self.out.push(']');
+            }
+            Expression::ObjectExpression(node) => {
+                self.out.push('{');
+                for (i, prop) in node.properties.iter().enumerate() {
+                    if i > 0 {
+                        self.out.push(',');
+                    }
+                    let ObjectPropertyKind::ObjectProperty(prop) = prop else {
+                        return None;
+                    };
+                    if prop.kind != PropertyKind::Init
+                        || prop.method
+                        || prop.shorthand
+                        || prop.computed
+                    {
+                        return None;
+                    }
+                    let name = match &prop.key {
+                        PropertyKey::StaticIdentifier(key) => key.name.into(),
+                        PropertyKey::StringLiteral(key) if !key.lone_surrogates => key.value,
+                        _ => return None,
+                    };
+                    // `{ __proto__: v }` sets a prototype in object literals,
+                    // but `JSON.parse()` creates an own property.
+                    if name == "__proto__" {
+                        return None;
+                    }
+                    if let PropertyKey::StaticIdentifier(_) = &prop.key
+                        && let Some(property_map) = self.property_map.as_deref_mut()
+                        && let Some(v) = property_map.get(name, self.ast)
+                    {
+                        self.write_string(v.as_str());
+                    } else {
+                        self.write_string(name.as_str());
+                    }
+                    self.out.push(':');
+                    self.write_expr(&prop.value)?;
+                }
+                self.out.push('}');
+            }
+            Expression::ParenthesizedExpression(node) => self.write_expr(&node.expression)?,
+            _ => return None,
+        }
+        Some(())
+    }
+
+    fn write_number(&mut self, value: f64) -> Option<()> {
+        if !value.is_finite() {
+            return None;
+        }
+        if value.fract() == 0.0 && value.abs() < 9007199254740992.0 {
+            self.out.push_str(&(value as i64).to_string());
+        } else {
+            self.out.push_str(&serde_json::Number::from_f64(value)?.to_string());
+        }
+        Some(())
+    }
+
+    fn write_string(&mut self, s: &str) {
+        // Serializing a `str` into a `String` never fails.
+        if let Ok(s) = serde_json::to_string(s) {
+            self.out.push_str(&s);
+        }
+    }
+}
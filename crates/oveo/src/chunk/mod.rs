@@ -1,36 +1,87 @@
-use oxc_allocator::{Address, Allocator, GetAddress, Vec as ArenaVec};
+use oxc_allocator::{
+    Address, Allocator, Box as ArenaBox, FromIn, GetAddress, TakeIn, Vec as ArenaVec,
+};
 use oxc_ast::{NONE, ast::*};
-use oxc_semantic::{ReferenceFlags, Scoping, SymbolFlags, SymbolId};
-use oxc_span::SPAN;
-use oxc_traverse::{BoundIdentifier, Traverse, traverse_mut};
-use rustc_hash::FxHashMap;
+use oxc_semantic::{ReferenceFlags, ScopeId, Scoping, SymbolFlags, SymbolId};
+use oxc_span::{GetSpan, SPAN, Span};
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse, traverse_mut};
+use rustc_hash::{FxHashMap, FxHashSet};
+use url::Url;
 
+mod const_propagation;
+mod dce;
 mod dedupe;
+mod inline;
+mod json;
+mod singletons;
 
 use crate::{
-    OptimizerOptions,
+    GlobalsScope, OptimizerOptions, OptimizerStats,
     annotation::Annotation,
-    chunk::dedupe::{DedupeKind, DedupeState, dedupe_hash},
+    chunk::{
+        const_propagation::ConstPropagation,
+        dce::remove_unreachable_statements,
+        dedupe::{
+            DedupeKind, DedupeState, constant_arguments_hash, dedupe_hash, dedupe_hash_frozen,
+        },
+        inline::InlineFunction,
+        json::literal_to_json,
+        singletons::{SharedSingleton, SharedSingletons},
+    },
     context::{TraverseCtx, TraverseCtxState},
-    globals::{GlobalValue, get_global_value},
+    debug_comments::DebugComments,
+    debug_names::{base_name, expr_name, uid_name},
+    globals::{GlobalCategory, GlobalValue, get_global_path, get_global_static, get_global_value},
     property_names::LocalPropertyMap,
     statements::Statements,
+    warnings::Warnings,
 };
 
+/// Returns warnings for annotated expressions that couldn't be optimized.
+#[expect(clippy::too_many_arguments)]
 pub fn optimize_chunk<'a, 'ctx>(
     program: &mut Program<'a>,
     options: &OptimizerOptions,
     property_map: LocalPropertyMap<'a, 'ctx>,
     allocator: &'a Allocator,
     scoping: Scoping,
-) {
-    let mut optimizer = ChunkOptimizer::new(options, property_map);
+    debug_comments: &mut DebugComments,
+    stats: &mut OptimizerStats,
+    warnings: Warnings<'ctx>,
+) -> Vec<String> {
+    let mut optimizer =
+        ChunkOptimizer::new(options, property_map, program.source_text, debug_comments, warnings);
     let scoping =
         traverse_mut(&mut optimizer, allocator, program, scoping, TraverseCtxState::default());
+    stats.globals_hoisted = optimizer.globals_ids.len() as u32;
+    stats.properties_renamed = optimizer.properties_renamed;
+    let mut singletons = SharedSingletons::new(options.debug_names, optimizer.debug_comments);
+    for ((scope_id, global, _), constructions) in &optimizer.singleton_constructions {
+        if constructions.len() > 1 {
+            let callee_id = optimizer.globals_ids[&(*scope_id, *global)].clone();
+            let callee_decl = optimizer.globals_decls[&callee_id.symbol_id];
+            singletons.add(
+                SharedSingleton { scope_id: *scope_id, callee_id, callee_decl, uid: None },
+                constructions,
+            );
+        }
+    }
+    let scoping = if singletons.is_empty() {
+        scoping
+    } else {
+        traverse_mut(&mut singletons, allocator, program, scoping, TraverseCtxState::default())
+    };
     if options.dedupe && optimizer.dedupe.duplicates > 0 {
-        let mut dedupe = Dedupe::new(optimizer.dedupe);
+        let mut dedupe = Dedupe::new(
+            optimizer.dedupe,
+            program.source_text,
+            options.debug_names,
+            singletons.debug_comments,
+        );
         traverse_mut(&mut dedupe, allocator, program, scoping, TraverseCtxState::default());
+        stats.duplicates_collapsed = dedupe.collapsed;
     }
+    optimizer.warnings.into_messages()
 }
 
 struct ChunkOptimizer<'a, 'ctx> {
@@ -39,13 +90,45 @@ struct ChunkOptimizer<'a, 'ctx> {
     statements: Statements<'a>,
     annotations: Vec<AnnotatedExpr>,
     globals_symbols: FxHashMap<SymbolId, &'ctx GlobalValue>,
-    globals_ids: FxHashMap<*const GlobalValue, BoundIdentifier<'a>>,
-    singletons: FxHashMap<*const GlobalValue, BoundIdentifier<'a>>,
+    globals_ids: FxHashMap<(ScopeId, *const GlobalValue), BoundIdentifier<'a>>,
+    /// Declarations of hoisted globals.
+    globals_decls: FxHashMap<SymbolId, Address>,
+    singletons: FxHashMap<(ScopeId, *const GlobalValue), BoundIdentifier<'a>>,
+    /// Constructions with constant arguments keyed by the hash of their
+    /// arguments, they are shared only when there are at least two
+    /// constructions with identical arguments.
+    singleton_constructions: FxHashMap<(ScopeId, *const GlobalValue, [u8; 20]), Vec<Address>>,
+    /// Function bodies where globals are hoisted with `GlobalsScope::Nearest`,
+    /// `None` for arrow functions with expression bodies.
+    function_bodies: Vec<Option<GlobalsTarget>>,
+    /// Constructors from `GlobalsOptions::singleton_constructors`.
+    singleton_constructors: FxHashSet<*const GlobalValue>,
+    /// `keyObject()` expressions with renamed property keys.
+    key_objects: FxHashSet<Address>,
+    /// Const bindings that reference `keyObject()` objects.
+    key_object_symbols: FxHashSet<SymbolId>,
+    /// Const bindings with arrow functions that can be inlined.
+    inline_functions: FxHashMap<SymbolId, InlineFunction<'a>>,
+    const_propagation: ConstPropagation<'a>,
     dedupe: DedupeState,
+    /// Dedupe checkpoints for expression statements that can be removed.
+    dedupe_checkpoints: Vec<usize>,
+    /// Number of renamed property names.
+    properties_renamed: u32,
+    source_text: &'a str,
+    /// Annotated expressions that couldn't be optimized.
+    warnings: Warnings<'ctx>,
+    debug_comments: &'ctx mut DebugComments,
 }
 
 impl<'a, 'ctx> ChunkOptimizer<'a, 'ctx> {
-    fn new(options: &'ctx OptimizerOptions, property_map: LocalPropertyMap<'a, 'ctx>) -> Self {
+    fn new(
+        options: &'ctx OptimizerOptions,
+        property_map: LocalPropertyMap<'a, 'ctx>,
+        source_text: &'a str,
+        debug_comments: &'ctx mut DebugComments,
+        warnings: Warnings<'ctx>,
+    ) -> Self {
         Self {
             options,
             property_map,
@@ -53,68 +136,386 @@ impl<'a, 'ctx> ChunkOptimizer<'a, 'ctx> {
             annotations: Vec::new(),
             globals_symbols: FxHashMap::default(),
             globals_ids: FxHashMap::default(),
+            globals_decls: FxHashMap::default(),
             singletons: FxHashMap::default(),
-            dedupe: DedupeState::default(),
+            singleton_constructions: FxHashMap::default(),
+            function_bodies: Vec::new(),
+            singleton_constructors: options
+                .globals
+                .singleton_constructors
+                .iter()
+                .filter_map(|name| get_global_path(name))
+                .map(|v| v as *const _)
+                .collect(),
+            key_objects: FxHashSet::default(),
+            key_object_symbols: FxHashSet::default(),
+            inline_functions: FxHashMap::default(),
+            const_propagation: ConstPropagation::default(),
+            dedupe: DedupeState::new(options.dedupe_sibling_scopes, options.dedupe_calls),
+            dedupe_checkpoints: Vec::new(),
+            properties_renamed: 0,
+            source_text,
+            warnings,
+            debug_comments,
+        }
+    }
+
+    /// Adds a warning with a location of the span, e.g. `unable to inline
+    /// call at 3:10`.
+    fn warn(&mut self, message: &str, span: Span) {
+        self.warnings.warn(self.source_text, message, span);
+    }
+
+    /// Returns a scope where global values should be hoisted.
+    fn globals_target(&self, ctx: &TraverseCtx<'a>) -> GlobalsTarget {
+        self.function_bodies
+            .iter()
+            .rev()
+            .find_map(|v| *v)
+            .unwrap_or(GlobalsTarget { scope_id: ctx.scoping().root_scope_id(), statement: None })
+    }
+
+    /// Resolves global values, including references to hoisted globals.
+    fn resolve_global(
+        &self,
+        node: &Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<&'ctx GlobalValue> {
+        match node {
+            Expression::Identifier(id) => {
+                match ctx.scoping().get_reference(id.reference_id()).symbol_id() {
+                    Some(symbol_id) => self.globals_symbols.get(&symbol_id).copied(),
+                    None => get_global_value(GlobalCategory::ALL, id.name.as_str()),
+                }
+            }
+            Expression::StaticMemberExpression(expr) => {
+                self.resolve_global(&expr.object, ctx)?.statics.get(expr.property.name.as_str())
+            }
+            _ => None,
+        }
+    }
+
+    /// Collects arrow functions assigned to top-level const bindings that can
+    /// be inlined at `inline()` call sites.
+    ///
+    /// `const add = (a, b) => a + b`
+    fn collect_inline_functions(&mut self, program: &Program<'a>, ctx: &TraverseCtx<'a>) {
+        for stmt in &program.body {
+            let decl = match stmt {
+                Statement::VariableDeclaration(decl) => decl,
+                Statement::ExportNamedDeclaration(export) => match &export.declaration {
+                    Some(Declaration::VariableDeclaration(decl)) => decl,
+                    _ => continue,
+                },
+                _ => continue,
+            };
+            if decl.kind != VariableDeclarationKind::Const {
+                continue;
+            }
+            for d in &decl.declarations {
+                if let (
+                    BindingPattern::BindingIdentifier(id),
+                    Some(Expression::ArrowFunctionExpression(f)),
+                ) = (&d.id, &d.init)
+                    && let Some(f) = InlineFunction::from_arrow(f, ctx)
+                {
+                    self.inline_functions.insert(id.symbol_id(), f);
+                }
+            }
+        }
+    }
+
+    /// Collects `keyObject()` objects assigned to top-level const bindings and
+    /// their aliases.
+    ///
+    /// `const o = keyObject({ a: 1 })`
+    /// `const p = o`
+    fn collect_key_objects(&mut self, program: &Program<'a>, ctx: &TraverseCtx<'a>) {
+        for stmt in &program.body {
+            let decl = match stmt {
+                Statement::VariableDeclaration(decl) => decl,
+                Statement::ExportNamedDeclaration(export) => match &export.declaration {
+                    Some(Declaration::VariableDeclaration(decl)) => decl,
+                    _ => continue,
+                },
+                _ => continue,
+            };
+            if decl.kind != VariableDeclarationKind::Const {
+                continue;
+            }
+            for d in &decl.declarations {
+                let (BindingPattern::BindingIdentifier(id), Some(init)) = (&d.id, &d.init) else {
+                    continue;
+                };
+                match init {
+                    Expression::CallExpression(expr) => {
+                        if is_key_object_annotation(expr, ctx) {
+                            self.key_objects.insert(init.address());
+                            self.key_object_symbols.insert(id.symbol_id());
+                        }
+                    }
+                    Expression::Identifier(alias) => {
+                        if let Some(symbol_id) =
+                            ctx.scoping().get_reference(alias.reference_id()).symbol_id()
+                            && self.key_object_symbols.contains(&symbol_id)
+                        {
+                            self.key_object_symbols.insert(id.symbol_id());
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    /// Checks if expression is a reference to a `keyObject()` binding.
+    fn is_key_object_ref(&self, node: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        if let Expression::Identifier(id) = node
+            && let Some(symbol_id) = ctx.scoping().get_reference(id.reference_id()).symbol_id()
+        {
+            return self.key_object_symbols.contains(&symbol_id);
+        }
+        false
+    }
+
+    /// Renames static property keys `{ a: 1, b() {} }`.
+    fn rename_object_keys(&mut self, node: &mut ObjectExpression<'a>, ctx: &TraverseCtx<'a>) {
+        for prop in &mut node.properties {
+            if let ObjectPropertyKind::ObjectProperty(prop) = prop
+                && let PropertyKey::StaticIdentifier(key) = &mut prop.key
+                && let Some(v) = self.rename_property(key.name.into(), ctx)
+            {
+                key.name = v.into();
+                // `{ a }` => `{ b: a }`
+                prop.shorthand = false;
+            }
         }
     }
+
+    /// Inlines `f(args)` when `f` is a const binding with an inlinable arrow
+    /// function.
+    fn inline_call(
+        &mut self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let Expression::CallExpression(call) = expr else {
+            return None;
+        };
+        let Expression::Identifier(callee) = &call.callee else {
+            return None;
+        };
+        let symbol_id = ctx.scoping().get_reference(callee.reference_id()).symbol_id()?;
+        let f = self.inline_functions.get(&symbol_id)?;
+        let body = f.inline(&call.arguments, ctx)?;
+        ctx.scoping_mut().delete_resolved_reference(symbol_id, callee.reference_id());
+        Some(body)
+    }
+
+    /// Creates `Object.freeze(expr)`, `Object.freeze` is hoisted when hoisting
+    /// of globals is enabled.
+    fn create_object_freeze(
+        &mut self,
+        expr: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut object = ctx.create_unbound_ident_expr(SPAN, "Object".into(), ReferenceFlags::Read);
+        self.exit_expression(&mut object, ctx);
+        let mut callee = Expression::StaticMemberExpression(StaticMemberExpression::boxed(
+            SPAN,
+            object,
+            IdentifierName::new(SPAN, "freeze", ctx),
+            false,
+            ctx,
+        ));
+        self.exit_expression(&mut callee, ctx);
+        Expression::CallExpression(CallExpression::boxed(
+            SPAN,
+            callee,
+            NONE,
+            ArenaVec::from_value_in(expr.into(), ctx),
+            false,
+            ctx,
+        ))
+    }
+
+    /// Returns a new name for a property that should be renamed.
+    fn rename_property(&mut self, key: Str<'a>, ctx: &TraverseCtx<'a>) -> Option<Str<'a>> {
+        let v = self.property_map.get(key, &ctx.ast)?;
+        self.properties_renamed += 1;
+        Some(v)
+    }
+
+    /// Checks if property names should be renamed everywhere.
+    fn rename_all_properties(&self) -> bool {
+        self.options.rename_properties && !self.options.rename_key_objects_only
+    }
+
+    /// Checks if callee is `Object.defineProperty` or `Reflect.defineProperty`.
+    ///
+    /// Keys in `Object.defineProperties` descriptor maps are identifier names
+    /// and they are already renamed as any other property name.
+    fn is_define_property(&self, callee: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        let Some(v) = self.resolve_global(callee, ctx) else {
+            return false;
+        };
+        ["Object", "Reflect"].into_iter().any(|name| {
+            get_global_static(name, "defineProperty").is_some_and(|f| std::ptr::eq(f, v))
+        })
+    }
 }
 
 impl<'a, 'ctx> Traverse<'a, TraverseCtxState<'a>> for ChunkOptimizer<'a, 'ctx> {
+    fn enter_program(&mut self, node: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.rename_properties
+            && self.options.rename_key_objects_only
+            && !self.options.keep_annotations
+        {
+            self.collect_key_objects(node, ctx);
+        }
+        if self.options.inline {
+            self.collect_inline_functions(node, ctx);
+        }
+    }
+
     fn exit_program(&mut self, node: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
         self.statements.exit_program(node, ctx);
     }
 
+    fn enter_function_body(&mut self, node: &mut FunctionBody<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.globals.hoist && self.options.globals.scope == GlobalsScope::Nearest {
+            let scope_id = match ctx.parent() {
+                Ancestor::FunctionBody(f) => f.scope_id().get(),
+                Ancestor::ArrowFunctionExpressionBody(f) if !*f.expression() => f.scope_id().get(),
+                _ => None,
+            };
+            self.function_bodies.push(scope_id.zip(node.statements.first()).map(
+                |(scope_id, stmt)| GlobalsTarget { scope_id, statement: Some(stmt.address()) },
+            ));
+        }
+    }
+
+    fn exit_function_body(&mut self, _node: &mut FunctionBody<'a>, _ctx: &mut TraverseCtx<'a>) {
+        if self.options.globals.hoist && self.options.globals.scope == GlobalsScope::Nearest {
+            self.function_bodies.pop();
+        }
+    }
+
     fn enter_statements(
         &mut self,
         _node: &mut ArenaVec<'a, Statement<'a>>,
-        _ctx: &mut TraverseCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
     ) {
         if self.options.dedupe {
+            if self.options.dedupe_isolate_module_wrappers && is_module_wrapper_body(ctx) {
+                self.dedupe.isolated.push(self.dedupe.scopes.len());
+            }
             self.dedupe.scopes.push(FxHashMap::default());
         }
     }
 
     fn exit_statements(
         &mut self,
-        _node: &mut ArenaVec<'a, Statement<'a>>,
-        _ctx: &mut TraverseCtx<'a>,
+        node: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
     ) {
         if self.options.dedupe {
             self.dedupe.scopes.pop();
+            if self.dedupe.isolated.last() == Some(&self.dedupe.scopes.len()) {
+                self.dedupe.isolated.pop();
+            }
+        }
+        if self.options.dce {
+            remove_unreachable_statements(node, &self.statements, ctx);
+        }
+        self.statements.exit_statements(node, ctx); // update statements
+    }
+
+    fn enter_statement(&mut self, node: &mut Statement<'a>, _ctx: &mut TraverseCtx<'a>) {
+        if self.options.dedupe && matches!(node, Statement::ExpressionStatement(_)) {
+            self.dedupe_checkpoints.push(self.dedupe.checkpoint());
+        }
+    }
+
+    fn exit_statement(&mut self, node: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.dedupe && matches!(node, Statement::ExpressionStatement(_)) {
+            self.dedupe_checkpoints.pop();
+        }
+        if self.options.const_propagation {
+            self.const_propagation.exit_statement(node, ctx);
         }
     }
 
     fn enter_expression(&mut self, node: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
-        // Replaces `new URL("./url", import.meta.url).href` with an absolute URL.
+        // Replaces `new URL("./url", import.meta.url).href` and
+        // `import.meta.resolve("./url")` with an absolute URL.
         if let Some(base_url) = &self.options.url {
             if let Expression::StaticMemberExpression(expr) = node {
-                if expr.property.name == "href" {
-                    if let Expression::NewExpression(new_expr) = &mut expr.object {
-                        let args = &mut new_expr.arguments;
-                        if args.len() == 2 {
-                            let arg0 = &args[0];
-                            let arg1 = &args[1];
-                            if let Argument::StringLiteral(rel_url) = arg0
-                                && is_import_meta_url(arg1)
-                            {
-                                let rel_url = rel_url.value.as_str();
-                                *node = Expression::StringLiteral(StringLiteral::boxed(
-                                    SPAN,
-                                    Str::from_strs_array_in(
-                                        [base_url, rel_url.strip_prefix("./").unwrap_or(rel_url)],
-                                        ctx,
-                                    ),
-                                    None,
-                                    ctx,
-                                ));
-                            }
-                        }
-                    }
+                if expr.property.name == "href"
+                    && let Expression::NewExpression(new_expr) = &expr.object
+                    && let Some(url) = resolve_new_url(base_url, new_expr)
+                {
+                    *node = Expression::StringLiteral(StringLiteral::boxed(
+                        SPAN,
+                        Str::from_in(url.as_str(), ctx.ast.allocator),
+                        None,
+                        ctx,
+                    ));
+                }
+            } else if let Expression::CallExpression(call_expr) = node
+                && let Expression::StaticMemberExpression(callee) = &call_expr.callee
+                && is_import_meta_member(callee, "resolve")
+                && let [Argument::StringLiteral(rel_url)] = call_expr.arguments.as_slice()
+                && let Some(url) = resolve_url(base_url, rel_url.value.as_str())
+            {
+                // `import.meta.resolve("./url")`
+                *node = Expression::StringLiteral(StringLiteral::boxed(
+                    SPAN,
+                    Str::from_in(url.as_str(), ctx.ast.allocator),
+                    None,
+                    ctx,
+                ));
+            } else if let Expression::NewExpression(new_expr) = node
+                && let Some(url) = resolve_new_url(base_url, new_expr)
+            {
+                // `new URL("./url", import.meta.url)` without `.href`:
+                // - `new URL("https://host/url")` when base is an absolute URL.
+                // - `new URL("/url", import.meta.url)` when base is a path, the
+                //   same way with relative paths, e.g. `assets/url`.
+                new_expr.arguments[0] = Argument::StringLiteral(StringLiteral::boxed(
+                    SPAN,
+                    Str::from_in(url.as_str(), ctx.ast.allocator),
+                    None,
+                    ctx,
+                ));
+                if ["http:", "https:", "file:"].iter().any(|scheme| url.starts_with(scheme)) {
+                    new_expr.arguments.pop();
+                }
+            }
+        }
+
+        // Replaces large object and array literals with `JSON.parse("...")`.
+        if self.options.stringify_large_literals
+            && matches!(node, Expression::ObjectExpression(_) | Expression::ArrayExpression(_))
+        {
+            // Objects with renamed keys: `keyObject({ ... })`
+            let is_key_object = matches!(ctx.parent(), Ancestor::CallExpressionArguments(_))
+                && self.key_objects.contains(&ctx.parent().address());
+            let property_map =
+                (self.rename_all_properties() || is_key_object).then_some(&mut self.property_map);
+            if let Some(json) = literal_to_json(node, property_map, &ctx.ast) {
+                if json.len() >= self.options.stringify_threshold {
+                    *node = create_json_parse(&json, ctx);
                 }
             }
         }
 
-        if self.options.dedupe || self.options.rename_properties {
+        if (self.options.dedupe
+            || self.options.rename_properties
+            || self.options.pure
+            || self.options.inline)
+            && !self.options.keep_annotations
+        {
             // Unwraps `__oveo__()` expressions and adds annotation to the stack.
             let address = node.address();
             if let Expression::CallExpression(expr) = node {
@@ -128,6 +529,8 @@ impl<'a, 'ctx> Traverse<'a, TraverseCtxState<'a>> for ChunkOptimizer<'a, 'ctx> {
                                 address,
                                 annotation: Annotation::new(flags.value as u32),
                             });
+                        } else {
+                            self.warn("invalid annotation flags", flags.span());
                         }
                     }
                 }
@@ -136,6 +539,9 @@ impl<'a, 'ctx> Traverse<'a, TraverseCtxState<'a>> for ChunkOptimizer<'a, 'ctx> {
     }
 
     fn exit_expression(&mut self, node: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.const_propagation {
+            self.const_propagation.exit_expression(node, &self.statements, ctx);
+        }
         if self.options.globals.hoist {
             'hoist_globals: {
                 match node {
@@ -149,24 +555,47 @@ impl<'a, 'ctx> Traverse<'a, TraverseCtxState<'a>> for ChunkOptimizer<'a, 'ctx> {
                                 if !v.is_hoistable() {
                                     break 'hoist_globals;
                                 }
+                                let references = ctx
+                                    .scoping()
+                                    .root_unresolved_references()
+                                    .get(&expr.name)
+                                    .map_or(&[][..], |r| r.as_slice());
+                                if references.len() < self.options.globals.min_uses as usize {
+                                    break 'hoist_globals;
+                                }
+                                if self.options.globals.no_hoist_in_top_scope {
+                                    let root_scope_id = ctx.scoping().root_scope_id();
+                                    if references.iter().all(|id| {
+                                        ctx.scoping().get_reference(*id).scope_id() == root_scope_id
+                                    }) {
+                                        break 'hoist_globals;
+                                    }
+                                }
+                                let target = self.globals_target(ctx);
                                 let uid = self
                                     .globals_ids
-                                    .entry(v as *const _)
+                                    .entry((target.scope_id, v as *const _))
                                     .or_insert_with(|| {
-                                        let uid = ctx.generate_uid_in_root_scope(
-                                            "_GLOBAL_",
+                                        let name =
+                                            uid_name(self.options.debug_names, "_GLOBAL_", || {
+                                                expr.name.to_string()
+                                            });
+                                        let uid = ctx.generate_uid(
+                                            &name,
+                                            target.scope_id,
                                             SymbolFlags::ConstVariable,
                                         );
                                         self.globals_symbols.insert(uid.symbol_id, v);
-                                        self.statements.insert_top_level_statement(
-                                            stmt_const_decl(
-                                                &uid,
-                                                Expression::Identifier(IdentifierReference::boxed(
-                                                    SPAN, expr.name, ctx,
-                                                )),
-                                                ctx,
-                                            ),
+                                        self.debug_comments.add(&uid.name, "global", &expr.name);
+                                        let decl = stmt_const_decl(
+                                            &uid,
+                                            Expression::Identifier(IdentifierReference::boxed(
+                                                SPAN, expr.name, ctx,
+                                            )),
+                                            ctx,
                                         );
+                                        self.globals_decls.insert(uid.symbol_id, decl.address());
+                                        target.insert(&self.statements, decl);
                                         uid
                                     })
                                     .clone();
@@ -174,9 +603,23 @@ impl<'a, 'ctx> Traverse<'a, TraverseCtxState<'a>> for ChunkOptimizer<'a, 'ctx> {
                             }
                         }
                     }
-                    Expression::StaticMemberExpression(expr) => {
-                        if let Expression::Identifier(object_id_expr) = &expr.object {
-                            // Replaces global.property with a reference to a const symbol.
+                    // Replaces `global.property` and `global["property"]` with a
+                    // reference to a const symbol.
+                    Expression::StaticMemberExpression(_)
+                    | Expression::ComputedMemberExpression(_) => {
+                        let (object, property) = match node {
+                            Expression::StaticMemberExpression(expr) => {
+                                (&expr.object, Str::from(expr.property.name))
+                            }
+                            Expression::ComputedMemberExpression(expr) => {
+                                let Expression::StringLiteral(property) = &expr.expression else {
+                                    break 'hoist_globals;
+                                };
+                                (&expr.object, property.value)
+                            }
+                            _ => unreachable!(),
+                        };
+                        if let Expression::Identifier(object_id_expr) = object {
                             if let Some(object_symbol_id) = ctx
                                 .scoping()
                                 .get_reference(object_id_expr.reference_id())
@@ -185,33 +628,52 @@ impl<'a, 'ctx> Traverse<'a, TraverseCtxState<'a>> for ChunkOptimizer<'a, 'ctx> {
                                 if let Some(global) =
                                     self.globals_symbols.get(&object_symbol_id).copied()
                                 {
-                                    if let Some(v) = global.statics.get(expr.property.name.as_str())
-                                    {
+                                    if let Some(v) = global.statics.get(property.as_str()) {
                                         if !v.is_hoistable() {
                                             break 'hoist_globals;
                                         }
-                                        let object_id = self
+                                        let target = self.globals_target(ctx);
+                                        let Some(object_id) = self
                                             .globals_ids
-                                            .get(&(global as *const _))
+                                            .get(&(target.scope_id, global as *const _))
                                             .cloned()
-                                            .unwrap();
+                                        else {
+                                            break 'hoist_globals;
+                                        };
                                         let uid = self
                                             .globals_ids
-                                            .entry(v as *const _)
+                                            .entry((target.scope_id, v as *const _))
                                             .or_insert_with(|| {
-                                                let uid = ctx.generate_uid_in_root_scope(
+                                                let name = uid_name(
+                                                    self.options.debug_names,
                                                     "_GLOBAL_",
+                                                    || {
+                                                        format!(
+                                                            "{}_{}",
+                                                            base_name(&object_id.name),
+                                                            property
+                                                        )
+                                                    },
+                                                );
+                                                let uid = ctx.generate_uid(
+                                                    &name,
+                                                    target.scope_id,
                                                     SymbolFlags::ConstVariable,
                                                 );
                                                 self.globals_symbols.insert(uid.symbol_id, v);
-                                                self.statements.insert_top_level_statement(
-                                                    create_static_member_decl(
-                                                        &uid,
-                                                        &object_id,
-                                                        expr.property.name.into(),
-                                                        ctx,
-                                                    ),
+                                                if let Some(object) =
+                                                    self.debug_comments.expr(&object_id.name)
+                                                {
+                                                    let path = format!("{object}.{property}");
+                                                    self.debug_comments
+                                                        .add(&uid.name, "global", &path);
+                                                }
+                                                let decl = create_static_member_decl(
+                                                    &uid, &object_id, property, ctx,
                                                 );
+                                                self.globals_decls
+                                                    .insert(uid.symbol_id, decl.address());
+                                                target.insert(&self.statements, decl);
                                                 uid
                                             })
                                             .clone();
@@ -230,28 +692,69 @@ impl<'a, 'ctx> Traverse<'a, TraverseCtxState<'a>> for ChunkOptimizer<'a, 'ctx> {
                                 .symbol_id()
                             {
                                 if let Some(&global) = self.globals_symbols.get(&object_symbol_id) {
-                                    if global.is_singleton_func() {
-                                        let uid = self
-                                            .singletons
-                                            .entry(global as *const _)
-                                            .or_insert_with(|| {
-                                                let callee_id =
-                                                    &self.globals_ids[&(global as *const _)];
-                                                let uid = ctx.generate_uid_in_root_scope(
-                                                    "_SINGLETON_",
-                                                    SymbolFlags::ConstVariable,
-                                                );
-                                                self.statements.insert_top_level_statement(
-                                                    create_new_expr(
-                                                        &uid,
-                                                        callee_id,
-                                                        ArenaVec::new_in(ctx),
-                                                        ctx,
-                                                    ),
-                                                );
-                                                uid
-                                            });
-                                        *node = uid.create_read_expression(ctx);
+                                    // Constructors with arguments are deduplicated only when
+                                    // all arguments are constant.
+                                    if self.options.globals.singletons
+                                        && !global.is_unique_func()
+                                        && (global.is_singleton_func()
+                                            || self
+                                                .singleton_constructors
+                                                .contains(&(global as *const _)))
+                                        && let Some(args_hash) =
+                                            constant_arguments_hash(&expr.arguments, ctx.scoping())
+                                    {
+                                        let target = self.globals_target(ctx);
+                                        if !expr.arguments.is_empty() {
+                                            // Constructions with arguments are shared in a
+                                            // separate pass.
+                                            self.singleton_constructions
+                                                .entry((
+                                                    target.scope_id,
+                                                    global as *const _,
+                                                    args_hash,
+                                                ))
+                                                .or_default()
+                                                .push(node.address());
+                                        } else {
+                                            let uid = self
+                                                .singletons
+                                                .entry((target.scope_id, global as *const _))
+                                                .or_insert_with(|| {
+                                                    let callee_id = &self.globals_ids
+                                                        [&(target.scope_id, global as *const _)];
+                                                    let name = uid_name(
+                                                        self.options.debug_names,
+                                                        "_SINGLETON_",
+                                                        || base_name(&callee_id.name).to_string(),
+                                                    );
+                                                    let uid = ctx.generate_uid(
+                                                        &name,
+                                                        target.scope_id,
+                                                        SymbolFlags::ConstVariable,
+                                                    );
+                                                    if let Some(callee) =
+                                                        self.debug_comments.expr(&callee_id.name)
+                                                    {
+                                                        let expr = format!("new {callee}()");
+                                                        self.debug_comments.add(
+                                                            &uid.name,
+                                                            "singleton",
+                                                            &expr,
+                                                        );
+                                                    }
+                                                    target.insert(
+                                                        &self.statements,
+                                                        create_new_expr(
+                                                            &uid,
+                                                            callee_id,
+                                                            ArenaVec::new_in(ctx),
+                                                            ctx,
+                                                        ),
+                                                    );
+                                                    uid
+                                                });
+                                            *node = uid.create_read_expression(ctx);
+                                        }
                                     }
                                 }
                             }
@@ -262,63 +765,348 @@ impl<'a, 'ctx> Traverse<'a, TraverseCtxState<'a>> for ChunkOptimizer<'a, 'ctx> {
             }
         }
 
+        // Annotates `new URL("./a", "http://h")` as pure.
+        if self.options.mark_pure_constructors {
+            if let Expression::NewExpression(expr) = node
+                && let Some(global) = self.resolve_global(&expr.callee, ctx)
+                && global.is_pure_func()
+                && expr
+                    .arguments
+                    .iter()
+                    .all(|arg| arg.as_expression().is_some_and(Expression::is_literal))
+                // `new URL("./a")` throws when URL can't be parsed.
+                && (!get_global_path("URL").is_some_and(|url| std::ptr::eq(url, global))
+                    || is_valid_url(&expr.arguments))
+            {
+                let parent = ctx.parent();
+                if parent.is_expression_statement() {
+                    // Removes unused pure expressions `new URL("./a", "http://h");`
+                    self.statements.remove_address(parent.address());
+                    if let Some(&checkpoint) = self.dedupe_checkpoints.last() {
+                        self.dedupe.rollback(checkpoint);
+                    }
+                } else {
+                    expr.pure = true;
+                }
+            }
+        }
+
+        // Renames property keys in `Object.defineProperty(obj, "key", desc)`.
+        if self.options.rename_properties {
+            if let Expression::CallExpression(expr) = node {
+                if self.is_define_property(&expr.callee, ctx)
+                    && (self.rename_all_properties()
+                        || expr.arguments.first().is_some_and(|arg| {
+                            arg.as_expression().is_some_and(|arg| self.is_key_object_ref(arg, ctx))
+                        }))
+                {
+                    if let Some(Argument::StringLiteral(key)) = expr.arguments.get_mut(1) {
+                        if let Some(v) = self.rename_property(key.value, ctx) {
+                            key.value = v;
+                        }
+                    }
+                }
+            }
+        }
+
         let address = node.address();
         if let Some(a) = self.annotations.pop_if(|a| a.address == address) {
             if self.options.dedupe && a.annotation.is_dedupe() {
                 if let Expression::CallExpression(expr) = node {
                     if let Some(arg0) = expr.arguments.pop() {
                         let arg0 = arg0.into_expression();
-                        let _ = dedupe_hash(&mut self.dedupe, &arg0, ctx.scoping());
+                        // Nested annotations `hoist(dedupe(expr))` are hashed only once.
+                        if !self.dedupe.expressions.contains_key(&arg0.address())
+                            && dedupe_hash(&mut self.dedupe, &arg0, ctx.scoping()).is_none()
+                            && !a.annotation.is_hoisted()
+                        {
+                            self.warn("unable to dedupe expression", arg0.span());
+                        }
                         *node = arg0;
                         return;
                     }
                 }
+            } else if self.options.dedupe && a.annotation.is_freeze() {
+                if let Expression::CallExpression(expr) = node {
+                    if let Some(arg0) = expr.arguments.pop() {
+                        let arg0 = arg0.into_expression();
+                        let span = arg0.span();
+                        let frozen = self.create_object_freeze(arg0, ctx);
+                        if dedupe_hash_frozen(&mut self.dedupe, &frozen, ctx.scoping()).is_none() {
+                            self.warn("unable to dedupe frozen object", span);
+                        }
+                        *node = frozen;
+                        return;
+                    }
+                }
             } else if self.options.rename_properties && a.annotation.is_key() {
                 if let Expression::CallExpression(expr) = node {
                     if let Some(arg0) = expr.arguments.pop() {
                         let mut arg0 = arg0.into_expression();
-                        if let Expression::StringLiteral(expr) = &mut arg0 {
-                            if let Some(v) = self.property_map.get(expr.value, &ctx.ast) {
-                                expr.value = v;
+                        match &mut arg0 {
+                            Expression::StringLiteral(expr) => {
+                                if let Some(v) = self.rename_property(expr.value, ctx) {
+                                    expr.value = v;
+                                }
                             }
+                            // Templates without expressions: key(`a`)
+                            Expression::TemplateLiteral(tpl) if tpl.expressions.is_empty() => {
+                                if let Some(cooked) = tpl.quasis[0].value.cooked
+                                    && let Some(v) = self.rename_property(cooked, ctx)
+                                {
+                                    arg0 = Expression::StringLiteral(StringLiteral::boxed(
+                                        tpl.span, v, None, ctx,
+                                    ));
+                                }
+                            }
+                            _ => {
+                                self.warn(
+                                    "unable to rename non-constant property key",
+                                    arg0.span(),
+                                );
+                            }
+                        }
+                        *node = arg0;
+                        return;
+                    }
+                }
+            } else if self.options.rename_properties && a.annotation.is_key_object() {
+                if let Expression::CallExpression(expr) = node {
+                    if let Some(arg0) = expr.arguments.pop() {
+                        let mut arg0 = arg0.into_expression();
+                        if self.key_objects.contains(&address)
+                            && let Expression::ObjectExpression(expr) = &mut arg0
+                        {
+                            self.rename_object_keys(expr, ctx);
+                        } else if self.options.rename_key_objects_only {
+                            // Objects that aren't assigned to top-level const
+                            // bindings can't be tracked.
+                            self.warn("unable to rename keys of keyObject() value", arg0.span());
                         }
                         *node = arg0;
                         return;
                     }
                 }
+            } else if self.options.inline && a.annotation.is_inline() {
+                if let Expression::CallExpression(expr) = node {
+                    if let Some(arg0) = expr.arguments.pop() {
+                        let mut arg0 = arg0.into_expression();
+                        if let Some(inlined) = self.inline_call(&mut arg0, ctx) {
+                            arg0 = inlined;
+                        } else {
+                            self.warn("unable to inline call", arg0.span());
+                        }
+                        *node = arg0;
+                        return;
+                    }
+                }
+            } else if self.options.pure && a.annotation.is_pure() {
+                if let Expression::CallExpression(expr) = node {
+                    if let Some(arg0) = expr.arguments.pop() {
+                        let mut arg0 = arg0.into_expression();
+                        let parent = ctx.parent();
+                        if parent.is_expression_statement() {
+                            // Removes unused pure expressions `pure(expr);`
+                            self.statements.remove_address(parent.address());
+                            if let Some(&checkpoint) = self.dedupe_checkpoints.last() {
+                                self.dedupe.rollback(checkpoint);
+                            }
+                        } else if let Expression::CallExpression(call_expr) = &mut arg0 {
+                            // Adds `/* @__PURE__ */` comment for minifiers
+                            call_expr.pure = true;
+                        }
+                        *node = arg0;
+                        return;
+                    }
+                }
+            }
+            self.warn("unable to resolve annotation", node.span());
+            let parent = ctx.parent();
+            if parent.is_expression_statement() {
+                // Removes `void 0;` statements
+                self.statements.remove_address(parent.address());
+                if let Some(&checkpoint) = self.dedupe_checkpoints.last() {
+                    self.dedupe.rollback(checkpoint);
+                }
             }
             *node = Expression::new_void_0(SPAN, ctx);
         }
     }
 
+    // Renames `o.key` when `o` is a `keyObject()` binding.
+    fn exit_static_member_expression(
+        &mut self,
+        node: &mut StaticMemberExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.rename_properties
+            && self.options.rename_key_objects_only
+            && self.is_key_object_ref(&node.object, ctx)
+            && let Some(v) = self.rename_property(node.property.name.into(), ctx)
+        {
+            node.property.name = v.into();
+        }
+    }
+
+    // Quoted property keys `{ "key": v }` are string literals, so they are
+    // preserved unless they are annotated with `key()`.
+    //
+    // Method and accessor keys `get key() {}` are identifier names, so they
+    // are renamed consistently with member expressions `obj.key`.
     fn exit_identifier_name(&mut self, node: &mut IdentifierName<'a>, ctx: &mut TraverseCtx<'a>) {
-        if self.options.rename_properties {
-            if let Some(v) = self.property_map.get(node.name.into(), &ctx.ast) {
+        if self.rename_all_properties() {
+            match ctx.parent() {
+                Ancestor::StaticMemberExpressionProperty(expr)
+                    if is_reserved_property_context(expr.object()) =>
+                {
+                    return;
+                }
+                // `const { userId } = el.dataset`
+                Ancestor::BindingPropertyKey(_)
+                | Ancestor::AssignmentTargetPropertyPropertyName(_)
+                    if is_reserved_pattern_context(ctx.ancestor(2)) =>
+                {
+                    return;
+                }
+                _ => {}
+            }
+            if let Some(v) = self.rename_property(node.name.into(), ctx) {
                 node.name = v.into();
             }
         }
     }
+
+    // Private names `#key_` in declarations, `this.#key_` accesses and
+    // `#key_ in obj` checks. Name doesn't include the `#` prefix.
+    fn exit_private_identifier(
+        &mut self,
+        node: &mut PrivateIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.rename_all_properties()
+            && let Some(v) = self.rename_property(node.name.into(), ctx)
+        {
+            node.name = v.into();
+        }
+    }
+
+    // Shorthand properties with renamed keys are expanded, so that values
+    // still reference the same bindings: `{ a }` => `{ b: a }`.
+    fn exit_object_property(&mut self, node: &mut ObjectProperty<'a>, _ctx: &mut TraverseCtx<'a>) {
+        if node.shorthand
+            && let PropertyKey::StaticIdentifier(key) = &node.key
+            && let Expression::Identifier(value) = &node.value
+            && key.name != value.name
+        {
+            node.shorthand = false;
+        }
+    }
+
+    // `const { a = 1 } = o` => `const { b: a = 1 } = o`
+    fn exit_binding_property(
+        &mut self,
+        node: &mut BindingProperty<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+        if node.shorthand
+            && let PropertyKey::StaticIdentifier(key) = &node.key
+            && let Some(value) = node.value.get_binding_identifier()
+            && key.name != value.name
+        {
+            node.shorthand = false;
+        }
+    }
+
+    // Shorthand assignment targets don't have keys, so they are replaced with
+    // properties: `({ a = 1 } = o)` => `({ b: a = 1 } = o)`.
+    fn exit_assignment_target_property(
+        &mut self,
+        node: &mut AssignmentTargetProperty<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.rename_all_properties()
+            && let AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(prop) = node
+            && !is_reserved_pattern_context(ctx.ancestor(1))
+            && let Some(v) = self.rename_property(prop.binding.name.into(), ctx)
+        {
+            let span = prop.span;
+            let id = ArenaBox::new_in(prop.binding.take_in(ctx), ctx);
+            let binding = match prop.init.take() {
+                Some(init) => AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(
+                    AssignmentTargetWithDefault::boxed(
+                        span,
+                        AssignmentTarget::AssignmentTargetIdentifier(id),
+                        init,
+                        ctx,
+                    ),
+                ),
+                None => AssignmentTargetMaybeDefault::AssignmentTargetIdentifier(id),
+            };
+            *node = AssignmentTargetProperty::AssignmentTargetPropertyProperty(
+                AssignmentTargetPropertyProperty::boxed(
+                    span,
+                    PropertyKey::StaticIdentifier(IdentifierName::boxed(SPAN, v, ctx)),
+                    binding,
+                    false,
+                    ctx,
+                ),
+            );
+        }
+    }
 }
 
-struct Dedupe<'a> {
+struct Dedupe<'a, 'ctx> {
     statements: Statements<'a>,
     state: DedupeState,
     statement_stack: Vec<Address>,
     originals: FxHashMap<Address, BoundIdentifier<'a>>,
+    /// Originals that are declared at the module top level.
+    top_level_originals: FxHashSet<Address>,
+    /// Depth of nested expressions that are hoisted to the module top level.
+    hoisted_depth: u32,
+    source_text: &'a str,
+    debug_names: bool,
+    /// Number of replaced duplicates, duplicates nested in replaced
+    /// duplicates aren't counted.
+    collapsed: u32,
+    /// Values of `collapsed` before entering duplicates.
+    collapsed_stack: Vec<u32>,
+    debug_comments: &'ctx mut DebugComments,
 }
 
-impl<'a> Dedupe<'a> {
-    fn new(state: DedupeState) -> Self {
+impl<'a, 'ctx> Dedupe<'a, 'ctx> {
+    fn new(
+        state: DedupeState,
+        source_text: &'a str,
+        debug_names: bool,
+        debug_comments: &'ctx mut DebugComments,
+    ) -> Self {
         Self {
             statements: Statements::new(),
             state,
             statement_stack: Vec::new(),
             originals: FxHashMap::default(),
+            top_level_originals: FxHashSet::default(),
+            hoisted_depth: 0,
+            source_text,
+            debug_names,
+            collapsed: 0,
+            collapsed_stack: Vec::new(),
+            debug_comments,
         }
     }
+
+    fn is_hoisted(&self, address: Address) -> bool {
+        self.state.hoisted.contains(&address)
+            && matches!(self.state.expressions.get(&address), Some(DedupeKind::Original(n)) if *n > 0)
+    }
 }
 
-impl<'a> Traverse<'a, TraverseCtxState<'a>> for Dedupe<'a> {
+impl<'a> Traverse<'a, TraverseCtxState<'a>> for Dedupe<'a, '_> {
+    fn exit_program(&mut self, node: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.statements.exit_program(node, ctx);
+    }
+
     fn exit_statements(
         &mut self,
         node: &mut ArenaVec<'a, Statement<'a>>,
@@ -335,26 +1123,60 @@ impl<'a> Traverse<'a, TraverseCtxState<'a>> for Dedupe<'a> {
         self.statement_stack.pop();
     }
 
+    fn enter_expression(&mut self, node: &mut Expression<'a>, _ctx: &mut TraverseCtx<'a>) {
+        let address = node.address();
+        if self.is_hoisted(address) {
+            self.hoisted_depth += 1;
+        }
+        if let Some(DedupeKind::Duplicate(_)) = self.state.expressions.get(&address) {
+            self.collapsed_stack.push(self.collapsed);
+        }
+    }
+
     fn exit_expression(&mut self, node: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         let address = node.address();
+        // Originals nested in hoisted expressions are also hoisted, so that
+        // they are declared before the hoisted expression.
+        let top_level = self.hoisted_depth > 0;
+        if self.is_hoisted(address) {
+            self.hoisted_depth -= 1;
+        }
         if let Some(dedupe_kind) = self.state.expressions.get(&address) {
             match dedupe_kind {
                 DedupeKind::Original(duplicates) => {
                     if *duplicates > 0
                         && let Some(statement_address) = self.statement_stack.last()
                     {
-                        let uid =
-                            ctx.generate_uid_in_root_scope("_DEDUPE_", SymbolFlags::ConstVariable);
+                        let name = uid_name(self.debug_names, "_DEDUPE_", || expr_name(node));
+                        let uid = ctx.generate_uid_in_root_scope(&name, SymbolFlags::ConstVariable);
+                        self.debug_comments.add_source(
+                            &uid.name,
+                            "dedupe",
+                            node.span(),
+                            self.source_text,
+                        );
                         let mut expr2 = uid.create_read_expression(ctx);
                         std::mem::swap(node, &mut expr2);
                         let decl = stmt_const_decl(&uid, expr2, ctx);
-                        self.statements.insert_before(statement_address, decl);
+                        if top_level {
+                            self.statements.insert_top_level_statement(decl);
+                            self.top_level_originals.insert(address);
+                        } else {
+                            self.statements.insert_before(statement_address, decl);
+                        }
                         self.originals.insert(address, uid);
                     }
                 }
                 DedupeKind::Duplicate(original_address) => {
+                    let collapsed = self.collapsed_stack.pop().unwrap_or_default();
+                    // Hoisted expressions can't reference originals declared
+                    // in a local scope.
+                    if top_level && !self.top_level_originals.contains(original_address) {
+                        return;
+                    }
                     if let Some(id) = self.originals.get(original_address) {
                         *node = id.create_read_expression(ctx);
+                        self.collapsed = collapsed + 1;
                     }
                 }
             }
@@ -433,16 +1255,192 @@ fn create_new_expr<'a>(
     )
 }
 
-fn is_import_meta_url<'a>(expr: &Argument<'a>) -> bool {
-    if let Argument::StaticMemberExpression(url) = expr
-        && url.property.name == "url"
+// `JSON.parse("json")`
+fn create_json_parse<'a>(json: &str, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+    let callee = Expression::StaticMemberExpression(StaticMemberExpression::boxed(
+        SPAN,
+        ctx.create_unbound_ident_expr(SPAN, "JSON".into(), ReferenceFlags::Read),
+        IdentifierName::new(SPAN, "parse", ctx),
+        false,
+        ctx,
+    ));
+    let arg = Argument::StringLiteral(StringLiteral::boxed(
+        SPAN,
+        Str::from_in(json, ctx.ast.allocator),
+        None,
+        ctx,
+    ));
+    Expression::CallExpression(CallExpression::boxed(
+        SPAN,
+        callee,
+        NONE,
+        ArenaVec::from_value_in(arg, ctx),
+        false,
+        ctx,
+    ))
+}
+
+/// Scope where global values are hoisted.
+#[derive(Clone, Copy)]
+struct GlobalsTarget {
+    scope_id: ScopeId,
+    /// The first statement in a function body, `None` for the root scope.
+    statement: Option<Address>,
+}
+
+impl GlobalsTarget {
+    fn insert<'a>(self, statements: &Statements<'a>, stmt: Statement<'a>) {
+        match self.statement {
+            Some(address) => statements.insert_before(&address, stmt),
+            None => statements.insert_top_level_statement(stmt),
+        }
+    }
+}
+
+/// Resolves `new URL("./url", import.meta.url)` expressions.
+fn resolve_new_url(base_url: &str, expr: &NewExpression<'_>) -> Option<String> {
+    if !matches!(&expr.callee, Expression::Identifier(id) if id.name == "URL") {
+        return None;
+    }
+    let [Argument::StringLiteral(rel_url), arg1] = expr.arguments.as_slice() else {
+        return None;
+    };
+    if !is_import_meta_url(arg1) {
+        return None;
+    }
+    resolve_url(base_url, rel_url.value.as_str())
+}
+
+/// Resolves `./` and `../` relative URLs against a path-like or http(s)/file
+/// base URL.
+///
+/// Returns `None` for absolute URLs, bare specifiers and unsupported bases
+/// (`data:`, `blob:`, etc).
+fn resolve_url(base_url: &str, rel_url: &str) -> Option<String> {
+    if !rel_url.starts_with("./") && !rel_url.starts_with("../") {
+        return None;
+    }
+    // The shortest prefix that can't be removed with `../`.
+    let root_len = if let Some((scheme, rest)) = base_url.split_once(':')
+        && !scheme.contains('/')
     {
-        if let Expression::MetaProperty(meta) = &url.object
-            && meta.meta.name == "import"
-            && meta.property.name == "meta"
-        {
-            return true;
+        if !matches!(scheme, "http" | "https" | "file") {
+            return None;
+        }
+        let authority = rest.strip_prefix("//")?;
+        scheme.len() + 3 + authority.find('/')? + 1
+    } else {
+        usize::from(base_url.starts_with('/'))
+    };
+
+    // Directory part of the base URL.
+    let mut base = &base_url[..base_url.rfind('/').map_or(0, |i| i + 1).max(root_len)];
+    let mut rel = rel_url;
+    loop {
+        if let Some(r) = rel.strip_prefix("./") {
+            rel = r;
+        } else if let Some(r) = rel.strip_prefix("../") {
+            if base.len() <= root_len {
+                return None;
+            }
+            let parent = base[..base.len() - 1].rfind('/').map_or(0, |i| i + 1);
+            base = &base[..parent.max(root_len)];
+            rel = r;
+        } else {
+            break;
+        }
+    }
+    Some([base, rel].concat())
+}
+
+/// Checks if `new URL(url, base)` arguments are string literals that can be
+/// parsed.
+fn is_valid_url(arguments: &[Argument<'_>]) -> bool {
+    match arguments {
+        [Argument::StringLiteral(url)] => Url::parse(&url.value).is_ok(),
+        [Argument::StringLiteral(url), Argument::StringLiteral(base)] => {
+            Url::parse(&base.value).and_then(|base| base.join(&url.value)).is_ok()
+        }
+        _ => false,
+    }
+}
+
+/// Checks if statements are a body of a top-level IIFE that wraps a module,
+/// e.g. `(function() { .. })();` or `(() => { .. })();`.
+fn is_module_wrapper_body(ctx: &TraverseCtx<'_>) -> bool {
+    let mut ancestors = ctx.ancestors();
+    if !matches!(ancestors.next(), Some(Ancestor::FunctionBodyStatements(_))) {
+        return false;
+    }
+    if !matches!(
+        ancestors.next(),
+        Some(Ancestor::FunctionBody(_) | Ancestor::ArrowFunctionExpressionBody(_))
+    ) {
+        return false;
+    }
+    let mut ancestors = ancestors.skip_while(|a| a.is_parenthesized_expression());
+    if !matches!(ancestors.next(), Some(Ancestor::CallExpressionCallee(_))) {
+        return false;
+    }
+    // `(function() {})()`, `!function() {}()`
+    let mut ancestors =
+        ancestors.skip_while(|a| a.is_parenthesized_expression() || a.is_unary_expression());
+    matches!(ancestors.next(), Some(Ancestor::ExpressionStatementExpression(_)))
+        && matches!(ancestors.next(), Some(Ancestor::ProgramBody(_)))
+}
+
+/// Properties that map their keys to external names, e.g. `el.dataset.userId`
+/// is mapped to the `data-user-id` attribute.
+const RESERVED_PROPERTY_CONTEXTS: &[&str] = &["dataset"];
+
+// obj.dataset
+fn is_reserved_property_context(expr: &Expression<'_>) -> bool {
+    if let Expression::StaticMemberExpression(expr) = expr {
+        return RESERVED_PROPERTY_CONTEXTS.contains(&expr.property.name.as_str());
+    }
+    false
+}
+
+// const { key } = obj.dataset
+// ({ key } = obj.dataset)
+fn is_reserved_pattern_context(pattern_parent: Ancestor<'_, '_>) -> bool {
+    match pattern_parent {
+        Ancestor::VariableDeclaratorId(decl) => {
+            decl.init().as_ref().is_some_and(is_reserved_property_context)
         }
+        Ancestor::AssignmentExpressionLeft(expr) => is_reserved_property_context(expr.right()),
+        _ => false,
+    }
+}
+
+// __oveo__({ ... }, KEY_OBJECT)
+fn is_key_object_annotation<'a>(expr: &CallExpression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+    if let Expression::Identifier(id) = &expr.callee
+        && id.name == Annotation::ID_NAME
+        && ctx.scoping().get_reference(id.reference_id()).symbol_id().is_none()
+        && let [arg0, arg1] = expr.arguments.as_slice()
+        && let Argument::ObjectExpression(_) = arg0
+        && let Argument::NumericLiteral(flags) = arg1
+    {
+        return Annotation::new(flags.value as u32).is_key_object();
     }
     false
 }
+
+fn is_import_meta_url(expr: &Argument<'_>) -> bool {
+    if let Argument::StaticMemberExpression(expr) = expr {
+        is_import_meta_member(expr, "url")
+    } else {
+        false
+    }
+}
+
+/// Checks if expression is `import.meta.{name}`.
+fn is_import_meta_member(expr: &StaticMemberExpression<'_>, name: &str) -> bool {
+    expr.property.name == name
+        && matches!(
+            &expr.object,
+            Expression::MetaProperty(meta)
+                if meta.meta.name == "import" && meta.property.name == "meta"
+        )
+}
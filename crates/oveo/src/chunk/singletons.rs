@@ -0,0 +1,103 @@
+use oxc_allocator::{Address, GetAddress, TakeIn, Vec as ArenaVec};
+use oxc_ast::ast::*;
+use oxc_semantic::{ScopeId, SymbolFlags};
+use oxc_traverse::{BoundIdentifier, Traverse};
+use rustc_hash::FxHashMap;
+
+use crate::{
+    chunk::create_new_expr,
+    context::{TraverseCtx, TraverseCtxState},
+    debug_comments::DebugComments,
+    debug_names::{base_name, uid_name},
+    statements::Statements,
+};
+
+/// Constructions with identical constant arguments `new Intl.NumberFormat("en-US")`.
+pub struct SharedSingleton<'a> {
+    pub scope_id: ScopeId,
+    pub callee_id: BoundIdentifier<'a>,
+    /// Declaration of the hoisted constructor, singleton is declared after it.
+    pub callee_decl: Address,
+    pub uid: Option<BoundIdentifier<'a>>,
+}
+
+/// Replaces constructions with identical constant arguments with a reference
+/// to a const symbol, arguments are moved from the first construction to the
+/// singleton declaration.
+pub struct SharedSingletons<'a, 'ctx> {
+    statements: Statements<'a>,
+    singletons: Vec<SharedSingleton<'a>>,
+    /// Index of the singleton for each construction.
+    constructions: FxHashMap<Address, usize>,
+    debug_names: bool,
+    pub debug_comments: &'ctx mut DebugComments,
+}
+
+impl<'a, 'ctx> SharedSingletons<'a, 'ctx> {
+    pub fn new(debug_names: bool, debug_comments: &'ctx mut DebugComments) -> Self {
+        Self {
+            statements: Statements::new(),
+            singletons: Vec::new(),
+            constructions: FxHashMap::default(),
+            debug_names,
+            debug_comments,
+        }
+    }
+
+    pub fn add(&mut self, singleton: SharedSingleton<'a>, constructions: &[Address]) {
+        let index = self.singletons.len();
+        self.singletons.push(singleton);
+        self.constructions.extend(constructions.iter().map(|address| (*address, index)));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.singletons.is_empty()
+    }
+}
+
+impl<'a> Traverse<'a, TraverseCtxState<'a>> for SharedSingletons<'a, '_> {
+    fn exit_program(&mut self, node: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.statements.exit_program(node, ctx);
+    }
+
+    fn exit_statements(
+        &mut self,
+        node: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.statements.exit_statements(node, ctx);
+    }
+
+    fn exit_expression(&mut self, node: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Some(&index) = self.constructions.get(&node.address()) else {
+            return;
+        };
+        let Expression::NewExpression(expr) = node else {
+            return;
+        };
+        let singleton = &mut self.singletons[index];
+        let uid = match &singleton.uid {
+            Some(uid) => uid.clone(),
+            None => {
+                let name = uid_name(self.debug_names, "_SINGLETON_", || {
+                    base_name(&singleton.callee_id.name).to_string()
+                });
+                let uid = ctx.generate_uid(&name, singleton.scope_id, SymbolFlags::ConstVariable);
+                if let Some(callee) = self.debug_comments.expr(&singleton.callee_id.name) {
+                    let expr = format!("new {callee}(...)");
+                    self.debug_comments.add(&uid.name, "singleton", &expr);
+                }
+                // Arguments are constant, so they are moved to the singleton
+                // declaration.
+                let arguments = expr.arguments.take_in(ctx);
+                self.statements.insert_after(
+                    &singleton.callee_decl,
+                    create_new_expr(&uid, &singleton.callee_id, arguments, ctx),
+                );
+                singleton.uid = Some(uid.clone());
+                uid
+            }
+        };
+        *node = uid.create_read_expression(ctx);
+    }
+}
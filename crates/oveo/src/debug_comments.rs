@@ -0,0 +1,113 @@
+use oxc_span::Span;
+use rustc_hash::FxHashMap;
+
+/// Maximum length of an expression in a debug comment.
+const MAX_EXPR_LEN: usize = 60;
+
+/// Comments that are appended to injected declarations when
+/// `OptimizerOptions::debug_comments` is enabled.
+///
+/// `const _GLOBAL_ = fetch; // oveo:global fetch`
+#[derive(Default)]
+pub struct DebugComments {
+    enabled: bool,
+    /// Declaration name -> (pass, expression)
+    comments: FxHashMap<String, (&'static str, String)>,
+}
+
+impl DebugComments {
+    pub fn new(enabled: bool) -> Self {
+        Self { enabled, comments: FxHashMap::default() }
+    }
+
+    pub fn add(&mut self, name: &str, pass: &'static str, expr: &str) {
+        if !self.enabled {
+            return;
+        }
+        let mut text = String::with_capacity(expr.len().min(MAX_EXPR_LEN));
+        for word in expr.split_whitespace() {
+            if !text.is_empty() {
+                text.push(' ');
+            }
+            text.push_str(word);
+        }
+        if text.len() > MAX_EXPR_LEN {
+            let mut end = MAX_EXPR_LEN;
+            while !text.is_char_boundary(end) {
+                end -= 1;
+            }
+            text.truncate(end);
+            text.push_str("...");
+        }
+        self.comments.insert(name.to_string(), (pass, text));
+    }
+
+    /// Adds a comment with the source text of an expression.
+    pub fn add_source(&mut self, name: &str, pass: &'static str, span: Span, source_text: &str) {
+        if self.enabled {
+            self.add(
+                name,
+                pass,
+                source_text.get(span.start as usize..span.end as usize).unwrap_or(""),
+            );
+        }
+    }
+
+    /// Collects debug comments from the source text, trailing comments are
+    /// dropped by the codegen and they are appended again in `apply()`.
+    pub fn collect(&mut self, source_text: &str) {
+        if !self.enabled {
+            return;
+        }
+        for line in source_text.lines() {
+            if let Some(decl) = line.trim_start().strip_prefix("const ")
+                && let Some((name, _)) = decl.split_once(" = ")
+                && let Some((_, comment)) = line.rsplit_once("; // oveo:")
+            {
+                let (pass, expr) = comment.split_once(' ').unwrap_or((comment, ""));
+                let pass = match pass {
+                    "hoist" => "hoist",
+                    "global" => "global",
+                    "singleton" => "singleton",
+                    "dedupe" => "dedupe",
+                    _ => continue,
+                };
+                self.comments.insert(name.to_string(), (pass, expr.to_string()));
+            }
+        }
+    }
+
+    /// Expression from the comment of an injected declaration.
+    pub fn expr(&self, name: &str) -> Option<&str> {
+        self.comments.get(name).map(|(_, expr)| expr.as_str())
+    }
+
+    /// Appends comments to single-line declarations in the generated code.
+    ///
+    /// Comments are appended at the end of lines, so source map positions
+    /// aren't affected.
+    pub fn apply(&self, code: &mut String) {
+        if self.comments.is_empty() {
+            return;
+        }
+        let mut result = String::with_capacity(code.len());
+        for line in code.split_inclusive('\n') {
+            let content = line.trim_end_matches(['\n', '\r']);
+            result.push_str(content);
+            if content.ends_with(';')
+                && let Some(decl) = content.trim_start().strip_prefix("const ")
+                && let Some((name, _)) = decl.split_once(" = ")
+                && let Some((pass, expr)) = self.comments.get(name)
+            {
+                result.push_str(" // oveo:");
+                result.push_str(pass);
+                if !expr.is_empty() {
+                    result.push(' ');
+                    result.push_str(expr);
+                }
+            }
+            result.push_str(&line[content.len()..]);
+        }
+        *code = result;
+    }
+}
@@ -0,0 +1,55 @@
+use std::borrow::Cow;
+
+use oxc_ast::ast::Expression;
+
+/// Name of an injected declaration with a descriptive suffix when
+/// `OptimizerOptions::debug_names` is enabled.
+///
+/// `_GLOBAL_Math_PI` instead of `_GLOBAL_2`
+pub fn uid_name(
+    enabled: bool,
+    prefix: &'static str,
+    suffix: impl FnOnce() -> String,
+) -> Cow<'static, str> {
+    if !enabled {
+        return Cow::Borrowed(prefix);
+    }
+    let mut name = format!("{prefix}{}", suffix());
+    // Numeric suffixes are used by the uid generator to make names unique,
+    // `_GLOBAL_Math_LN2` would be generated as `_GLOBAL_Math_LN`.
+    if name.ends_with(|c: char| c.is_ascii_digit()) {
+        name.push('_');
+    }
+    Cow::Owned(name)
+}
+
+/// Name of an injected declaration without the `_GLOBAL_` or `_DEDUPE_`
+/// prefix.
+pub fn base_name(name: &str) -> &str {
+    ["_GLOBAL_", "_DEDUPE_"].iter().find_map(|prefix| name.strip_prefix(prefix)).unwrap_or(name)
+}
+
+/// Describes the shape of a deduplicated expression.
+pub fn expr_name(expr: &Expression) -> String {
+    match expr {
+        Expression::ObjectExpression(_) => "object".to_string(),
+        Expression::ArrayExpression(_) => "array".to_string(),
+        Expression::ArrowFunctionExpression(_) => "arrow".to_string(),
+        Expression::StringLiteral(_) => "string".to_string(),
+        Expression::TemplateLiteral(_) => "template".to_string(),
+        Expression::TaggedTemplateExpression(_) => "tagged_template".to_string(),
+        Expression::RegExpLiteral(_) => "regexp".to_string(),
+        Expression::BigIntLiteral(_) => "bigint".to_string(),
+        Expression::ConditionalExpression(_) => "conditional".to_string(),
+        Expression::StaticMemberExpression(expr) => expr.property.name.to_string(),
+        Expression::CallExpression(expr) => match &expr.callee {
+            Expression::Identifier(id) => format!("call_{}", base_name(&id.name)),
+            Expression::StaticMemberExpression(callee) => {
+                format!("call_{}", callee.property.name)
+            }
+            _ => "call".to_string(),
+        },
+        Expression::ParenthesizedExpression(expr) => expr_name(&expr.expression),
+        _ => "expr".to_string(),
+    }
+}
@@ -1,11 +1,11 @@
-use std::sync::Arc;
+use std::{collections::hash_map, sync::Arc};
 
 use rustc_hash::FxHashMap;
 use serde::Deserialize;
 
 pub static INTRINSICS_MODULE_NAME: &str = "oveo";
 
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize)]
 pub struct ExternModule {
     pub exports: FxHashMap<String, ExternValue>,
 }
@@ -21,15 +21,31 @@ impl ExternMap {
 
         // Add intrinsic functions
         let mut exports = FxHashMap::default();
-        add_intrinsic(&mut exports, "hoist", IntrinsicFunction::Hoist, vec![arg_hoist()]);
-        add_intrinsic(&mut exports, "scope", IntrinsicFunction::Scope, vec![arg_scope()]);
+        add_intrinsic(
+            &mut exports,
+            "hoist",
+            IntrinsicFunction::Hoist,
+            vec![arg_hoist(), arg_label()],
+        );
+        add_intrinsic(
+            &mut exports,
+            "scope",
+            IntrinsicFunction::Scope,
+            vec![arg_scope(), arg_label()],
+        );
         add_intrinsic(&mut exports, "dedupe", IntrinsicFunction::Dedupe, vec![]);
         add_intrinsic(&mut exports, "key", IntrinsicFunction::Key, vec![]);
+        add_intrinsic(&mut exports, "keyObject", IntrinsicFunction::KeyObject, vec![]);
+        add_intrinsic(&mut exports, "pure", IntrinsicFunction::Pure, vec![]);
+        add_intrinsic(&mut exports, "inline", IntrinsicFunction::Inline, vec![]);
+        add_intrinsic(&mut exports, "freeze", IntrinsicFunction::Freeze, vec![]);
         modules.insert(INTRINSICS_MODULE_NAME.to_string(), Arc::new(ExternModule { exports }));
 
         Self { modules }
     }
 
+    /// Imports modules from JSON, existing modules with the same names are
+    /// replaced.
     pub fn import_from_json(&mut self, raw: &[u8]) -> Result<(), serde_json::Error> {
         let mut modules = serde_json::from_slice::<FxHashMap<String, Arc<ExternModule>>>(raw)?;
         for (k, v) in modules.drain() {
@@ -37,14 +53,49 @@ impl ExternMap {
         }
         Ok(())
     }
+
+    /// Imports modules from JSON and merges their exports into existing
+    /// modules, exports from `raw` win on conflicts.
+    ///
+    /// Nested namespaces are merged recursively.
+    pub fn merge_from_json(&mut self, raw: &[u8]) -> Result<(), serde_json::Error> {
+        let mut modules = serde_json::from_slice::<FxHashMap<String, Arc<ExternModule>>>(raw)?;
+        for (k, v) in modules.drain() {
+            match self.modules.entry(k) {
+                hash_map::Entry::Occupied(mut entry) => {
+                    merge_module(Arc::make_mut(entry.get_mut()), v);
+                }
+                hash_map::Entry::Vacant(entry) => {
+                    entry.insert(v);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn merge_module(module: &mut ExternModule, other: Arc<ExternModule>) {
+    for (k, v) in Arc::unwrap_or_clone(other).exports {
+        if let ExternValue::Namespace(other_ns) = &v
+            && let Some(ExternValue::Namespace(ns)) = module.exports.get_mut(&k)
+        {
+            merge_module(Arc::make_mut(ns), Arc::clone(other_ns));
+            continue;
+        }
+        module.exports.insert(k, v);
+    }
 }
 
 fn arg_hoist() -> ExternFunctionArgument {
-    ExternFunctionArgument { hoist: true, scope: false }
+    ExternFunctionArgument { hoist: true, ..Default::default() }
 }
 
 fn arg_scope() -> ExternFunctionArgument {
-    ExternFunctionArgument { hoist: false, scope: true }
+    ExternFunctionArgument { scope: true, ..Default::default() }
+}
+
+fn arg_label() -> ExternFunctionArgument {
+    ExternFunctionArgument { label: true, ..Default::default() }
 }
 
 fn add_intrinsic(
@@ -64,6 +115,7 @@ fn add_intrinsic(
 pub enum ExternValue {
     Namespace(Arc<ExternModule>),
     Function(Arc<ExternFunction>),
+    Const(Arc<ExternConst>),
 }
 
 #[derive(Deserialize)]
@@ -81,18 +133,35 @@ pub enum IntrinsicFunction {
     Scope,
     Dedupe,
     Key,
+    KeyObject,
+    Pure,
+    Inline,
+    Freeze,
 }
 
-#[derive(Deserialize)]
+#[derive(Default, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ExternFunctionArgument {
     #[serde(default)]
     pub hoist: bool,
     #[serde(default)]
     pub scope: bool,
+    /// String literal argument is a label for `hoist` and `scope` arguments.
+    #[serde(default)]
+    pub label: bool,
+    /// Argument identifier is resolved as an extern namespace inside of the
+    /// call expression.
+    #[serde(default)]
+    pub externs: Option<Arc<ExternModule>>,
 }
 
 #[derive(Deserialize)]
 pub struct ExternConst {
     pub value: serde_json::Value,
+    #[serde(default = "default_inline")]
+    pub inline: bool,
+}
+
+fn default_inline() -> bool {
+    true
 }
@@ -3,10 +3,13 @@
 use std::sync::LazyLock;
 
 use rustc_hash::FxHashMap;
+use serde::{Deserialize, Deserializer};
 
 static GLOBALS: LazyLock<GlobalValue> = LazyLock::new(|| {
     let mut statics = FxHashMap::default();
     add_globals_js(&mut statics);
+    add_globals_deno(&mut statics);
+    add_globals_bun(&mut statics);
 
     GlobalValue {
         statics,
@@ -16,7 +19,7 @@ static GLOBALS: LazyLock<GlobalValue> = LazyLock::new(|| {
     }
 });
 
-#[derive(Default, Clone, Copy, Debug)]
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
 pub struct GlobalCategory(u32);
 
 impl GlobalCategory {
@@ -26,17 +29,74 @@ impl GlobalCategory {
     pub const WEB: Self = Self(1 << 2);
     pub const ELECTRON: Self = Self(1 << 3);
     pub const TAURI: Self = Self(1 << 4);
+    pub const DENO: Self = Self(1 << 5);
+    pub const BUN: Self = Self(1 << 6);
     pub const UNKNOWN: Self = Self(1 << 10);
 
+    /// Checks if categories have at least one common category.
+    ///
+    /// ```
+    /// use oveo::GlobalCategory;
+    ///
+    /// let c = GlobalCategory::JS.union(GlobalCategory::WEB);
+    /// assert!(c.matches(GlobalCategory::WEB));
+    /// assert!(!c.matches(GlobalCategory::CONSOLE));
+    /// assert!(!GlobalCategory::default().matches(GlobalCategory::ALL));
+    /// ```
     #[inline]
     pub fn matches(self, rhs: GlobalCategory) -> bool {
         self.0 & rhs.0 != 0
     }
 
+    /// Checks if all categories from `rhs` are included.
+    ///
+    /// ```
+    /// use oveo::GlobalCategory;
+    ///
+    /// let c = GlobalCategory::JS.union(GlobalCategory::WEB);
+    /// assert!(c.contains(GlobalCategory::JS));
+    /// assert!(!c.contains(GlobalCategory::JS.union(GlobalCategory::CONSOLE)));
+    /// assert!(GlobalCategory::ALL.contains(c));
+    /// ```
     #[inline]
-    pub fn and(self, rhs: GlobalCategory) -> GlobalCategory {
+    pub fn contains(self, rhs: GlobalCategory) -> bool {
+        self.0 & rhs.0 == rhs.0
+    }
+
+    /// Returns categories that are included in either `self` or `rhs`.
+    ///
+    /// ```
+    /// use oveo::GlobalCategory;
+    ///
+    /// let c = GlobalCategory::JS.union(GlobalCategory::WEB);
+    /// assert_eq!(c, GlobalCategory::from(["js", "web"].iter()));
+    /// assert_eq!(c.union(GlobalCategory::JS), c);
+    /// ```
+    #[inline]
+    pub fn union(self, rhs: GlobalCategory) -> GlobalCategory {
         Self(self.0 | rhs.0)
     }
+
+    /// Returns categories that are included in both `self` and `rhs`.
+    ///
+    /// ```
+    /// use oveo::GlobalCategory;
+    ///
+    /// let c = GlobalCategory::JS.union(GlobalCategory::WEB);
+    /// assert_eq!(c.intersection(GlobalCategory::WEB), GlobalCategory::WEB);
+    /// assert_eq!(c.intersection(GlobalCategory::CONSOLE), GlobalCategory::default());
+    /// assert_eq!(GlobalCategory::ALL.intersection(c), c);
+    /// ```
+    #[inline]
+    pub fn intersection(self, rhs: GlobalCategory) -> GlobalCategory {
+        Self(self.0 & rhs.0)
+    }
+
+    #[inline]
+    #[deprecated(note = "`and` is a union of categories, use `union` instead")]
+    pub fn and(self, rhs: GlobalCategory) -> GlobalCategory {
+        self.union(rhs)
+    }
 }
 
 impl<S: AsRef<str>, T: Iterator<Item = S>> From<T> for GlobalCategory {
@@ -44,16 +104,26 @@ impl<S: AsRef<str>, T: Iterator<Item = S>> From<T> for GlobalCategory {
         let mut c = GlobalCategory::default();
         for i in value {
             match i.as_ref() {
-                "js" => c = c.and(Self::JS),
-                "console" => c = c.and(Self::CONSOLE),
-                "web" => c = c.and(Self::WEB),
-                _ => c = c.and(Self::UNKNOWN),
+                "js" => c = c.union(Self::JS),
+                "console" => c = c.union(Self::CONSOLE),
+                "web" => c = c.union(Self::WEB),
+                "deno" => c = c.union(Self::DENO),
+                "bun" => c = c.union(Self::BUN),
+                _ => c = c.union(Self::UNKNOWN),
             }
         }
         c
     }
 }
 
+/// Deserializes categories from a list of names `["js", "web"]`.
+impl<'de> Deserialize<'de> for GlobalCategory {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let names = Vec::<String>::deserialize(deserializer)?;
+        Ok(Self::from(names.iter()))
+    }
+}
+
 pub fn get_global_value(categories: GlobalCategory, name: &str) -> Option<&'static GlobalValue> {
     match name {
         "window" | "globalThis" => Some(&GLOBALS),
@@ -61,6 +131,21 @@ pub fn get_global_value(categories: GlobalCategory, name: &str) -> Option<&'stat
     }
 }
 
+/// Returns a static property of a global value, e.g. `Object.defineProperty`.
+pub fn get_global_static(name: &str, property: &str) -> Option<&'static GlobalValue> {
+    GLOBALS.statics.get(name)?.statics.get(property)
+}
+
+/// Returns a global value by its path, e.g. `Intl.NumberFormat`.
+pub fn get_global_path(path: &str) -> Option<&'static GlobalValue> {
+    let mut parts = path.split('.');
+    let mut v = get_global_value(GlobalCategory::ALL, parts.next()?)?;
+    for part in parts {
+        v = v.statics.get(part)?;
+    }
+    Some(v)
+}
+
 #[derive(Default, Clone)]
 pub struct GlobalValue {
     pub statics: FxHashMap<&'static str, GlobalValue>,
@@ -87,11 +172,30 @@ impl GlobalValue {
         }
         false
     }
+
+    /// Constructor that creates objects with a distinct identity, they should
+    /// never be shared as singletons.
+    pub fn is_unique_func(&self) -> bool {
+        if let GlobalValueKind::Func(f) = &self.kind {
+            return f.unique;
+        }
+        false
+    }
+
+    /// Constructor without side effects when invoked with constant arguments.
+    pub fn is_pure_func(&self) -> bool {
+        if let GlobalValueKind::Func(f) = &self.kind {
+            return f.pure;
+        }
+        false
+    }
 }
 
 #[derive(Clone)]
 pub struct GlobalFunction {
     pub singleton: bool,
+    pub pure: bool,
+    pub unique: bool,
 }
 
 struct GlobalObjectBuilder {
@@ -143,6 +247,8 @@ fn object(category: GlobalCategory) -> GlobalObjectBuilder {
 
 struct GlobalFunctionBuilder {
     pub singleton: bool,
+    pub pure: bool,
+    pub unique: bool,
 }
 
 impl GlobalFunctionBuilder {
@@ -150,18 +256,28 @@ impl GlobalFunctionBuilder {
         self.singleton = true;
         self
     }
+
+    fn pure(mut self) -> Self {
+        self.pure = true;
+        self
+    }
+
+    fn unique(mut self) -> Self {
+        self.unique = true;
+        self
+    }
 }
 
 impl Build for GlobalFunctionBuilder {
     type Output = GlobalFunction;
 
     fn build(self) -> Self::Output {
-        GlobalFunction { singleton: self.singleton }
+        GlobalFunction { singleton: self.singleton, pure: self.pure, unique: self.unique }
     }
 }
 
 fn func() -> GlobalFunctionBuilder {
-    GlobalFunctionBuilder { singleton: false }
+    GlobalFunctionBuilder { singleton: false, pure: false, unique: false }
 }
 
 fn add<T: Build<Output = GlobalValue>>(
@@ -179,6 +295,21 @@ fn add_globals_js(g: &mut FxHashMap<&'static str, GlobalValue>) {
         g,
         "Array",
         object(GlobalCategory::JS)
+            .with_static(
+                "prototype",
+                object(GlobalCategory::JS)
+                    .with_static("concat", object(GlobalCategory::JS))
+                    .with_static("every", object(GlobalCategory::JS))
+                    .with_static("filter", object(GlobalCategory::JS))
+                    .with_static("forEach", object(GlobalCategory::JS))
+                    .with_static("indexOf", object(GlobalCategory::JS))
+                    .with_static("join", object(GlobalCategory::JS))
+                    .with_static("map", object(GlobalCategory::JS))
+                    .with_static("push", object(GlobalCategory::JS))
+                    .with_static("slice", object(GlobalCategory::JS))
+                    .with_static("some", object(GlobalCategory::JS))
+                    .with_static("splice", object(GlobalCategory::JS)),
+            )
             .with_static("from", object(GlobalCategory::JS))
             .with_static("fromAsync", object(GlobalCategory::JS))
             .with_static("isArray", object(GlobalCategory::JS))
@@ -187,13 +318,26 @@ fn add_globals_js(g: &mut FxHashMap<&'static str, GlobalValue>) {
     add(
         g,
         "ArrayBuffer",
-        object(GlobalCategory::JS).with_static("isView", object(GlobalCategory::JS)),
+        object(GlobalCategory::JS)
+            .with_static(
+                "prototype",
+                object(GlobalCategory::JS)
+                    .with_static("resize", object(GlobalCategory::JS))
+                    .with_static("slice", object(GlobalCategory::JS))
+                    .with_static("transfer", object(GlobalCategory::JS))
+                    .with_static("transferToFixedLength", object(GlobalCategory::JS)),
+            )
+            .with_static("isView", object(GlobalCategory::JS)),
     );
     add(g, "AsyncDisposableStack", object(GlobalCategory::JS));
     add(g, "AsyncFunction", object(GlobalCategory::JS));
     add(g, "AsyncGenerator", object(GlobalCategory::JS));
     add(g, "AsyncGeneratorFunction", object(GlobalCategory::JS));
-    add(g, "AsyncIterator", object(GlobalCategory::JS));
+    add(
+        g,
+        "AsyncIterator",
+        object(GlobalCategory::JS).with_static("from", object(GlobalCategory::JS)),
+    );
     add(
         g,
         "Atomics",
@@ -238,7 +382,7 @@ fn add_globals_js(g: &mut FxHashMap<&'static str, GlobalValue>) {
             .with_static("captureStackTrace", object(GlobalCategory::JS))
             .with_static("isError", object(GlobalCategory::JS)),
     );
-    add(g, "FinalizationRegistry", object(GlobalCategory::JS));
+    add(g, "FinalizationRegistry", object(GlobalCategory::JS).with_func(func().unique()));
     add(g, "Function", object(GlobalCategory::JS));
     add(g, "Generator", object(GlobalCategory::JS));
     add(g, "GeneratorFunction", object(GlobalCategory::JS));
@@ -247,10 +391,43 @@ fn add_globals_js(g: &mut FxHashMap<&'static str, GlobalValue>) {
         g,
         "Intl",
         object(GlobalCategory::JS)
+            .with_static("Collator", object(GlobalCategory::JS).with_func(func()))
+            .with_static("DateTimeFormat", object(GlobalCategory::JS).with_func(func()))
+            .with_static("DisplayNames", object(GlobalCategory::JS).with_func(func()))
+            .with_static("DurationFormat", object(GlobalCategory::JS).with_func(func()))
+            .with_static("ListFormat", object(GlobalCategory::JS).with_func(func()))
+            .with_static("Locale", object(GlobalCategory::JS).with_func(func()))
+            .with_static("NumberFormat", object(GlobalCategory::JS).with_func(func()))
+            .with_static("PluralRules", object(GlobalCategory::JS).with_func(func()))
+            .with_static("RelativeTimeFormat", object(GlobalCategory::JS).with_func(func()))
+            .with_static("Segmenter", object(GlobalCategory::JS).with_func(func()))
             .with_static("getCanonicalLocales", object(GlobalCategory::JS))
             .with_static("supportedValuesOf", object(GlobalCategory::JS)),
     );
-    add(g, "Iterator", object(GlobalCategory::JS).with_static("from", object(GlobalCategory::JS)));
+    add(
+        g,
+        "Iterator",
+        object(GlobalCategory::JS)
+            .with_static(
+                "prototype",
+                object(GlobalCategory::JS)
+                    .with_static("drop", object(GlobalCategory::JS))
+                    .with_static("every", object(GlobalCategory::JS))
+                    .with_static("filter", object(GlobalCategory::JS))
+                    .with_static("find", object(GlobalCategory::JS))
+                    .with_static("flatMap", object(GlobalCategory::JS))
+                    .with_static("forEach", object(GlobalCategory::JS))
+                    .with_static("map", object(GlobalCategory::JS))
+                    .with_static("reduce", object(GlobalCategory::JS))
+                    .with_static("some", object(GlobalCategory::JS))
+                    .with_static("take", object(GlobalCategory::JS))
+                    .with_static("toArray", object(GlobalCategory::JS)),
+            )
+            .with_static("concat", object(GlobalCategory::JS))
+            .with_static("from", object(GlobalCategory::JS))
+            .with_static("zip", object(GlobalCategory::JS))
+            .with_static("zipKeyed", object(GlobalCategory::JS)),
+    );
     add(
         g,
         "JSON",
@@ -342,7 +519,10 @@ fn add_globals_js(g: &mut FxHashMap<&'static str, GlobalValue>) {
                 object(GlobalCategory::JS)
                     .with_static("hasOwnProperty", object(GlobalCategory::JS))
                     .with_static("isPrototypeOf", object(GlobalCategory::JS))
-                    .with_static("propertyIsEnumerable", object(GlobalCategory::JS)),
+                    .with_static("propertyIsEnumerable", object(GlobalCategory::JS))
+                    .with_static("toLocaleString", object(GlobalCategory::JS))
+                    .with_static("toString", object(GlobalCategory::JS))
+                    .with_static("valueOf", object(GlobalCategory::JS)),
             )
             .with_static("assign", object(GlobalCategory::JS))
             .with_static("create", object(GlobalCategory::JS))
@@ -436,14 +616,86 @@ fn add_globals_js(g: &mut FxHashMap<&'static str, GlobalValue>) {
             .with_static("unscopables", object(GlobalCategory::JS)),
     );
     add(g, "SyntaxError", object(GlobalCategory::JS));
-    add(g, "TextDecoder", object(GlobalCategory::JS).with_func(func().singleton()));
-    add(g, "TextEncoder", object(GlobalCategory::JS).with_func(func().singleton()));
+    add(
+        g,
+        "Temporal",
+        object(GlobalCategory::JS)
+            .with_static(
+                "Now",
+                object(GlobalCategory::JS)
+                    .with_static("instant", object(GlobalCategory::JS))
+                    .with_static("plainDateISO", object(GlobalCategory::JS))
+                    .with_static("plainDateTimeISO", object(GlobalCategory::JS))
+                    .with_static("plainTimeISO", object(GlobalCategory::JS))
+                    .with_static("timeZoneId", object(GlobalCategory::JS))
+                    .with_static("zonedDateTimeISO", object(GlobalCategory::JS)),
+            )
+            .with_static(
+                "Duration",
+                object(GlobalCategory::JS)
+                    .with_func(func())
+                    .with_static("compare", object(GlobalCategory::JS))
+                    .with_static("from", object(GlobalCategory::JS)),
+            )
+            .with_static(
+                "Instant",
+                object(GlobalCategory::JS)
+                    .with_func(func())
+                    .with_static("compare", object(GlobalCategory::JS))
+                    .with_static("from", object(GlobalCategory::JS))
+                    .with_static("fromEpochMilliseconds", object(GlobalCategory::JS))
+                    .with_static("fromEpochNanoseconds", object(GlobalCategory::JS)),
+            )
+            .with_static(
+                "PlainDate",
+                object(GlobalCategory::JS)
+                    .with_func(func())
+                    .with_static("compare", object(GlobalCategory::JS))
+                    .with_static("from", object(GlobalCategory::JS)),
+            )
+            .with_static(
+                "PlainDateTime",
+                object(GlobalCategory::JS)
+                    .with_func(func())
+                    .with_static("compare", object(GlobalCategory::JS))
+                    .with_static("from", object(GlobalCategory::JS)),
+            )
+            .with_static(
+                "PlainMonthDay",
+                object(GlobalCategory::JS)
+                    .with_func(func())
+                    .with_static("from", object(GlobalCategory::JS)),
+            )
+            .with_static(
+                "PlainTime",
+                object(GlobalCategory::JS)
+                    .with_func(func())
+                    .with_static("compare", object(GlobalCategory::JS))
+                    .with_static("from", object(GlobalCategory::JS)),
+            )
+            .with_static(
+                "PlainYearMonth",
+                object(GlobalCategory::JS)
+                    .with_func(func())
+                    .with_static("compare", object(GlobalCategory::JS))
+                    .with_static("from", object(GlobalCategory::JS)),
+            )
+            .with_static(
+                "ZonedDateTime",
+                object(GlobalCategory::JS)
+                    .with_func(func())
+                    .with_static("compare", object(GlobalCategory::JS))
+                    .with_static("from", object(GlobalCategory::JS)),
+            ),
+    );
+    add(g, "TextDecoder", object(GlobalCategory::JS).with_func(func().singleton().pure()));
+    add(g, "TextEncoder", object(GlobalCategory::JS).with_func(func().singleton().pure()));
     add(g, "TypeError", object(GlobalCategory::JS));
 
     add(g, "URIError", object(GlobalCategory::JS));
     add(g, "URLPattern", object(GlobalCategory::JS));
     add(g, "WeakMap", object(GlobalCategory::JS));
-    add(g, "WeakRef", object(GlobalCategory::JS));
+    add(g, "WeakRef", object(GlobalCategory::JS).with_func(func().unique()));
     add(g, "WeakSet", object(GlobalCategory::JS));
     add(g, "decodeURI", object(GlobalCategory::JS));
     add(g, "decodeURIComponent", object(GlobalCategory::JS));
@@ -597,12 +849,13 @@ fn add_globals_js(g: &mut FxHashMap<&'static str, GlobalValue>) {
         g,
         "URL",
         object(GlobalCategory::WEB)
+            .with_func(func().pure())
             .with_static("canParse", object(GlobalCategory::WEB))
             .with_static("createObjectURL", object(GlobalCategory::WEB))
             .with_static("parse", object(GlobalCategory::WEB))
             .with_static("revokeObjectURL", object(GlobalCategory::WEB)),
     );
-    add(g, "URLSearchParams", object(GlobalCategory::WEB));
+    add(g, "URLSearchParams", object(GlobalCategory::WEB).with_func(func().pure()));
     add(g, "AbstractRange", object(GlobalCategory::WEB));
     add(g, "Range", object(GlobalCategory::WEB));
     add(g, "StaticRange", object(GlobalCategory::WEB));
@@ -861,7 +1114,13 @@ fn add_globals_js(g: &mut FxHashMap<&'static str, GlobalValue>) {
     add(g, "UIEvent", object(GlobalCategory::WEB));
     add(g, "WheelEvent", object(GlobalCategory::WEB));
 
-    add(g, "navigator", object(GlobalCategory::WEB));
+    add(
+        g,
+        "navigator",
+        object(GlobalCategory::WEB)
+            .with_static("clearAppBadge", object(GlobalCategory::WEB))
+            .with_static("setAppBadge", object(GlobalCategory::WEB)),
+    );
     add(g, "document", object(GlobalCategory::WEB));
     add(g, "structuredClone", object(GlobalCategory::WEB));
     add(g, "atob", object(GlobalCategory::WEB));
@@ -911,16 +1170,31 @@ fn add_globals_js(g: &mut FxHashMap<&'static str, GlobalValue>) {
     add(g, "DragEvent", object(GlobalCategory::WEB));
 
     add(g, "AbortController", object(GlobalCategory::WEB));
-    add(g, "AbortSignal", object(GlobalCategory::WEB));
-    add(g, "Blob", object(GlobalCategory::WEB));
+    add(
+        g,
+        "AbortSignal",
+        object(GlobalCategory::WEB)
+            .with_static("abort", object(GlobalCategory::WEB))
+            .with_static("any", object(GlobalCategory::WEB))
+            .with_static("timeout", object(GlobalCategory::WEB)),
+    );
+    add(g, "Blob", object(GlobalCategory::WEB).with_func(func().pure()));
     add(g, "VideoFrame", object(GlobalCategory::WEB));
     add(g, "FormData", object(GlobalCategory::WEB));
     add(g, "XMLHttpRequest", object(GlobalCategory::WEB));
 
     // https://developer.mozilla.org/en-US/docs/Web/API/Fetch_API
-    add(g, "Headers", object(GlobalCategory::WEB));
-    add(g, "Request", object(GlobalCategory::WEB));
-    add(g, "Response", object(GlobalCategory::WEB));
+    add(g, "Headers", object(GlobalCategory::WEB).with_func(func().pure()));
+    add(g, "Request", object(GlobalCategory::WEB).with_func(func().pure()));
+    add(
+        g,
+        "Response",
+        object(GlobalCategory::WEB)
+            .with_func(func().pure())
+            .with_static("error", object(GlobalCategory::WEB))
+            .with_static("json", object(GlobalCategory::WEB))
+            .with_static("redirect", object(GlobalCategory::WEB)),
+    );
     add(g, "fetch", object(GlobalCategory::WEB));
 
     // https://developer.mozilla.org/en-US/docs/Web/API/WebSockets_API
@@ -942,6 +1216,10 @@ fn add_globals_js(g: &mut FxHashMap<&'static str, GlobalValue>) {
     add(g, "ReadableByteStreamController", object(GlobalCategory::WEB));
     add(g, "ReadableStreamBYOBRequest", object(GlobalCategory::WEB));
 
+    // https://developer.mozilla.org/en-US/docs/Web/API/Compression_Streams_API
+    add(g, "CompressionStream", object(GlobalCategory::WEB).with_func(func().pure()));
+    add(g, "DecompressionStream", object(GlobalCategory::WEB).with_func(func().pure()));
+
     // https://developer.mozilla.org/en-US/docs/Web/API/Clipboard_API
     add(g, "Clipboard", object(GlobalCategory::WEB));
     add(g, "ClipboardEvent", object(GlobalCategory::WEB));
@@ -1113,6 +1391,68 @@ fn add_globals_js(g: &mut FxHashMap<&'static str, GlobalValue>) {
     add(g, "Path2D", object(GlobalCategory::WEB)); // Experimental
     add(g, "ImageBitmapRenderingContext", object(GlobalCategory::WEB)); // Experimental
 
+    // https://developer.mozilla.org/en-US/docs/Web/API/WebGL_API
+    add(g, "WebGLRenderingContext", object(GlobalCategory::WEB));
+    add(g, "WebGL2RenderingContext", object(GlobalCategory::WEB));
+    add(g, "WebGLActiveInfo", object(GlobalCategory::WEB));
+    add(g, "WebGLBuffer", object(GlobalCategory::WEB));
+    add(g, "WebGLContextEvent", object(GlobalCategory::WEB));
+    add(g, "WebGLFramebuffer", object(GlobalCategory::WEB));
+    add(g, "WebGLProgram", object(GlobalCategory::WEB));
+    add(g, "WebGLQuery", object(GlobalCategory::WEB));
+    add(g, "WebGLRenderbuffer", object(GlobalCategory::WEB));
+    add(g, "WebGLSampler", object(GlobalCategory::WEB));
+    add(g, "WebGLShader", object(GlobalCategory::WEB));
+    add(g, "WebGLShaderPrecisionFormat", object(GlobalCategory::WEB));
+    add(g, "WebGLSync", object(GlobalCategory::WEB));
+    add(g, "WebGLTexture", object(GlobalCategory::WEB));
+    add(g, "WebGLTransformFeedback", object(GlobalCategory::WEB));
+    add(g, "WebGLUniformLocation", object(GlobalCategory::WEB));
+    add(g, "WebGLVertexArrayObject", object(GlobalCategory::WEB));
+
+    // https://developer.mozilla.org/en-US/docs/Web/API/WebGPU_API
+    add(g, "GPU", object(GlobalCategory::WEB));
+    add(g, "GPUAdapter", object(GlobalCategory::WEB));
+    add(g, "GPUAdapterInfo", object(GlobalCategory::WEB));
+    add(g, "GPUBindGroup", object(GlobalCategory::WEB));
+    add(g, "GPUBindGroupLayout", object(GlobalCategory::WEB));
+    add(g, "GPUBuffer", object(GlobalCategory::WEB));
+    add(g, "GPUBufferUsage", object(GlobalCategory::WEB));
+    add(g, "GPUCanvasContext", object(GlobalCategory::WEB));
+    add(g, "GPUColorWrite", object(GlobalCategory::WEB));
+    add(g, "GPUCommandBuffer", object(GlobalCategory::WEB));
+    add(g, "GPUCommandEncoder", object(GlobalCategory::WEB));
+    add(g, "GPUCompilationInfo", object(GlobalCategory::WEB));
+    add(g, "GPUCompilationMessage", object(GlobalCategory::WEB));
+    add(g, "GPUComputePassEncoder", object(GlobalCategory::WEB));
+    add(g, "GPUComputePipeline", object(GlobalCategory::WEB));
+    add(g, "GPUDevice", object(GlobalCategory::WEB));
+    add(g, "GPUDeviceLostInfo", object(GlobalCategory::WEB));
+    add(g, "GPUError", object(GlobalCategory::WEB));
+    add(g, "GPUExternalTexture", object(GlobalCategory::WEB));
+    add(g, "GPUInternalError", object(GlobalCategory::WEB));
+    add(g, "GPUMapMode", object(GlobalCategory::WEB));
+    add(g, "GPUOutOfMemoryError", object(GlobalCategory::WEB));
+    add(g, "GPUPipelineError", object(GlobalCategory::WEB));
+    add(g, "GPUPipelineLayout", object(GlobalCategory::WEB));
+    add(g, "GPUQuerySet", object(GlobalCategory::WEB));
+    add(g, "GPUQueue", object(GlobalCategory::WEB));
+    add(g, "GPURenderBundle", object(GlobalCategory::WEB));
+    add(g, "GPURenderBundleEncoder", object(GlobalCategory::WEB));
+    add(g, "GPURenderPassEncoder", object(GlobalCategory::WEB));
+    add(g, "GPURenderPipeline", object(GlobalCategory::WEB));
+    add(g, "GPUSampler", object(GlobalCategory::WEB));
+    add(g, "GPUShaderModule", object(GlobalCategory::WEB));
+    add(g, "GPUShaderStage", object(GlobalCategory::WEB));
+    add(g, "GPUSupportedFeatures", object(GlobalCategory::WEB));
+    add(g, "GPUSupportedLimits", object(GlobalCategory::WEB));
+    add(g, "GPUTexture", object(GlobalCategory::WEB));
+    add(g, "GPUTextureUsage", object(GlobalCategory::WEB));
+    add(g, "GPUTextureView", object(GlobalCategory::WEB));
+    add(g, "GPUUncapturedErrorEvent", object(GlobalCategory::WEB));
+    add(g, "GPUValidationError", object(GlobalCategory::WEB));
+    add(g, "WGSLLanguageFeatures", object(GlobalCategory::WEB));
+
     // https://developer.mozilla.org/en-US/docs/Web/API/Web_Animations_API
     add(g, "Animation", object(GlobalCategory::WEB));
     add(g, "AnimationEffect", object(GlobalCategory::WEB));
@@ -1148,6 +1488,8 @@ fn add_globals_js(g: &mut FxHashMap<&'static str, GlobalValue>) {
     add(g, "PasswordCredential", object(GlobalCategory::WEB));
 
     // https://developer.mozilla.org/en-US/docs/Web/API/Web_Workers_API
+    add(g, "SharedWorker", object(GlobalCategory::WEB));
+    add(g, "Worker", object(GlobalCategory::WEB));
     add(g, "WorkerNavigator", object(GlobalCategory::WEB));
     add(g, "WorkerGlobalScope", object(GlobalCategory::WEB));
 
@@ -1167,6 +1509,19 @@ fn add_globals_js(g: &mut FxHashMap<&'static str, GlobalValue>) {
     add(g, "WindowClient", object(GlobalCategory::WEB));
     add(g, "caches", object(GlobalCategory::WEB));
 
+    // https://developer.mozilla.org/en-US/docs/Web/API/Notifications_API
+    // `Notification.permission` is omitted, it changes when permission is granted.
+    add(
+        g,
+        "Notification",
+        object(GlobalCategory::WEB).with_static("requestPermission", object(GlobalCategory::WEB)),
+    );
+    add(g, "NotificationEvent", object(GlobalCategory::WEB));
+
+    // https://developer.mozilla.org/en-US/docs/Web/API/Permissions_API
+    add(g, "PermissionStatus", object(GlobalCategory::WEB));
+    add(g, "Permissions", object(GlobalCategory::WEB));
+
     // https://developer.mozilla.org/en-US/docs/Web/API/Cookie_Store_API
     add(g, "cookieStore", object(GlobalCategory::WEB)); // Experimental
     add(g, "CookieStore", object(GlobalCategory::WEB)); // Experimental
@@ -1183,6 +1538,19 @@ fn add_globals_js(g: &mut FxHashMap<&'static str, GlobalValue>) {
     add(g, "DeviceMotionEventRotationRate", object(GlobalCategory::WEB));
     add(g, "DeviceOrientationEvent", object(GlobalCategory::WEB));
 
+    // https://developer.mozilla.org/en-US/docs/Web/API/Sensor_APIs
+    add(g, "Sensor", object(GlobalCategory::WEB));
+    add(g, "SensorErrorEvent", object(GlobalCategory::WEB));
+    add(g, "Accelerometer", object(GlobalCategory::WEB));
+    add(g, "AbsoluteOrientationSensor", object(GlobalCategory::WEB));
+    add(g, "AmbientLightSensor", object(GlobalCategory::WEB)); // Experimental
+    add(g, "GravitySensor", object(GlobalCategory::WEB));
+    add(g, "Gyroscope", object(GlobalCategory::WEB));
+    add(g, "LinearAccelerationSensor", object(GlobalCategory::WEB));
+    add(g, "Magnetometer", object(GlobalCategory::WEB)); // Experimental
+    add(g, "OrientationSensor", object(GlobalCategory::WEB));
+    add(g, "RelativeOrientationSensor", object(GlobalCategory::WEB));
+
     // https://developer.mozilla.org/en-US/docs/Web/API/Resize_Observer_API
     add(g, "ResizeObserver", object(GlobalCategory::WEB));
     add(g, "ResizeObserverEntry", object(GlobalCategory::WEB));
@@ -1271,3 +1639,205 @@ fn add_globals_js(g: &mut FxHashMap<&'static str, GlobalValue>) {
     add(g, "GeolocationCoordinates", object(GlobalCategory::WEB));
     add(g, "GeolocationPositionError", object(GlobalCategory::WEB));
 }
+
+fn add_globals_deno(g: &mut FxHashMap<&'static str, GlobalValue>) {
+    // https://docs.deno.com/api/deno/
+    add(
+        g,
+        "Deno",
+        object(GlobalCategory::DENO)
+            .with_static("Command", object(GlobalCategory::DENO))
+            .with_static(
+                "env",
+                object(GlobalCategory::DENO)
+                    .with_static("delete", object(GlobalCategory::DENO))
+                    .with_static("get", object(GlobalCategory::DENO))
+                    .with_static("has", object(GlobalCategory::DENO))
+                    .with_static("set", object(GlobalCategory::DENO))
+                    .with_static("toObject", object(GlobalCategory::DENO)),
+            )
+            .with_static(
+                "errors",
+                object(GlobalCategory::DENO)
+                    .with_static("AddrInUse", object(GlobalCategory::DENO))
+                    .with_static("AddrNotAvailable", object(GlobalCategory::DENO))
+                    .with_static("AlreadyExists", object(GlobalCategory::DENO))
+                    .with_static("BadResource", object(GlobalCategory::DENO))
+                    .with_static("BrokenPipe", object(GlobalCategory::DENO))
+                    .with_static("Busy", object(GlobalCategory::DENO))
+                    .with_static("ConnectionAborted", object(GlobalCategory::DENO))
+                    .with_static("ConnectionRefused", object(GlobalCategory::DENO))
+                    .with_static("ConnectionReset", object(GlobalCategory::DENO))
+                    .with_static("Http", object(GlobalCategory::DENO))
+                    .with_static("Interrupted", object(GlobalCategory::DENO))
+                    .with_static("InvalidData", object(GlobalCategory::DENO))
+                    .with_static("NotCapable", object(GlobalCategory::DENO))
+                    .with_static("NotConnected", object(GlobalCategory::DENO))
+                    .with_static("NotFound", object(GlobalCategory::DENO))
+                    .with_static("NotSupported", object(GlobalCategory::DENO))
+                    .with_static("PermissionDenied", object(GlobalCategory::DENO))
+                    .with_static("TimedOut", object(GlobalCategory::DENO))
+                    .with_static("UnexpectedEof", object(GlobalCategory::DENO))
+                    .with_static("WriteZero", object(GlobalCategory::DENO)),
+            )
+            .with_static(
+                "permissions",
+                object(GlobalCategory::DENO)
+                    .with_static("query", object(GlobalCategory::DENO))
+                    .with_static("querySync", object(GlobalCategory::DENO))
+                    .with_static("request", object(GlobalCategory::DENO))
+                    .with_static("requestSync", object(GlobalCategory::DENO))
+                    .with_static("revoke", object(GlobalCategory::DENO))
+                    .with_static("revokeSync", object(GlobalCategory::DENO)),
+            )
+            .with_static("addSignalListener", object(GlobalCategory::DENO))
+            .with_static("args", object(GlobalCategory::DENO))
+            .with_static("build", object(GlobalCategory::DENO))
+            .with_static("chdir", object(GlobalCategory::DENO))
+            .with_static("chmod", object(GlobalCategory::DENO))
+            .with_static("chmodSync", object(GlobalCategory::DENO))
+            .with_static("chown", object(GlobalCategory::DENO))
+            .with_static("chownSync", object(GlobalCategory::DENO))
+            .with_static("connect", object(GlobalCategory::DENO))
+            .with_static("connectTls", object(GlobalCategory::DENO))
+            .with_static("copyFile", object(GlobalCategory::DENO))
+            .with_static("copyFileSync", object(GlobalCategory::DENO))
+            .with_static("create", object(GlobalCategory::DENO))
+            .with_static("createSync", object(GlobalCategory::DENO))
+            .with_static("cwd", object(GlobalCategory::DENO))
+            .with_static("execPath", object(GlobalCategory::DENO))
+            .with_static("exit", object(GlobalCategory::DENO))
+            .with_static("gid", object(GlobalCategory::DENO))
+            .with_static("hostname", object(GlobalCategory::DENO))
+            .with_static("inspect", object(GlobalCategory::DENO))
+            .with_static("link", object(GlobalCategory::DENO))
+            .with_static("linkSync", object(GlobalCategory::DENO))
+            .with_static("listen", object(GlobalCategory::DENO))
+            .with_static("listenTls", object(GlobalCategory::DENO))
+            .with_static("loadavg", object(GlobalCategory::DENO))
+            .with_static("lstat", object(GlobalCategory::DENO))
+            .with_static("lstatSync", object(GlobalCategory::DENO))
+            .with_static("makeTempDir", object(GlobalCategory::DENO))
+            .with_static("makeTempDirSync", object(GlobalCategory::DENO))
+            .with_static("makeTempFile", object(GlobalCategory::DENO))
+            .with_static("makeTempFileSync", object(GlobalCategory::DENO))
+            .with_static("memoryUsage", object(GlobalCategory::DENO))
+            .with_static("mkdir", object(GlobalCategory::DENO))
+            .with_static("mkdirSync", object(GlobalCategory::DENO))
+            .with_static("networkInterfaces", object(GlobalCategory::DENO))
+            .with_static("noColor", object(GlobalCategory::DENO))
+            .with_static("open", object(GlobalCategory::DENO))
+            .with_static("openKv", object(GlobalCategory::DENO))
+            .with_static("openSync", object(GlobalCategory::DENO))
+            .with_static("osRelease", object(GlobalCategory::DENO))
+            .with_static("osUptime", object(GlobalCategory::DENO))
+            .with_static("pid", object(GlobalCategory::DENO))
+            .with_static("ppid", object(GlobalCategory::DENO))
+            .with_static("readDir", object(GlobalCategory::DENO))
+            .with_static("readDirSync", object(GlobalCategory::DENO))
+            .with_static("readFile", object(GlobalCategory::DENO))
+            .with_static("readFileSync", object(GlobalCategory::DENO))
+            .with_static("readLink", object(GlobalCategory::DENO))
+            .with_static("readLinkSync", object(GlobalCategory::DENO))
+            .with_static("readTextFile", object(GlobalCategory::DENO))
+            .with_static("readTextFileSync", object(GlobalCategory::DENO))
+            .with_static("realPath", object(GlobalCategory::DENO))
+            .with_static("realPathSync", object(GlobalCategory::DENO))
+            .with_static("remove", object(GlobalCategory::DENO))
+            .with_static("removeSignalListener", object(GlobalCategory::DENO))
+            .with_static("removeSync", object(GlobalCategory::DENO))
+            .with_static("rename", object(GlobalCategory::DENO))
+            .with_static("renameSync", object(GlobalCategory::DENO))
+            .with_static("resolveDns", object(GlobalCategory::DENO))
+            .with_static("serve", object(GlobalCategory::DENO))
+            .with_static("stat", object(GlobalCategory::DENO))
+            .with_static("statSync", object(GlobalCategory::DENO))
+            .with_static("stderr", object(GlobalCategory::DENO))
+            .with_static("stdin", object(GlobalCategory::DENO))
+            .with_static("stdout", object(GlobalCategory::DENO))
+            .with_static("symlink", object(GlobalCategory::DENO))
+            .with_static("symlinkSync", object(GlobalCategory::DENO))
+            .with_static("systemMemoryInfo", object(GlobalCategory::DENO))
+            .with_static("truncate", object(GlobalCategory::DENO))
+            .with_static("truncateSync", object(GlobalCategory::DENO))
+            .with_static("uid", object(GlobalCategory::DENO))
+            .with_static("upgradeWebSocket", object(GlobalCategory::DENO))
+            .with_static("utime", object(GlobalCategory::DENO))
+            .with_static("utimeSync", object(GlobalCategory::DENO))
+            .with_static("version", object(GlobalCategory::DENO))
+            .with_static("watchFs", object(GlobalCategory::DENO))
+            .with_static("writeFile", object(GlobalCategory::DENO))
+            .with_static("writeFileSync", object(GlobalCategory::DENO))
+            .with_static("writeTextFile", object(GlobalCategory::DENO))
+            .with_static("writeTextFileSync", object(GlobalCategory::DENO)),
+    );
+}
+
+fn add_globals_bun(g: &mut FxHashMap<&'static str, GlobalValue>) {
+    // https://bun.com/reference/bun
+    add(
+        g,
+        "Bun",
+        object(GlobalCategory::BUN)
+            .with_static("CryptoHasher", object(GlobalCategory::BUN))
+            .with_static("Glob", object(GlobalCategory::BUN))
+            .with_static("Transpiler", object(GlobalCategory::BUN))
+            .with_static(
+                "password",
+                object(GlobalCategory::BUN)
+                    .with_static("hash", object(GlobalCategory::BUN))
+                    .with_static("hashSync", object(GlobalCategory::BUN))
+                    .with_static("verify", object(GlobalCategory::BUN))
+                    .with_static("verifySync", object(GlobalCategory::BUN)),
+            )
+            .with_static(
+                "semver",
+                object(GlobalCategory::BUN)
+                    .with_static("order", object(GlobalCategory::BUN))
+                    .with_static("satisfies", object(GlobalCategory::BUN)),
+            )
+            .with_static("$", object(GlobalCategory::BUN))
+            .with_static("allocUnsafe", object(GlobalCategory::BUN))
+            .with_static("argv", object(GlobalCategory::BUN))
+            .with_static("build", object(GlobalCategory::BUN))
+            .with_static("concatArrayBuffers", object(GlobalCategory::BUN))
+            .with_static("connect", object(GlobalCategory::BUN))
+            .with_static("deepEquals", object(GlobalCategory::BUN))
+            .with_static("deflateSync", object(GlobalCategory::BUN))
+            .with_static("escapeHTML", object(GlobalCategory::BUN))
+            .with_static("file", object(GlobalCategory::BUN))
+            .with_static("fileURLToPath", object(GlobalCategory::BUN))
+            .with_static("gc", object(GlobalCategory::BUN))
+            .with_static("gunzipSync", object(GlobalCategory::BUN))
+            .with_static("gzipSync", object(GlobalCategory::BUN))
+            .with_static("hash", object(GlobalCategory::BUN))
+            .with_static("inflateSync", object(GlobalCategory::BUN))
+            .with_static("inspect", object(GlobalCategory::BUN))
+            .with_static("listen", object(GlobalCategory::BUN))
+            .with_static("main", object(GlobalCategory::BUN))
+            .with_static("nanoseconds", object(GlobalCategory::BUN))
+            .with_static("openInEditor", object(GlobalCategory::BUN))
+            .with_static("pathToFileURL", object(GlobalCategory::BUN))
+            .with_static("peek", object(GlobalCategory::BUN))
+            .with_static("randomUUIDv7", object(GlobalCategory::BUN))
+            .with_static("readableStreamToArrayBuffer", object(GlobalCategory::BUN))
+            .with_static("readableStreamToBlob", object(GlobalCategory::BUN))
+            .with_static("readableStreamToJSON", object(GlobalCategory::BUN))
+            .with_static("readableStreamToText", object(GlobalCategory::BUN))
+            .with_static("resolveSync", object(GlobalCategory::BUN))
+            .with_static("revision", object(GlobalCategory::BUN))
+            .with_static("serve", object(GlobalCategory::BUN))
+            .with_static("sleep", object(GlobalCategory::BUN))
+            .with_static("sleepSync", object(GlobalCategory::BUN))
+            .with_static("spawn", object(GlobalCategory::BUN))
+            .with_static("spawnSync", object(GlobalCategory::BUN))
+            .with_static("stderr", object(GlobalCategory::BUN))
+            .with_static("stdin", object(GlobalCategory::BUN))
+            .with_static("stdout", object(GlobalCategory::BUN))
+            .with_static("stringWidth", object(GlobalCategory::BUN))
+            .with_static("udpSocket", object(GlobalCategory::BUN))
+            .with_static("version", object(GlobalCategory::BUN))
+            .with_static("which", object(GlobalCategory::BUN))
+            .with_static("write", object(GlobalCategory::BUN)),
+    );
+}
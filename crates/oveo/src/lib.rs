@@ -1,57 +1,350 @@
 use std::path::PathBuf;
 
 use oxc_allocator::Allocator;
-use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_ast::ast::Program;
+use oxc_codegen::{Codegen, CodegenOptions, CommentOptions, LegalComment};
+use oxc_diagnostics::OxcDiagnostic;
 use oxc_parser::Parser;
-use oxc_semantic::SemanticBuilder;
+use oxc_semantic::{Scoping, SemanticBuilder};
+use oxc_sourcemap::{SourceMap, Token};
 use oxc_span::SourceType;
+use serde::Deserialize;
 
-use crate::{externs::ExternMap, property_names::LocalPropertyMap};
+use crate::{
+    debug_comments::DebugComments, externs::ExternMap, property_names::LocalPropertyMap,
+    warnings::Warnings,
+};
 pub use globals::GlobalCategory;
-pub use property_names::PropertyMap;
+pub use property_names::{PropertyMap, PropertyMapStats, PropertyNaming};
 
 pub mod annotation;
 pub(crate) mod chunk;
 pub(crate) mod context;
+pub(crate) mod debug_comments;
+pub(crate) mod debug_names;
 pub mod externs;
 pub(crate) mod globals;
 pub(crate) mod module;
 pub(crate) mod property_names;
 pub(crate) mod statements;
+pub(crate) mod warnings;
 
-#[derive(Default, Debug)]
+#[derive(Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct OptimizerOptions {
     pub hoist: bool,
+    /// Disables hoisting inside of async and generator functions.
+    pub no_hoist_in_async_generators: bool,
+    /// Logs hoist stack entries and Hoist Scope decisions to
+    /// `OptimizerStats::hoist_log`.
+    pub debug_hoist: bool,
     pub dedupe: bool,
+    /// Expressions in top-level IIFEs that wrap modules aren't deduplicated
+    /// with expressions outside of them.
+    pub dedupe_isolate_module_wrappers: bool,
+    /// Expressions without references to local bindings are deduplicated
+    /// across sibling scopes and hoisted to the module top level.
+    pub dedupe_sibling_scopes: bool,
+    /// Call expressions without `/* @__PURE__ */` annotations are
+    /// deduplicated, calls with side effects are invoked only once.
+    pub dedupe_calls: bool,
     pub globals: GlobalsOptions,
+    pub externs: ExternsOptions,
     pub rename_properties: bool,
+    /// Renames properties only in objects annotated with `keyObject()`.
+    pub rename_key_objects_only: bool,
+    pub pure: bool,
+    /// Removes unreachable statements after `return`, `throw`, `break` and
+    /// `continue` statements.
+    pub dce: bool,
+    /// Replaces single reads of local `const` bindings with literal values.
+    pub const_propagation: bool,
+    /// Inlines calls annotated with `inline()` to local arrow functions.
+    pub inline: bool,
+    /// Annotates well-known global constructors with constant arguments as pure.
+    pub mark_pure_constructors: bool,
+    pub stringify_large_literals: bool,
+    /// Minimal size (in bytes) of a JSON string for `stringify_large_literals`.
+    pub stringify_threshold: usize,
     pub url: Option<String>,
+    /// Comments that are preserved in the output, annotation comments
+    /// (`/* @__PURE__ */`) are always preserved.
+    pub preserve_comments: PreserveComments,
+    /// Appends comments with the originating pass to injected declarations,
+    /// e.g. `const _GLOBAL_ = fetch; // oveo:global fetch`.
+    pub debug_comments: bool,
+    /// Injected declarations are named after the original global or the
+    /// deduplicated expression, e.g. `_GLOBAL_Math_PI` instead of `_GLOBAL_2`.
+    pub debug_names: bool,
+    /// Chunk pass leaves `__oveo__(expr, flags)` annotation calls unchanged,
+    /// so that the module pass output can be inspected.
+    pub keep_annotations: bool,
 }
 
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PreserveComments {
+    #[default]
+    All,
+    /// Legal comments: `/*! .. */`, `/* @license */` and `/* @preserve */`.
+    Legal,
+    None,
+}
+
+impl PreserveComments {
+    fn comment_options(self) -> CommentOptions {
+        let legal = if self == Self::None { LegalComment::None } else { LegalComment::Inline };
+        let other = self == Self::All;
+        CommentOptions { normal: other, jsdoc: other, annotation: true, legal }
+    }
+}
+
+impl std::str::FromStr for PreserveComments {
+    type Err = OptimizerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "all" => Ok(Self::All),
+            "legal" => Ok(Self::Legal),
+            "none" => Ok(Self::None),
+            _ => Err(OptimizerError::OptionsParseError(format!("Invalid preserve comments: {s}"))),
+        }
+    }
+}
+
+/// Default value for `OptimizerOptions::stringify_threshold`.
+pub const DEFAULT_STRINGIFY_THRESHOLD: usize = 1024;
+
+impl Default for OptimizerOptions {
+    fn default() -> Self {
+        Self {
+            hoist: false,
+            no_hoist_in_async_generators: false,
+            debug_hoist: false,
+            dedupe: false,
+            dedupe_isolate_module_wrappers: false,
+            dedupe_sibling_scopes: false,
+            dedupe_calls: false,
+            globals: GlobalsOptions::default(),
+            externs: ExternsOptions::default(),
+            rename_properties: false,
+            rename_key_objects_only: false,
+            pure: false,
+            dce: false,
+            const_propagation: false,
+            inline: false,
+            mark_pure_constructors: false,
+            stringify_large_literals: false,
+            stringify_threshold: DEFAULT_STRINGIFY_THRESHOLD,
+            url: None,
+            preserve_comments: PreserveComments::default(),
+            debug_comments: false,
+            debug_names: false,
+            keep_annotations: false,
+        }
+    }
+}
+
+impl OptimizerOptions {
+    fn debug_comments(&self) -> DebugComments {
+        DebugComments::new(self.debug_comments && self.preserve_comments != PreserveComments::None)
+    }
+
+    /// Deserializes options from a JSON document with camelCase keys.
+    ///
+    /// ```json
+    /// { "hoist": true, "globals": { "include": ["js", "web"], "hoist": true } }
+    /// ```
+    pub fn from_json(data: &[u8]) -> Result<Self, OptimizerError> {
+        serde_json::from_slice(data)
+            .map_err(|err| OptimizerError::OptionsParseError(err.to_string()))
+    }
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct GlobalsOptions {
     pub include: GlobalCategory,
     pub hoist: bool,
     pub singletons: bool,
+    /// Minimal number of references to a global identifier before it is
+    /// hoisted, `0` and `1` hoist all globals.
+    pub min_uses: u32,
+    /// Additional constructors that should be deduplicated as singletons,
+    /// e.g. `Intl.NumberFormat`.
+    pub singleton_constructors: Vec<String>,
+    pub scope: GlobalsScope,
+    /// Hoists constant object and array literals inside of functions without
+    /// an explicit `hoist()` annotation, requires `OptimizerOptions::hoist`.
+    pub auto_hoist_literals: bool,
+    /// Global identifiers that are referenced only in the root scope aren't
+    /// hoisted, hoisting them doesn't reduce repeated lookups.
+    pub no_hoist_in_top_scope: bool,
+}
+
+/// Scope where global values are hoisted.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GlobalsScope {
+    /// Hoists globals to the top level of the chunk.
+    #[default]
+    Root,
+    /// Hoists globals to the top of the nearest function body that uses them.
+    Nearest,
+}
+
+impl std::str::FromStr for GlobalsScope {
+    type Err = OptimizerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "root" => Ok(Self::Root),
+            "nearest" => Ok(Self::Nearest),
+            _ => Err(OptimizerError::OptionsParseError(format!("Invalid globals scope: {s}"))),
+        }
+    }
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ExternsOptions {
+    pub inline_const_values: bool,
+    /// Maximum size (in bytes) of a const value that can be inlined, values
+    /// above this limit are left as references.
+    pub max_inline_size: Option<usize>,
+}
+
+/// Callback that receives warnings as soon as they are reported, warnings
+/// are also returned in `OptimizerOutput::warnings`.
+pub type WarningSink<'s> = &'s dyn Fn(&str);
+
+/// Source file of an optimized module or chunk.
+#[derive(Default, Clone, Copy)]
+pub struct SourceOptions<'s> {
+    /// File name that is used in the `file` and `sources` fields of the output
+    /// source map.
+    pub file_name: &'s str,
+    /// Source map of the input source text (e.g. produced by TypeScript), the
+    /// output source map is remapped to the original sources.
+    pub input_map: Option<&'s str>,
+    /// Receives warnings while the source is optimized.
+    pub on_warning: Option<WarningSink<'s>>,
+}
+
+impl std::fmt::Debug for SourceOptions<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SourceOptions")
+            .field("file_name", &self.file_name)
+            .field("input_map", &self.input_map)
+            .field("on_warning", &self.on_warning.is_some())
+            .finish()
+    }
 }
 
 pub struct OptimizerOutput {
     pub code: String,
     pub map: String,
+    pub stats: OptimizerStats,
+    /// Non-fatal issues, e.g. annotated expressions that couldn't be
+    /// optimized and were emitted unchanged.
+    pub warnings: Vec<String>,
+}
+
+#[derive(Default, Debug)]
+pub struct OptimizerStats {
+    /// Extern exports referenced by the module as `(module, export)` pairs.
+    pub used_externs: Vec<(String, String)>,
+    /// Hoist stack entries and Hoist Scope decisions, collected when
+    /// `OptimizerOptions::debug_hoist` is enabled.
+    pub hoist_log: Vec<String>,
+    /// Number of duplicate expressions replaced with references to
+    /// deduplicated expressions.
+    pub duplicates_collapsed: u32,
+    /// Number of injected declarations with hoisted globals.
+    pub globals_hoisted: u32,
+    /// Number of renamed property names.
+    pub properties_renamed: u32,
 }
 
 #[derive(Debug, thiserror::Error)]
 pub enum OptimizerError {
     #[error("Invalid module type: {0}")]
     ModuleType(String),
-    #[error("Unable to parse javascript file: {0}")]
-    SyntaxError(String),
-    #[error("Unable to parse javascript file: {0}")]
-    SemanticError(String),
+    #[error("Unable to parse javascript file: {message}")]
+    SyntaxError { message: String, location: Option<SourceLocation> },
+    #[error("Unable to parse javascript file: {message}")]
+    SemanticError { message: String, location: Option<SourceLocation> },
     #[error("Unable to optimize javascript file: {0}")]
     OptimizerError(String),
     #[error("Unable to parse property map: {0}")]
     PropertyMapParseError(String),
+    #[error("Unable to parse optimizer options: {0}")]
+    OptionsParseError(String),
+}
+
+impl OptimizerError {
+    /// Location of the error in the source text, available for syntax and
+    /// semantic errors that have a labeled span.
+    pub fn location(&self) -> Option<&SourceLocation> {
+        match self {
+            Self::SyntaxError { location, .. } | Self::SemanticError { location, .. } => {
+                location.as_ref()
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Position of an error in the source text.
+#[derive(Debug, Clone)]
+pub struct SourceLocation {
+    /// Byte offset from the start of the source text.
+    pub offset: u32,
+    /// 1-based line number.
+    pub line: u32,
+    /// 0-based column in UTF-16 code units, the same way as JS strings are
+    /// indexed.
+    pub column: u32,
+    /// Source text of the line that contains the error.
+    pub source: String,
+}
+
+impl SourceLocation {
+    fn from_diagnostic(source_text: &str, diagnostic: &OxcDiagnostic) -> Option<Self> {
+        let labels = diagnostic.labels.as_slice();
+        let label = labels.iter().find(|l| l.primary()).or_else(|| labels.first())?;
+        Self::from_offset(source_text, label.offset())
+    }
+
+    pub(crate) fn from_offset(source_text: &str, offset: u32) -> Option<Self> {
+        let offset = (offset as usize).min(source_text.len());
+        if !source_text.is_char_boundary(offset) {
+            return None;
+        }
+        let (before, after) = source_text.split_at(offset);
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let line_end = after.find('\n').map_or(source_text.len(), |i| offset + i);
+        Some(Self {
+            offset: offset as u32,
+            line: before.matches('\n').count() as u32 + 1,
+            column: before[line_start..].encode_utf16().count() as u32,
+            source: source_text[line_start..line_end].trim_end_matches('\r').to_string(),
+        })
+    }
+}
+
+fn syntax_error(source_text: &str, diagnostic: &OxcDiagnostic) -> OptimizerError {
+    OptimizerError::SyntaxError {
+        message: diagnostic.to_string(),
+        location: SourceLocation::from_diagnostic(source_text, diagnostic),
+    }
+}
+
+fn semantic_error(source_text: &str, diagnostic: &OxcDiagnostic) -> OptimizerError {
+    OptimizerError::SemanticError {
+        message: diagnostic.to_string(),
+        location: SourceLocation::from_diagnostic(source_text, diagnostic),
+    }
 }
 
 pub fn optimize_module(
@@ -59,81 +352,419 @@ pub fn optimize_module(
     module_type: &str,
     options: &OptimizerOptions,
     externs: &ExternMap,
+    source: &SourceOptions,
 ) -> Result<OptimizerOutput, OptimizerError> {
     let allocator = Allocator::default();
-    let source_type = match module_type {
-        "js" => SourceType::mjs(),
-        "jsx" => SourceType::jsx(),
-        "ts" => SourceType::ts(),
-        "tsx" => SourceType::tsx(),
-        _ => return Err(OptimizerError::ModuleType(module_type.to_string())),
-    };
-    let ret = Parser::new(&allocator, source_text, source_type).parse();
-    if let Some(err) = ret.diagnostics.first() {
-        return Err(OptimizerError::SyntaxError(err.to_string()));
-    }
+    let source_type = module_source_type(module_type)?;
+    let (mut program, scoping) = parse(&allocator, source_text, source_type)?;
 
-    let mut program = ret.program;
+    let mut debug_comments = options.debug_comments();
+    let (stats, warnings) = module::optimize_module(
+        &mut program,
+        options,
+        externs,
+        &allocator,
+        scoping,
+        &mut debug_comments,
+        Warnings::new(source.on_warning),
+    );
 
-    let ret = SemanticBuilder::new().with_excess_capacity(0.1).build(&program);
-    if let Some(err) = ret.diagnostics.first() {
-        return Err(OptimizerError::SemanticError(err.to_string()));
-    }
+    let comments = options.preserve_comments.comment_options();
+    let mut output = codegen(&program, stats, source.file_name, comments);
+    output.warnings = warnings;
+    debug_comments.apply(&mut output.code);
+    apply_input_map(output, source.input_map)
+}
 
-    let scoping = ret.semantic.into_scoping();
-    module::optimize_module(&mut program, options, externs, &allocator, scoping);
+/// Optimizes module and resolves its annotations in the same pass.
+///
+/// Output doesn't contain `__oveo__()` annotations and doesn't need to be
+/// processed by `optimize_chunk()`, so it can be consumed by bundlers that
+/// can't run the chunk pass.
+pub fn optimize_module_single_phase(
+    source_text: &str,
+    module_type: &str,
+    options: &OptimizerOptions,
+    externs: &ExternMap,
+    property_map: &PropertyMap,
+    source: &SourceOptions,
+) -> Result<OptimizerOutput, OptimizerError> {
+    let allocator = Allocator::default();
+    let source_type = module_source_type(module_type)?;
+    let (mut program, scoping) = parse(&allocator, source_text, source_type)?;
 
-    let result = Codegen::new()
-        .with_options(CodegenOptions {
-            source_map_path: Some(PathBuf::new()),
-            ..Default::default()
-        })
-        .build(&program);
+    let mut debug_comments = options.debug_comments();
+    let (mut stats, mut warnings) = module::optimize_module(
+        &mut program,
+        options,
+        externs,
+        &allocator,
+        scoping,
+        &mut debug_comments,
+        Warnings::new(source.on_warning),
+    );
+
+    // Annotations are resolved by the chunk optimizer on the same program,
+    // scoping is rebuilt to pick up symbols created by the module optimizer.
+    let scoping =
+        SemanticBuilder::new().with_excess_capacity(0.1).build(&program).semantic.into_scoping();
+    warnings.extend(chunk::optimize_chunk(
+        &mut program,
+        options,
+        LocalPropertyMap::new(property_map),
+        &allocator,
+        scoping,
+        &mut debug_comments,
+        &mut stats,
+        Warnings::new(source.on_warning),
+    ));
 
-    Ok(OptimizerOutput {
-        code: result.code,
-        map: result.map.map_or_else(String::default, |v| v.to_json_string()),
-    })
+    let comments = options.preserve_comments.comment_options();
+    let mut output = codegen(&program, stats, source.file_name, comments);
+    output.warnings = warnings;
+    debug_comments.apply(&mut output.code);
+    apply_input_map(output, source.input_map)
 }
 
+/// Optimizes chunk, `source.input_map` is a source map produced by plugins
+/// that transformed the chunk before it.
+///
+/// Fails only when the chunk can't be parsed, an invalid input source map is
+/// reported as a warning and the output source map isn't remapped.
 pub fn optimize_chunk(
     source_text: &str,
     options: &OptimizerOptions,
     property_map: &PropertyMap,
+    source: &SourceOptions,
 ) -> Result<OptimizerOutput, OptimizerError> {
     let allocator = Allocator::default();
-    let source_type = SourceType::mjs();
-    let ret = Parser::new(&allocator, source_text, source_type).parse();
-    if let Some(err) = ret.diagnostics.first() {
-        return Err(OptimizerError::SyntaxError(err.to_string()));
+    let mut output = optimize_chunk_in(&allocator, source_text, options, property_map, source)?;
+    if let Some(input_map) = source.input_map {
+        match remap_input_map(&output.map, input_map) {
+            Ok(map) => output.map = map,
+            Err(err) => {
+                let message = format!("input source map is ignored, {err}");
+                if let Some(on_warning) = source.on_warning {
+                    on_warning(&message);
+                }
+                output.warnings.push(message);
+            }
+        }
     }
+    Ok(output)
+}
 
-    let mut program = ret.program;
+/// Optimizes a batch of chunks, results are in the same order as sources.
+///
+/// Chunks are optimized sequentially and they share one allocator.
+pub fn optimize_chunks<S: AsRef<str>>(
+    sources: &[S],
+    options: &OptimizerOptions,
+    property_map: &PropertyMap,
+) -> Vec<Result<OptimizerOutput, OptimizerError>> {
+    optimize_chunks_with(sources, options, property_map, |_, _| {}, |_, _| {})
+}
 
-    let ret = SemanticBuilder::new().with_excess_capacity(0.1).build(&program);
-    if let Some(err) = ret.diagnostics.first() {
-        return Err(OptimizerError::SemanticError(err.to_string()));
+/// Optimizes a batch of chunks and invokes callbacks with the index of the
+/// source, so that warnings and errors can be reported before the whole
+/// batch is finished.
+///
+/// `on_warning` is invoked as soon as each warning is reported and
+/// `on_chunk` is invoked as soon as each chunk is optimized.
+pub fn optimize_chunks_with<S: AsRef<str>>(
+    sources: &[S],
+    options: &OptimizerOptions,
+    property_map: &PropertyMap,
+    on_warning: impl Fn(usize, &str),
+    mut on_chunk: impl FnMut(usize, &Result<OptimizerOutput, OptimizerError>),
+) -> Vec<Result<OptimizerOutput, OptimizerError>> {
+    let mut allocator = Allocator::default();
+    let mut outputs = Vec::with_capacity(sources.len());
+    for (index, source_text) in sources.iter().enumerate() {
+        allocator.reset();
+        let sink = |message: &str| on_warning(index, message);
+        let output = optimize_chunk_in(
+            &allocator,
+            source_text.as_ref(),
+            options,
+            property_map,
+            &SourceOptions { on_warning: Some(&sink), ..SourceOptions::default() },
+        );
+        on_chunk(index, &output);
+        outputs.push(output);
     }
+    outputs
+}
 
-    let scoping = ret.semantic.into_scoping();
+/// Optimizes chunk without applying `source.input_map`.
+fn optimize_chunk_in(
+    allocator: &Allocator,
+    source_text: &str,
+    options: &OptimizerOptions,
+    property_map: &PropertyMap,
+    source: &SourceOptions,
+) -> Result<OptimizerOutput, OptimizerError> {
+    let (mut program, scoping) = parse(allocator, source_text, CHUNK_SOURCE_TYPE)?;
+
+    let mut debug_comments = options.debug_comments();
+    debug_comments.collect(source_text);
+    let mut stats = OptimizerStats::default();
+    let warnings = chunk::optimize_chunk(
+        &mut program,
+        options,
+        LocalPropertyMap::new(property_map),
+        allocator,
+        scoping,
+        &mut debug_comments,
+        &mut stats,
+        Warnings::new(source.on_warning),
+    );
+
+    let comments = options.preserve_comments.comment_options();
+    let mut output = codegen(&program, stats, source.file_name, comments);
+    output.warnings = warnings;
+    debug_comments.apply(&mut output.code);
+    Ok(output)
+}
+
+/// Replaces property name placeholders in the chunk output.
+///
+/// Used in deterministic mode after `PropertyMap::finalize()`, `map` is the
+/// source map of the chunk output and it is remapped to the new output.
+pub fn finalize_chunk(
+    source_text: &str,
+    map: &str,
+    property_map: &PropertyMap,
+) -> Result<OptimizerOutput, OptimizerError> {
+    finalize(source_text, CHUNK_SOURCE_TYPE, map, property_map)
+}
+
+/// Replaces property name placeholders in the `optimize_module_single_phase()`
+/// output, module is parsed with its `module_type` (`"ts"`, `"tsx"`, etc).
+pub fn finalize_module(
+    source_text: &str,
+    module_type: &str,
+    map: &str,
+    property_map: &PropertyMap,
+) -> Result<OptimizerOutput, OptimizerError> {
+    finalize(source_text, module_source_type(module_type)?, map, property_map)
+}
+
+fn finalize(
+    source_text: &str,
+    source_type: SourceType,
+    map: &str,
+    property_map: &PropertyMap,
+) -> Result<OptimizerOutput, OptimizerError> {
+    let allocator = Allocator::default();
+    let (mut program, scoping) = parse(&allocator, source_text, source_type)?;
+
+    property_names::finalize::finalize_chunk(&mut program, property_map, &allocator, scoping);
 
+    let output = codegen(&program, OptimizerStats::default(), "", CommentOptions::default());
+    apply_input_map(output, (!map.is_empty()).then_some(map))
+}
+
+/// Adds properties that would be renamed in the chunk to the property map
+/// without generating the optimized output.
+///
+/// It can be used to build a complete property map from all chunks before
+/// they are renamed with `optimize_chunk()`.
+pub fn collect_properties(
+    source_text: &str,
+    options: &OptimizerOptions,
+    property_map: &PropertyMap,
+) -> Result<(), OptimizerError> {
+    let allocator = Allocator::default();
+    let (mut program, scoping) = parse(&allocator, source_text, CHUNK_SOURCE_TYPE)?;
+
+    // Properties are discovered by the chunk optimizer when annotations are
+    // resolved, so the whole chunk is optimized and the output is discarded.
     chunk::optimize_chunk(
         &mut program,
         options,
         LocalPropertyMap::new(property_map),
         &allocator,
         scoping,
+        &mut DebugComments::new(false),
+        &mut OptimizerStats::default(),
+        Warnings::new(None),
     );
+    Ok(())
+}
+
+/// Remaps output source map to the original sources of the input source map.
+fn apply_input_map(
+    mut output: OptimizerOutput,
+    input_map: Option<&str>,
+) -> Result<OptimizerOutput, OptimizerError> {
+    if let Some(input_map) = input_map {
+        output.map =
+            remap_input_map(&output.map, input_map).map_err(OptimizerError::OptimizerError)?;
+    }
+    Ok(output)
+}
+
+fn remap_input_map(map: &str, input_map: &str) -> Result<String, String> {
+    let prev = SourceMap::from_json_string(input_map)
+        .map_err(|err| format!("invalid source map: {err}"))?;
+    let next =
+        SourceMap::from_json_string(map).map_err(|err| format!("invalid source map: {err}"))?;
+    Ok(remap_source_map(&prev, &next).to_json_string())
+}
+
+/// Maps tokens from the `next` source map to the original positions in the
+/// `prev` source map.
+fn remap_source_map<'a>(prev: &SourceMap<'a>, next: &SourceMap<'_>) -> SourceMap<'a> {
+    let lookup_table = prev.generate_lookup_table();
+    let tokens = next
+        .get_tokens()
+        .filter_map(|token| {
+            let t = prev.lookup_token(&lookup_table, token.get_src_line(), token.get_src_col())?;
+            Some(Token::new(
+                token.get_dst_line(),
+                token.get_dst_col(),
+                t.get_src_line(),
+                t.get_src_col(),
+                t.get_source_id(),
+                t.get_name_id(),
+            ))
+        })
+        .collect();
+    SourceMap::new(
+        next.get_file().or(prev.get_file()).map(|v| v.to_string().into()),
+        prev.get_names().map(|v| v.to_string().into()).collect(),
+        prev.get_source_root().map(|v| v.to_string().into()),
+        prev.get_sources().map(|v| v.to_string().into()).collect(),
+        prev.get_source_contents().map(|v| v.map(|v| v.to_string().into())).collect(),
+        tokens,
+        None,
+    )
+}
+
+/// Chunks can contain JSX when the optimizer runs before JSX is transformed.
+const CHUNK_SOURCE_TYPE: SourceType = SourceType::jsx();
+
+fn module_source_type(module_type: &str) -> Result<SourceType, OptimizerError> {
+    match module_type {
+        "js" => Ok(SourceType::mjs()),
+        "cjs" => Ok(SourceType::cjs()),
+        "jsx" => Ok(SourceType::jsx()),
+        "ts" => Ok(SourceType::ts()),
+        "tsx" => Ok(SourceType::tsx()),
+        _ => Err(OptimizerError::ModuleType(module_type.to_string())),
+    }
+}
 
+fn parse<'a>(
+    allocator: &'a Allocator,
+    source_text: &'a str,
+    source_type: SourceType,
+) -> Result<(Program<'a>, Scoping), OptimizerError> {
+    let ret = Parser::new(allocator, source_text, source_type).parse();
+    if let Some(err) = ret.diagnostics.first() {
+        return Err(syntax_error(source_text, err));
+    }
+
+    let program = ret.program;
+
+    let ret = SemanticBuilder::new().with_excess_capacity(0.1).build(&program);
+    if let Some(err) = ret.diagnostics.first() {
+        return Err(semantic_error(source_text, err));
+    }
+
+    let scoping = ret.semantic.into_scoping();
+    Ok((program, scoping))
+}
+
+fn codegen(
+    program: &Program<'_>,
+    stats: OptimizerStats,
+    file_name: &str,
+    comments: CommentOptions,
+) -> OptimizerOutput {
     let result = Codegen::new()
         .with_options(CodegenOptions {
-            source_map_path: Some(PathBuf::new()),
+            comments,
+            source_map_path: Some(PathBuf::from(file_name)),
             ..Default::default()
         })
-        .build(&program);
+        .build(program);
 
-    Ok(OptimizerOutput {
-        code: result.code,
-        map: result.map.map_or_else(String::default, |v| v.to_json_string()),
-    })
+    let map = result.map.map_or_else(String::default, |mut v| {
+        if !file_name.is_empty() {
+            v.set_file(file_name);
+        }
+        v.to_json_string()
+    });
+    OptimizerOutput { code: result.code, map, stats, warnings: Vec::new() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn options_from_json() {
+        let options = OptimizerOptions::from_json(
+            br#"{
+                "hoist": true,
+                "dedupe": true,
+                "globals": { "include": ["js", "web"], "hoist": true, "scope": "nearest" },
+                "stringifyLargeLiterals": true,
+                "url": "https://example.com/assets/",
+                "preserveComments": "legal"
+            }"#,
+        )
+        .unwrap();
+        assert!(options.hoist);
+        assert!(options.dedupe);
+        assert!(!options.pure);
+        assert_eq!(options.globals.include, GlobalCategory::JS.union(GlobalCategory::WEB));
+        assert!(options.globals.hoist);
+        assert_eq!(options.globals.scope, GlobalsScope::Nearest);
+        assert!(options.stringify_large_literals);
+        assert_eq!(options.stringify_threshold, DEFAULT_STRINGIFY_THRESHOLD);
+        assert_eq!(options.url.as_deref(), Some("https://example.com/assets/"));
+        assert_eq!(options.preserve_comments, PreserveComments::Legal);
+    }
+
+    #[test]
+    fn options_from_json_defaults() {
+        let options = OptimizerOptions::from_json(b"{}").unwrap();
+        assert_eq!(options.stringify_threshold, DEFAULT_STRINGIFY_THRESHOLD);
+        assert_eq!(options.preserve_comments, PreserveComments::All);
+        assert_eq!(options.globals.include, GlobalCategory::default());
+        assert!(options.url.is_none());
+    }
+
+    #[test]
+    fn options_from_json_error() {
+        let err = OptimizerOptions::from_json(br#"{ "hoist": "yes" }"#).unwrap_err();
+        assert!(matches!(err, OptimizerError::OptionsParseError(_)));
+        let err = OptimizerOptions::from_json(br#"{ "globals": { "include": ["js"] "#).unwrap_err();
+        assert!(matches!(err, OptimizerError::OptionsParseError(_)));
+    }
+
+    #[test]
+    fn optimize_chunk_invalid_input_map() {
+        let reported = std::cell::RefCell::new(Vec::new());
+        let on_warning = |message: &str| reported.borrow_mut().push(message.to_string());
+        let output = optimize_chunk(
+            "export const a = 1;\n",
+            &OptimizerOptions::default(),
+            &PropertyMap::new(None),
+            &SourceOptions {
+                input_map: Some("{"),
+                on_warning: Some(&on_warning),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(output.code, "export const a = 1;\n");
+        assert!(!output.map.is_empty());
+        assert_eq!(output.warnings.len(), 1);
+        assert!(output.warnings[0].starts_with("input source map is ignored, invalid source map"));
+        assert_eq!(*reported.borrow(), output.warnings);
+    }
 }
@@ -1,47 +1,414 @@
-use std::sync::Arc;
+use std::{borrow::Cow, collections::BTreeSet, sync::Arc};
 
-use oxc_ast::ast::Expression;
+use oxc_allocator::{Address, FromIn, Vec as ArenaVec};
+use oxc_ast::{AstBuilder, ast::*};
+use oxc_ecmascript::{ToJsString, WithoutGlobalReferenceInformation};
 use oxc_semantic::SymbolId;
+use oxc_span::{SPAN, Span};
+use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
 use rustc_hash::FxHashMap;
 
 use crate::{
     context::TraverseCtx,
-    externs::{ExternMap, ExternModule, ExternValue},
+    externs::{ExternConst, ExternMap, ExternModule, ExternValue, INTRINSICS_MODULE_NAME},
 };
 
 pub struct Externs<'ctx> {
     map: &'ctx ExternMap,
-    symbols: FxHashMap<SymbolId, ExternValue>,
+    symbols: FxHashMap<SymbolId, ExternSymbol>,
+    /// Non-import bindings that are resolved as externs in a subtree.
+    scoped: Vec<ScopedExtern>,
+    used: BTreeSet<(String, String)>,
+}
+
+struct ScopedExtern {
+    /// Address of the call expression that provides an extern binding.
+    address: Address,
+    symbol_id: SymbolId,
+    value: ExternValue,
+}
+
+struct ExternSymbol {
+    value: ExternValue,
+    module: String,
+    /// Export name, `None` for namespace imports.
+    export: Option<String>,
 }
 
 impl<'ctx> Externs<'ctx> {
     pub fn new(map: &'ctx ExternMap) -> Self {
-        Self { map, symbols: FxHashMap::default() }
+        Self { map, symbols: FxHashMap::default(), scoped: Vec::new(), used: BTreeSet::default() }
+    }
+
+    pub fn resolve<'a>(
+        &mut self,
+        node: &Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<&ExternValue> {
+        resolve(&self.scoped, &self.symbols, &mut self.used, node, ctx)
+    }
+
+    /// Resolves a const value, member expressions on const objects and arrays
+    /// are resolved into nested values, e.g. `CONFIG.items[0]`.
+    pub fn resolve_const<'a>(
+        &mut self,
+        node: &Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<(&ExternConst, &serde_json::Value)> {
+        resolve_const(&self.scoped, &self.symbols, &mut self.used, node, ctx)
+    }
+
+    pub fn insert(
+        &mut self,
+        symbol_id: SymbolId,
+        value: ExternValue,
+        module: &str,
+        export: Option<&str>,
+    ) {
+        self.symbols.insert(
+            symbol_id,
+            ExternSymbol {
+                value,
+                module: module.to_string(),
+                // Intrinsic functions aren't declared in extern files.
+                export: if module == INTRINSICS_MODULE_NAME {
+                    None
+                } else {
+                    export.map(str::to_string)
+                },
+            },
+        );
+    }
+
+    /// Resolves `symbol_id` as `value` until `pop_scoped(address)`.
+    pub fn push_scoped(&mut self, address: Address, symbol_id: SymbolId, value: ExternValue) {
+        self.scoped.push(ScopedExtern { address, symbol_id, value });
+    }
+
+    pub fn pop_scoped(&mut self, address: Address) {
+        while self.scoped.last().is_some_and(|s| s.address == address) {
+            self.scoped.pop();
+        }
+    }
+
+    pub fn modules(&self) -> &FxHashMap<String, Arc<ExternModule>> {
+        &self.map.modules
+    }
+
+    /// Extern exports that were resolved as `(module, export)` pairs.
+    pub fn into_used(self) -> Vec<(String, String)> {
+        self.used.into_iter().collect()
     }
+}
 
-    pub fn resolve<'a>(&self, node: &Expression<'a>, ctx: &TraverseCtx<'a>) -> Option<ExternValue> {
-        match node {
-            Expression::Identifier(id) => {
-                if let Some(symbold_id) = ctx.scoping().get_reference(id.reference_id()).symbol_id()
+// Borrows `scoped` and `symbols` separately from `used`, so that resolved
+// values can be returned by reference.
+fn resolve<'s, 'a>(
+    scoped: &'s [ScopedExtern],
+    symbols: &'s FxHashMap<SymbolId, ExternSymbol>,
+    used: &mut BTreeSet<(String, String)>,
+    node: &Expression<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> Option<&'s ExternValue> {
+    match node {
+        Expression::Identifier(id) => {
+            if let Some(symbold_id) = ctx.scoping().get_reference(id.reference_id()).symbol_id() {
+                if let Some(s) = scoped.iter().rev().find(|s| s.symbol_id == symbold_id) {
+                    return Some(&s.value);
+                }
+                let s = symbols.get(&symbold_id)?;
+                if let Some(export) = &s.export {
+                    used.insert((s.module.clone(), export.clone()));
+                }
+                return Some(&s.value);
+            }
+        }
+        // `ns.a.b` resolves nested namespaces recursively
+        Expression::StaticMemberExpression(expr) => {
+            if let Some(ExternValue::Namespace(m)) =
+                resolve(scoped, symbols, used, &expr.object, ctx)
+            {
+                let name = expr.property.name.as_str();
+                let value = m.exports.get(name);
+                // `import * as ns` => `ns.name`
+                if value.is_some()
+                    && let Expression::Identifier(id) = &expr.object
+                    && let Some(symbol_id) =
+                        ctx.scoping().get_reference(id.reference_id()).symbol_id()
+                    && let Some(s) = symbols.get(&symbol_id)
+                    && s.export.is_none()
                 {
-                    return self.symbols.get(&symbold_id).cloned();
+                    used.insert((s.module.clone(), name.to_string()));
                 }
+                return value;
             }
-            Expression::StaticMemberExpression(expr) => {
-                if let Some(ExternValue::Namespace(m)) = self.resolve(&expr.object, ctx) {
-                    return m.exports.get(expr.property.name.as_str()).cloned();
+        }
+        _ => {}
+    }
+    None
+}
+
+// `CONFIG.nested.flag` and `CONFIG.items[0]` are resolved only with literal
+// keys, dynamic keys can't be resolved.
+fn resolve_const<'s, 'a>(
+    scoped: &'s [ScopedExtern],
+    symbols: &'s FxHashMap<SymbolId, ExternSymbol>,
+    used: &mut BTreeSet<(String, String)>,
+    node: &Expression<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> Option<(&'s ExternConst, &'s serde_json::Value)> {
+    if let Some(value) = resolve(scoped, symbols, used, node, ctx) {
+        return match value {
+            ExternValue::Const(c) => Some((c, &c.value)),
+            _ => None,
+        };
+    }
+    match node {
+        Expression::StaticMemberExpression(expr) => {
+            let (c, value) = resolve_const(scoped, symbols, used, &expr.object, ctx)?;
+            Some((c, value.as_object()?.get(expr.property.name.as_str())?))
+        }
+        Expression::ComputedMemberExpression(expr) => {
+            let (c, value) = resolve_const(scoped, symbols, used, &expr.object, ctx)?;
+            let value = match (&expr.expression, value) {
+                (Expression::StringLiteral(key), serde_json::Value::Object(v)) => {
+                    v.get(key.value.as_str())
                 }
+                (Expression::NumericLiteral(index), serde_json::Value::Array(v))
+                    if index.value >= 0.0 && index.value.fract() == 0.0 =>
+                {
+                    v.get(index.value as usize)
+                }
+                _ => None,
+            }?;
+            Some((c, value))
+        }
+        _ => None,
+    }
+}
+
+/// Returns the identifier at the root of a member expression chain.
+pub fn root_identifier<'b, 'a>(node: &'b Expression<'a>) -> Option<&'b IdentifierReference<'a>> {
+    match node {
+        Expression::Identifier(id) => Some(id),
+        Expression::StaticMemberExpression(expr) => root_identifier(&expr.object),
+        Expression::ComputedMemberExpression(expr) => root_identifier(&expr.object),
+        _ => None,
+    }
+}
+
+/// Size of a const value in bytes, strings are measured without quotes.
+pub fn const_size(value: &serde_json::Value) -> usize {
+    match value {
+        serde_json::Value::String(v) => v.len(),
+        v => v.to_string().len(),
+    }
+}
+
+/// Converts a primitive extern const value into an expression.
+///
+/// Objects and arrays aren't inlined because it would break referential
+/// equality.
+pub fn json_into_expr<'a>(
+    value: &serde_json::Value,
+    ast: &AstBuilder<'a>,
+) -> Option<Expression<'a>> {
+    match value {
+        serde_json::Value::Null => Some(Expression::NullLiteral(NullLiteral::boxed(SPAN, ast))),
+        serde_json::Value::Bool(v) => {
+            Some(Expression::BooleanLiteral(BooleanLiteral::boxed(SPAN, *v, ast)))
+        }
+        serde_json::Value::Number(v) => Some(number_expr(v.as_f64()?, SPAN, ast)),
+        serde_json::Value::String(v) => Some(Expression::StringLiteral(StringLiteral::boxed(
+            SPAN,
+            Str::from_in(v.as_str(), ast.allocator),
+            None,
+            ast,
+        ))),
+        serde_json::Value::Array(_) | serde_json::Value::Object(_) => None,
+    }
+}
+
+// `1` or `-1`
+fn number_expr<'a>(v: f64, span: Span, ast: &AstBuilder<'a>) -> Expression<'a> {
+    if v.is_sign_negative() {
+        Expression::UnaryExpression(UnaryExpression::boxed(
+            span,
+            UnaryOperator::UnaryNegation,
+            Expression::NumericLiteral(NumericLiteral::boxed(
+                SPAN,
+                -v,
+                None,
+                NumberBase::Decimal,
+                ast,
+            )),
+            ast,
+        ))
+    } else {
+        Expression::NumericLiteral(NumericLiteral::boxed(span, v, None, NumberBase::Decimal, ast))
+    }
+}
+
+/// Folds binary expressions with numeric and string literal operands.
+///
+/// `100 * 2` => `200`, `"a" + 1` => `"a1"`
+///
+/// Arithmetic expressions are folded only when the result is exact, so that
+/// the operands can be recovered from it, e.g. `0.1 + 0.2` isn't folded.
+pub fn fold_binary_expression<'a>(
+    node: &BinaryExpression<'a>,
+    ast: &AstBuilder<'a>,
+) -> Option<Expression<'a>> {
+    match (literal_operand(&node.left)?, literal_operand(&node.right)?) {
+        (Operand::Number(a), Operand::Number(b)) => {
+            let r = match node.operator {
+                BinaryOperator::Addition => {
+                    let r = a + b;
+                    (r - b == a && r - a == b).then_some(r)
+                }
+                BinaryOperator::Subtraction => {
+                    let r = a - b;
+                    (r + b == a && a - r == b).then_some(r)
+                }
+                BinaryOperator::Multiplication => {
+                    let r = a * b;
+                    (r / b == a && r / a == b).then_some(r)
+                }
+                BinaryOperator::Division => {
+                    let r = a / b;
+                    (r * b == a && a / r == b).then_some(r)
+                }
+                BinaryOperator::Remainder => (a.fract() == 0.0 && b.fract() == 0.0).then(|| a % b),
+                _ => None,
+            }?;
+            // `-0`, `NaN` and `Infinity` can't be represented with numeric literals.
+            if !r.is_finite() || (r == 0.0 && r.is_sign_negative()) {
+                return None;
             }
-            _ => {}
+            Some(number_expr(r, node.span, ast))
         }
-        None
+        _ if node.operator == BinaryOperator::Addition => {
+            let mut s = literal_to_js_string(&node.left)?.into_owned();
+            s.push_str(&literal_to_js_string(&node.right)?);
+            Some(Expression::StringLiteral(StringLiteral::boxed(
+                node.span,
+                Str::from_in(s.as_str(), ast.allocator),
+                None,
+                ast,
+            )))
+        }
+        _ => None,
     }
+}
 
-    pub fn insert(&mut self, symbol_id: SymbolId, value: ExternValue) {
-        self.symbols.insert(symbol_id, value);
+enum Operand {
+    Number(f64),
+    String,
+}
+
+fn literal_operand(node: &Expression<'_>) -> Option<Operand> {
+    match node {
+        Expression::StringLiteral(lit) if !lit.lone_surrogates => Some(Operand::String),
+        Expression::NumericLiteral(lit) => Some(Operand::Number(lit.value)),
+        // `-1`
+        Expression::UnaryExpression(expr) if expr.operator == UnaryOperator::UnaryNegation => {
+            match &expr.argument {
+                Expression::NumericLiteral(lit) => Some(Operand::Number(-lit.value)),
+                _ => None,
+            }
+        }
+        _ => None,
     }
+}
 
-    pub fn modules(&self) -> &FxHashMap<String, Arc<ExternModule>> {
-        &self.map.modules
+/// Folds primitive literal interpolations into the surrounding quasis.
+///
+/// `` `a${"b"}c${d}` `` => `` `abc${d}` ``
+///
+/// Returns `true` when all interpolations were folded and template literal
+/// can be replaced with a string literal.
+pub fn fold_template_literal<'a>(node: &mut TemplateLiteral<'a>, ast: &AstBuilder<'a>) -> bool {
+    if node.expressions.is_empty()
+        || node.quasis.iter().any(|q| q.lone_surrogates || q.value.cooked.is_none())
+        || !node.expressions.iter().any(|e| literal_to_js_string(e).is_some())
+    {
+        return node.expressions.is_empty();
+    }
+
+    let mut quasis = ArenaVec::new_in(ast);
+    let mut expressions = ArenaVec::new_in(ast);
+    let mut old_quasis = node.quasis.drain(..);
+    // Template literals always have `expressions.len() + 1` quasis.
+    let first = old_quasis.next().unwrap();
+    let mut raw = first.value.raw.as_str().to_string();
+    let mut cooked = first.value.cooked.unwrap().as_str().to_string();
+    for (expr, quasi) in node.expressions.drain(..).zip(old_quasis) {
+        if let Some(s) = literal_to_js_string(&expr) {
+            // `${"{a}"}` after `$` shouldn't turn into an interpolation.
+            if raw.ends_with('$') && s.starts_with('{') {
+                raw.push('\\');
+            }
+            escape_template_raw(&s, &mut raw);
+            cooked.push_str(&s);
+        } else {
+            quasis.push(template_element(&raw, &cooked, false, ast));
+            expressions.push(expr);
+            raw.clear();
+            cooked.clear();
+        }
+        raw.push_str(quasi.value.raw.as_str());
+        cooked.push_str(quasi.value.cooked.unwrap().as_str());
     }
+    quasis.push(template_element(&raw, &cooked, true, ast));
+
+    node.quasis = quasis;
+    node.expressions = expressions;
+    node.expressions.is_empty()
+}
+
+fn literal_to_js_string<'a>(node: &Expression<'a>) -> Option<Cow<'a, str>> {
+    match node {
+        Expression::StringLiteral(lit) if lit.lone_surrogates => None,
+        Expression::StringLiteral(_)
+        | Expression::NumericLiteral(_)
+        | Expression::BooleanLiteral(_)
+        | Expression::NullLiteral(_) => node.to_js_string(&WithoutGlobalReferenceInformation),
+        // `-1`
+        Expression::UnaryExpression(expr)
+            if expr.operator == UnaryOperator::UnaryNegation
+                && matches!(expr.argument, Expression::NumericLiteral(_)) =>
+        {
+            node.to_js_string(&WithoutGlobalReferenceInformation)
+        }
+        _ => None,
+    }
+}
+
+fn escape_template_raw(s: &str, out: &mut String) {
+    for c in s.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '`' => out.push_str("\\`"),
+            '$' => out.push_str("\\$"),
+            '\r' => out.push_str("\\r"),
+            _ => out.push(c),
+        }
+    }
+}
+
+fn template_element<'a>(
+    raw: &str,
+    cooked: &str,
+    tail: bool,
+    ast: &AstBuilder<'a>,
+) -> TemplateElement<'a> {
+    TemplateElement::new(
+        SPAN,
+        TemplateElementValue {
+            raw: Str::from_in(raw, ast.allocator),
+            cooked: Some(Str::from_in(cooked, ast.allocator)),
+        },
+        tail,
+        ast,
+    )
 }
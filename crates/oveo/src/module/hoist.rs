@@ -38,16 +38,29 @@
 //!   - `ObjectExpression`
 //!   - `ArrayExpression`
 //!   - `TaggedTemplateExpression`
-//! - No conditionals on the path to the Hoist Scope.
+//! - No conditionals on the path to the Hoist Scope, constant literals are
+//!   still hoisted.
 //!   - `ConditionalExpression`
 //!   - `IfStatement`
 //!   - `SwitchStatement`
+//!   - `TryStatement`
 //! - Expressions hoisted to the Inner Scope should be inside of a function
 //!   scope.
 //!
+//! With `GlobalsOptions::auto_hoist_literals`, constant object and array
+//! literals inside of functions are hoisted as if they were annotated with
+//! `hoist()`.
+//!
+//! Hoist Scopes can have labels `scope(() => {}, "name")`, and expressions
+//! with the same label `hoist(expr, "name")` are hoisted to the closest
+//! labeled scope instead of the outermost one.
+//!
 
 use oxc_allocator::Address;
+use oxc_ast::ast::*;
 use oxc_semantic::{ScopeId, Scoping};
+use oxc_span::Span;
+use oxc_syntax::operator::UnaryOperator;
 
 #[derive(Debug)]
 pub struct HoistStackEntry {
@@ -63,21 +76,159 @@ pub enum HoistStackEntryKind {
     Conditional,
 }
 
+impl HoistStackEntry {
+    fn kind_name(&self) -> String {
+        match &self.kind {
+            HoistStackEntryKind::Scope(HoistScope { label: Some(label), .. }) => {
+                format!("Scope({label})")
+            }
+            HoistStackEntryKind::Scope(_) => "Scope".to_string(),
+            HoistStackEntryKind::FunctionBody => "FunctionBody".to_string(),
+            HoistStackEntryKind::HoistExpr => "HoistExpr".to_string(),
+            HoistStackEntryKind::Conditional => "Conditional".to_string(),
+        }
+    }
+}
+
+/// Debug log of the hoist pass, enabled with `OptimizerOptions::debug_hoist`.
+///
+/// ```text
+/// push Scope scope=0 0..120
+///   push FunctionBody scope=1 20..118
+///     push HoistExpr scope=2 40..60
+///     reduce symbol_scope=1 outermost=1 hoist_scope=none
+///     pop HoistExpr
+///   hoist 40..60 hoist_scope=none
+/// ```
+#[derive(Default)]
+pub struct HoistLog {
+    enabled: bool,
+    lines: Vec<String>,
+}
+
+impl HoistLog {
+    pub fn new(enabled: bool) -> Self {
+        Self { enabled, lines: Vec::new() }
+    }
+
+    pub fn push(&mut self, entry: &HoistStackEntry, span: Span, depth: usize) {
+        if self.enabled {
+            self.add(
+                depth,
+                format!(
+                    "push {} scope={} {}..{}",
+                    entry.kind_name(),
+                    entry.scope_id.index(),
+                    span.start,
+                    span.end
+                ),
+            );
+        }
+    }
+
+    pub fn pop(&mut self, entry: &HoistStackEntry, depth: usize) {
+        if self.enabled {
+            self.add(depth, format!("pop {}", entry.kind_name()));
+        }
+    }
+
+    fn reduce(&mut self, expr: &HoistExpr, sym_scope_id: ScopeId, depth: usize) {
+        if self.enabled {
+            self.add(
+                depth,
+                format!(
+                    "reduce symbol_scope={} outermost={} hoist_scope={}",
+                    sym_scope_id.index(),
+                    expr.outermost_scope_id.index(),
+                    scope_name(expr.hoist_scope_id),
+                ),
+            );
+        }
+    }
+
+    /// Final Hoist Scope of a hoisted expression.
+    pub fn hoist(&mut self, span: Span, hoist_scope_id: Option<ScopeId>, depth: usize) {
+        if self.enabled {
+            self.add(
+                depth,
+                format!(
+                    "hoist {}..{} hoist_scope={}",
+                    span.start,
+                    span.end,
+                    scope_name(hoist_scope_id)
+                ),
+            );
+        }
+    }
+
+    pub fn into_lines(self) -> Vec<String> {
+        self.lines
+    }
+
+    fn add(&mut self, depth: usize, line: String) {
+        self.lines.push(format!("{:indent$}{line}", "", indent = depth * 2));
+    }
+}
+
+fn scope_name(scope_id: Option<ScopeId>) -> String {
+    scope_id.map_or_else(|| "none".to_string(), |id| id.index().to_string())
+}
+
 pub struct HoistArgument {
     pub address: Address,
     pub hoist: bool,
     pub scope: bool,
+    /// Scope label from the `label` argument, e.g. `scope(() => {}, "name")`.
+    pub label: Option<String>,
 }
 
 #[derive(Debug)]
 pub struct HoistScope {
     pub current_statement: Option<Address>,
+    pub label: Option<String>,
 }
 
 pub struct HoistExpr {
     pub address: Address,
     pub outermost_scope_id: ScopeId,
     pub hoist_scope_id: Option<ScopeId>,
+    /// Expression should be hoisted to the closest Hoist Scope with this label.
+    pub label: Option<String>,
+}
+
+/// Resolves Hoist Scope for an expression with a scope label.
+///
+/// Expression is hoisted to the closest Hoist Scope with the same label when
+/// all symbols are accessible from this scope, otherwise it isn't hoisted.
+pub fn resolve_labeled_hoist_scope(
+    expr: &HoistExpr,
+    label: &str,
+    hoist_scopes: &[HoistStackEntry],
+) -> Option<ScopeId> {
+    let hoist_scope_id = expr.hoist_scope_id?;
+    let target = hoist_scopes.iter().rev().find_map(|entry| match &entry.kind {
+        HoistStackEntryKind::Scope(s) if s.label.as_deref() == Some(label) => Some(entry.scope_id),
+        _ => None,
+    })?;
+    // Both scopes are ancestors of the expression, outer scopes have smaller
+    // ids.
+    (hoist_scope_id <= target).then_some(target)
+}
+
+/// Checks if there are conditionals on the path from the expression to its
+/// Hoist Scope, e.g. `try { hoist(f()) } catch {}`.
+///
+/// Expressions without symbols from the inner scopes don't reduce the
+/// hoistable scope, so conditionals should be checked separately.
+pub fn has_conditional_path(hoist_scope_id: ScopeId, hoist_scopes: &[HoistStackEntry]) -> bool {
+    hoist_scopes
+        .iter()
+        .rev()
+        .take_while(|entry| {
+            !(matches!(entry.kind, HoistStackEntryKind::Scope(_))
+                && entry.scope_id == hoist_scope_id)
+        })
+        .any(|entry| matches!(entry.kind, HoistStackEntryKind::Conditional))
 }
 
 #[derive(Debug)]
@@ -93,12 +244,14 @@ pub fn reduce_hoistable_scope(
     current_scope_id: ScopeId,
     sym_scope_id: ScopeId,
     hoist_scopes: &[HoistStackEntry],
+    log: &mut HoistLog,
 ) {
     let outermost_scope_id = expr.outermost_scope_id;
     // Ignore symbols outside of the outermost scope id
     if sym_scope_id < outermost_scope_id {
         return;
     }
+    let depth = hoist_scopes.len();
     let mut hoist_scopes = hoist_scopes.iter().rev().peekable();
 
     let mut state = State::HoistedExpr;
@@ -197,9 +350,62 @@ pub fn reduce_hoistable_scope(
                 } else {
                     expr.hoist_scope_id = current_hoist_scope_id;
                 }
+                log.reduce(expr, sym_scope_id, depth);
                 return;
             }
             break 'hoist_scopes;
         }
     }
 }
+
+/// Checks if expression is a non-empty object or array literal that contains
+/// only primitive literals and nested constant literals.
+///
+/// Constant literals don't have any free variables or side effects, so they
+/// can be hoisted without an explicit `hoist()` annotation. Empty literals
+/// `{}` and `[]` are usually mutated, so they aren't considered constant.
+pub fn is_constant_literal(expr: &Expression<'_>) -> bool {
+    match expr {
+        Expression::ObjectExpression(obj) => {
+            !obj.properties.is_empty()
+                && obj.properties.iter().all(|prop| match prop {
+                    ObjectPropertyKind::ObjectProperty(p) => {
+                        p.kind == PropertyKind::Init
+                            && !p.method
+                            && !p.computed
+                            && is_constant_value(&p.value)
+                    }
+                    ObjectPropertyKind::SpreadProperty(_) => false,
+                })
+        }
+        Expression::ArrayExpression(arr) => {
+            !arr.elements.is_empty()
+                && arr.elements.iter().all(|e| match e {
+                    ArrayExpressionElement::Elision(_) => true,
+                    ArrayExpressionElement::SpreadElement(_) => false,
+                    _ => is_constant_value(e.to_expression()),
+                })
+        }
+        _ => false,
+    }
+}
+
+fn is_constant_value(expr: &Expression<'_>) -> bool {
+    match expr {
+        Expression::StringLiteral(_)
+        | Expression::NumericLiteral(_)
+        | Expression::BigIntLiteral(_)
+        | Expression::BooleanLiteral(_)
+        | Expression::NullLiteral(_) => true,
+        Expression::TemplateLiteral(tpl) => tpl.expressions.is_empty(),
+        // `-1`
+        Expression::UnaryExpression(expr) => {
+            matches!(expr.operator, UnaryOperator::UnaryNegation | UnaryOperator::UnaryPlus)
+                && matches!(expr.argument, Expression::NumericLiteral(_))
+        }
+        Expression::ObjectExpression(_) | Expression::ArrayExpression(_) => {
+            is_constant_literal(expr)
+        }
+        _ => false,
+    }
+}
@@ -2,38 +2,55 @@ use std::sync::Arc;
 
 use oxc_allocator::{Address, Allocator, GetAddress, TakeIn, Vec as ArenaVec};
 use oxc_ast::{AstBuilder, NONE, ast::*};
-use oxc_semantic::{Scoping, SymbolFlags};
-use oxc_span::SPAN;
-use oxc_traverse::{Traverse, traverse_mut};
-use rustc_hash::FxHashSet;
+use oxc_semantic::{Scoping, SymbolFlags, SymbolId};
+use oxc_span::{GetSpan, SPAN, Span};
+use oxc_traverse::{Ancestor, Traverse, traverse_mut};
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
-    OptimizerOptions,
+    OptimizerOptions, OptimizerStats,
     annotation::Annotation,
     context::{TraverseCtx, TraverseCtxState},
+    debug_comments::DebugComments,
     externs::{ExternMap, ExternValue, INTRINSICS_MODULE_NAME, IntrinsicFunction},
     module::{
-        externs::Externs,
+        externs::{
+            Externs, const_size, fold_binary_expression, fold_template_literal, json_into_expr,
+            root_identifier,
+        },
         hoist::{
-            HoistArgument, HoistExpr, HoistScope, HoistStackEntry, HoistStackEntryKind,
-            reduce_hoistable_scope,
+            HoistArgument, HoistExpr, HoistLog, HoistScope, HoistStackEntry, HoistStackEntryKind,
+            has_conditional_path, is_constant_literal, reduce_hoistable_scope,
+            resolve_labeled_hoist_scope,
         },
     },
     statements::Statements,
+    warnings::Warnings,
 };
 
 mod externs;
 mod hoist;
 
-pub fn optimize_module<'a>(
+/// Returns extern exports that were used by the module, the hoist log and
+/// warnings.
+pub fn optimize_module<'a, 'ctx>(
     program: &mut Program<'a>,
-    options: &OptimizerOptions,
-    externs: &ExternMap,
+    options: &'ctx OptimizerOptions,
+    externs: &'ctx ExternMap,
     allocator: &'a Allocator,
     scoping: Scoping,
-) {
-    let mut optimizer = ModuleOptimizer::new(options, externs);
+    debug_comments: &'ctx mut DebugComments,
+    warnings: Warnings<'ctx>,
+) -> (OptimizerStats, Vec<String>) {
+    let mut optimizer =
+        ModuleOptimizer::new(options, externs, program.source_text, debug_comments, warnings);
     traverse_mut(&mut optimizer, allocator, program, scoping, TraverseCtxState::default());
+    let stats = OptimizerStats {
+        used_externs: optimizer.externs.into_used(),
+        hoist_log: optimizer.hoist_log.into_lines(),
+        ..Default::default()
+    };
+    (stats, optimizer.warnings.into_messages())
 }
 
 struct ModuleOptimizer<'a, 'ctx> {
@@ -42,41 +59,189 @@ struct ModuleOptimizer<'a, 'ctx> {
     externs: Externs<'ctx>,
 
     hoist_arguments: Vec<HoistArgument>,
-    hoist_scope_expressions: FxHashSet<Address>,
+    /// Arrow functions that create Hoist Scopes with optional labels.
+    hoist_scope_expressions: FxHashMap<Address, Option<String>>,
 
     hoist_stack: Vec<HoistStackEntry>,
     hoistable_expr_stack: Vec<HoistExpr>,
+    /// Number of enclosing async and generator functions.
+    async_generator_depth: u32,
+    /// Inside of a constant literal that is hoisted automatically.
+    in_constant_literal: bool,
+    hoist_log: HoistLog,
+    /// Imported symbols that had at least one inlined const reference.
+    inlined_symbols: FxHashSet<SymbolId>,
+    /// Extern modules are resolved from `require()` calls in CommonJS and
+    /// script sources.
+    require_externs: bool,
+    source_text: &'a str,
+    /// Non-fatal issues that are forwarded to the warning sink.
+    warnings: Warnings<'ctx>,
+    debug_comments: &'ctx mut DebugComments,
 }
 
-impl<'ctx> ModuleOptimizer<'_, 'ctx> {
-    pub fn new(options: &'ctx OptimizerOptions, extern_map: &'ctx ExternMap) -> Self {
+impl<'a, 'ctx> ModuleOptimizer<'a, 'ctx> {
+    pub fn new(
+        options: &'ctx OptimizerOptions,
+        extern_map: &'ctx ExternMap,
+        source_text: &'a str,
+        debug_comments: &'ctx mut DebugComments,
+        warnings: Warnings<'ctx>,
+    ) -> Self {
         Self {
             options,
             statements: Statements::new(),
             externs: Externs::new(extern_map),
             hoist_arguments: Vec::new(),
-            hoist_scope_expressions: FxHashSet::default(),
+            hoist_scope_expressions: FxHashMap::default(),
             hoist_stack: Vec::new(),
             hoistable_expr_stack: Vec::new(),
+            async_generator_depth: 0,
+            in_constant_literal: false,
+            hoist_log: HoistLog::new(options.debug_hoist),
+            inlined_symbols: FxHashSet::default(),
+            require_externs: false,
+            source_text,
+            warnings,
+            debug_comments,
         }
     }
+
+    fn push_hoist_entry(&mut self, entry: HoistStackEntry, span: Span) {
+        self.hoist_log.push(&entry, span, self.hoist_stack.len());
+        self.hoist_stack.push(entry);
+    }
+
+    fn pop_hoist_entry(&mut self) {
+        if let Some(entry) = self.hoist_stack.pop() {
+            self.hoist_log.pop(&entry, self.hoist_stack.len());
+        }
+    }
+
+    /// Adds a warning with a location of the span, e.g. `unable to hoist
+    /// expression at 3:10`.
+    fn warn(&mut self, message: &str, span: Span) {
+        self.warnings.warn(self.source_text, message, span);
+    }
+
+    /// Replaces hoistable expression with a reference to a `const` declaration
+    /// in its Hoist Scope, returns `false` when expression doesn't have a
+    /// Hoist Scope.
+    fn hoist_expression(
+        &mut self,
+        expr: &mut Expression<'a>,
+        s: HoistExpr,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> bool {
+        // Outer hoistable expr scope should be reduced to the outermost
+        // scope of the inner hoistable expr.
+        if let Some(last) = self.hoistable_expr_stack.last_mut() {
+            reduce_hoistable_scope(
+                last,
+                ctx.scoping(),
+                ctx.current_scope_id(),
+                s.outermost_scope_id,
+                &self.hoist_stack,
+                &mut self.hoist_log,
+            );
+        }
+        let span = expr.span();
+        // Constant literals don't have side effects and can be hoisted from
+        // conditional paths.
+        let constant = is_constant_literal(expr);
+        if self.options.dedupe {
+            *expr = annotate(expr.take_in(ctx), Annotation::hoisted_dedupe(), &mut ctx.ast);
+        }
+        let hoist_scope_id = match &s.label {
+            Some(label) => resolve_labeled_hoist_scope(&s, label, &self.hoist_stack),
+            None => s.hoist_scope_id,
+        }
+        .filter(|&id| constant || !has_conditional_path(id, &self.hoist_stack));
+        self.hoist_log.hoist(span, hoist_scope_id, self.hoist_stack.len());
+        let Some(hoist_scope_id) = hoist_scope_id else {
+            return false;
+        };
+
+        let uid = ctx.generate_uid("_HOISTED_", hoist_scope_id, SymbolFlags::ConstVariable);
+        self.debug_comments.add_source(&uid.name, "hoist", span, self.source_text);
+
+        // const _HOISTED_ = expr;
+        let hoisted_var_decl = Declaration::VariableDeclaration(VariableDeclaration::boxed(
+            SPAN,
+            VariableDeclarationKind::Const,
+            ArenaVec::from_value_in(
+                VariableDeclarator::new(
+                    SPAN,
+                    VariableDeclarationKind::Const,
+                    BindingPattern::BindingIdentifier(BindingIdentifier::boxed(
+                        SPAN, uid.name, ctx,
+                    )),
+                    NONE,
+                    Some(expr.take_in(ctx)),
+                    false,
+                    ctx,
+                ),
+                ctx,
+            ),
+            false,
+            ctx,
+        ));
+        *expr = uid.create_read_expression(ctx);
+
+        if let Some(scope) = self.hoist_stack.iter().find(|x| x.scope_id == hoist_scope_id) {
+            if let HoistStackEntryKind::Scope(scope) = &scope.kind {
+                if let Some(address) = scope.current_statement {
+                    self.statements.insert_before(&address, hoisted_var_decl.into());
+                }
+            }
+        }
+        true
+    }
 }
 
 impl<'a> Traverse<'a, TraverseCtxState<'a>> for ModuleOptimizer<'a, '_> {
     fn enter_program(&mut self, node: &mut Program<'a>, _ctx: &mut TraverseCtx<'a>) {
+        self.require_externs = node.source_type.is_commonjs() || node.source_type.is_script();
         // push program hoist scope
         if self.options.hoist {
-            self.hoist_stack.push(HoistStackEntry {
-                scope_id: node.scope_id(),
-                kind: HoistStackEntryKind::Scope(HoistScope { current_statement: None }),
-            });
+            self.push_hoist_entry(
+                HoistStackEntry {
+                    scope_id: node.scope_id(),
+                    kind: HoistStackEntryKind::Scope(HoistScope {
+                        current_statement: None,
+                        label: None,
+                    }),
+                },
+                node.span,
+            );
         }
     }
 
-    fn exit_program(&mut self, _node: &mut Program<'a>, _ctx: &mut TraverseCtx<'a>) {
+    fn exit_program(&mut self, node: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
         // pop program hoist scope
         if self.options.hoist {
-            self.hoist_stack.pop();
+            self.pop_hoist_entry();
+        }
+
+        // removes import specifiers that aren't used after inlining const values
+        if !self.inlined_symbols.is_empty() {
+            node.body.retain_mut(|s| {
+                let Statement::ImportDeclaration(import_decl) = s else {
+                    return true;
+                };
+                let Some(specifiers) = &mut import_decl.specifiers else {
+                    return true;
+                };
+                if specifiers.is_empty() {
+                    return true;
+                }
+                specifiers.retain(|spec| {
+                    let symbol_id = spec.local().symbol_id();
+                    !self.inlined_symbols.contains(&symbol_id)
+                        || !ctx.scoping().symbol_is_unused(symbol_id)
+                });
+                !specifiers.is_empty()
+            });
         }
     }
 
@@ -149,11 +314,16 @@ impl<'a> Traverse<'a, TraverseCtxState<'a>> for ModuleOptimizer<'a, '_> {
             }
 
             match node {
-                Statement::IfStatement(_) | Statement::SwitchStatement(_) => {
-                    self.hoist_stack.push(HoistStackEntry {
-                        scope_id: ctx.current_scope_id(),
-                        kind: HoistStackEntryKind::Conditional,
-                    });
+                Statement::IfStatement(_)
+                | Statement::SwitchStatement(_)
+                | Statement::TryStatement(_) => {
+                    self.push_hoist_entry(
+                        HoistStackEntry {
+                            scope_id: ctx.current_scope_id(),
+                            kind: HoistStackEntryKind::Conditional,
+                        },
+                        node.span(),
+                    );
                 }
                 _ => {}
             }
@@ -168,8 +338,10 @@ impl<'a> Traverse<'a, TraverseCtxState<'a>> for ModuleOptimizer<'a, '_> {
                 }
             }
             match node {
-                Statement::IfStatement(_) | Statement::SwitchStatement(_) => {
-                    self.hoist_stack.pop();
+                Statement::IfStatement(_)
+                | Statement::SwitchStatement(_)
+                | Statement::TryStatement(_) => {
+                    self.pop_hoist_entry();
                 }
                 _ => {}
             }
@@ -185,7 +357,63 @@ impl<'a> Traverse<'a, TraverseCtxState<'a>> for ModuleOptimizer<'a, '_> {
 
     fn enter_expression(&mut self, node: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         match node {
+            // Inlines extern const values, `CONFIG.items[0]` is inlined as
+            // a nested value.
+            Expression::Identifier(_)
+            | Expression::StaticMemberExpression(_)
+            | Expression::ComputedMemberExpression(_) => {
+                if self.options.externs.inline_const_values {
+                    if let Some((c, value)) = self.externs.resolve_const(node, ctx)
+                        && c.inline
+                        && self
+                            .options
+                            .externs
+                            .max_inline_size
+                            .is_none_or(|max| const_size(value) <= max)
+                    {
+                        if let Some(expr) = json_into_expr(value, &ctx.ast) {
+                            // `CONFIG.version` => `CONFIG`
+                            if let Some(id) = root_identifier(node) {
+                                let reference_id = id.reference_id();
+                                if let Some(symbol_id) =
+                                    ctx.scoping().get_reference(reference_id).symbol_id()
+                                {
+                                    ctx.scoping_mut()
+                                        .delete_resolved_reference(symbol_id, reference_id);
+                                    self.inlined_symbols.insert(symbol_id);
+                                }
+                            }
+                            *node = expr;
+                        }
+                    }
+                }
+            }
             Expression::CallExpression(call_expr) => {
+                if self.options.externs.inline_const_values {
+                    // Scoped externs `provide(deps, () => deps.value)`
+                    if let Some(ExternValue::Function(f)) =
+                        self.externs.resolve(&call_expr.callee, ctx)
+                    {
+                        let scoped: Vec<_> = f
+                            .arguments
+                            .iter()
+                            .enumerate()
+                            .filter_map(|(i, meta)| {
+                                let m = meta.externs.as_ref()?;
+                                let Some(Argument::Identifier(id)) = call_expr.arguments.get(i)
+                                else {
+                                    return None;
+                                };
+                                let symbol_id =
+                                    ctx.scoping().get_reference(id.reference_id()).symbol_id()?;
+                                Some((symbol_id, ExternValue::Namespace(Arc::clone(m))))
+                            })
+                            .collect();
+                        for (symbol_id, value) in scoped {
+                            self.externs.push_scoped(call_expr.address(), symbol_id, value);
+                        }
+                    }
+                }
                 if self.options.hoist {
                     // Hoist expressions
                     if call_expr.arguments.is_empty() {
@@ -194,6 +422,15 @@ impl<'a> Traverse<'a, TraverseCtxState<'a>> for ModuleOptimizer<'a, '_> {
                     if let Some(ExternValue::Function(f)) =
                         self.externs.resolve(&call_expr.callee, ctx)
                     {
+                        let label =
+                            f.arguments.iter().zip(&call_expr.arguments).find_map(|(meta, arg)| {
+                                match arg {
+                                    Argument::StringLiteral(s) if meta.label => {
+                                        Some(s.value.to_string())
+                                    }
+                                    _ => None,
+                                }
+                            });
                         for (i, meta) in f.arguments.iter().enumerate() {
                             if meta.hoist || meta.scope {
                                 if let Some(arg) = call_expr.arguments.get(i) {
@@ -201,6 +438,7 @@ impl<'a> Traverse<'a, TraverseCtxState<'a>> for ModuleOptimizer<'a, '_> {
                                         address: arg.address(),
                                         hoist: meta.hoist,
                                         scope: meta.scope,
+                                        label: label.clone(),
                                     });
                                 }
                             }
@@ -208,12 +446,43 @@ impl<'a> Traverse<'a, TraverseCtxState<'a>> for ModuleOptimizer<'a, '_> {
                     }
                 }
             }
+            // Hoists constant literals `[1, 2]` without `hoist()` annotations
+            Expression::ObjectExpression(_) | Expression::ArrayExpression(_) => {
+                if self.options.hoist
+                    && self.options.globals.auto_hoist_literals
+                    && !self.in_constant_literal
+                    && self.async_generator_depth == 0
+                    && self.hoistable_expr_stack.last().is_none_or(|s| s.address != node.address())
+                    && is_constant_literal(node)
+                {
+                    let root_scope_id = ctx.scoping().root_scope_id();
+                    if root_scope_id != ctx.current_hoist_scope_id() {
+                        self.in_constant_literal = true;
+                        self.hoistable_expr_stack.push(HoistExpr {
+                            address: node.address(),
+                            outermost_scope_id: root_scope_id,
+                            hoist_scope_id: Some(root_scope_id),
+                            label: None,
+                        });
+                        self.push_hoist_entry(
+                            HoistStackEntry {
+                                scope_id: ctx.current_scope_id(),
+                                kind: HoistStackEntryKind::HoistExpr,
+                            },
+                            node.span(),
+                        );
+                    }
+                }
+            }
             Expression::ConditionalExpression(_) => {
                 if self.options.hoist {
-                    self.hoist_stack.push(HoistStackEntry {
-                        scope_id: ctx.current_scope_id(),
-                        kind: HoistStackEntryKind::Conditional,
-                    });
+                    self.push_hoist_entry(
+                        HoistStackEntry {
+                            scope_id: ctx.current_scope_id(),
+                            kind: HoistStackEntryKind::Conditional,
+                        },
+                        node.span(),
+                    );
                 }
             }
             _ => {}
@@ -224,6 +493,9 @@ impl<'a> Traverse<'a, TraverseCtxState<'a>> for ModuleOptimizer<'a, '_> {
         match node {
             // Intrinsic functions
             Expression::CallExpression(expr) => {
+                let address = expr.address();
+                self.externs.pop_scoped(address);
+
                 if let Some(ExternValue::Function(f)) = self.externs.resolve(&expr.callee, ctx) {
                     if let Some(intrinsic) = &f.intrinsic {
                         match intrinsic {
@@ -256,44 +528,166 @@ impl<'a> Traverse<'a, TraverseCtxState<'a>> for ModuleOptimizer<'a, '_> {
                                     *node = unwrap_call_expr(expr, &mut ctx.ast);
                                 }
                             }
+                            IntrinsicFunction::KeyObject => {
+                                if self.options.rename_properties
+                                    && let Some(arg) = expr.arguments.pop()
+                                {
+                                    *node = annotate(
+                                        arg.into_expression(),
+                                        Annotation::key_object(),
+                                        &mut ctx.ast,
+                                    );
+                                } else {
+                                    *node = unwrap_call_expr(expr, &mut ctx.ast);
+                                }
+                            }
+                            IntrinsicFunction::Pure => {
+                                if self.options.pure
+                                    && let Some(arg) = expr.arguments.pop()
+                                {
+                                    *node = annotate(
+                                        arg.into_expression(),
+                                        Annotation::pure(),
+                                        &mut ctx.ast,
+                                    );
+                                } else {
+                                    *node = unwrap_call_expr(expr, &mut ctx.ast);
+                                }
+                            }
+                            IntrinsicFunction::Inline => {
+                                if self.options.inline
+                                    && let Some(arg) = expr.arguments.pop()
+                                {
+                                    *node = annotate(
+                                        arg.into_expression(),
+                                        Annotation::inline(),
+                                        &mut ctx.ast,
+                                    );
+                                } else {
+                                    *node = unwrap_call_expr(expr, &mut ctx.ast);
+                                }
+                            }
+                            IntrinsicFunction::Freeze => {
+                                let arg = unwrap_call_expr(expr, &mut ctx.ast);
+                                *node = if self.options.dedupe {
+                                    // `Object.freeze()` is added in the chunk pass, so that
+                                    // it can be hoisted and deduplicated.
+                                    annotate(arg, Annotation::freeze(), &mut ctx.ast)
+                                } else {
+                                    create_object_freeze(arg, &mut ctx.ast)
+                                };
+                            }
+                        }
+                        // Intrinsics inside of hoisted expressions `hoist(dedupe(expr))`
+                        // are replaced, so the hoisted argument address should be updated.
+                        if self.options.hoist
+                            && let Some(s) = self.hoistable_expr_stack.last_mut()
+                            && s.address == address
+                        {
+                            s.address = node.address();
                         }
                     }
                 }
             }
+            Expression::ObjectExpression(_) | Expression::ArrayExpression(_) => {
+                if self.in_constant_literal
+                    && self.hoistable_expr_stack.last().is_some_and(|s| s.address == node.address())
+                {
+                    self.in_constant_literal = false;
+                    self.pop_hoist_entry();
+                    let s = self.hoistable_expr_stack.pop().unwrap();
+                    // Literals without a Hoist Scope are left in place.
+                    self.hoist_expression(node, s, ctx);
+                }
+            }
             Expression::ConditionalExpression(_) => {
                 if self.options.hoist {
-                    self.hoist_stack.pop();
+                    self.pop_hoist_entry();
+                }
+            }
+            // Folds inlined const values in arithmetic and string concatenation
+            Expression::BinaryExpression(expr) => {
+                if self.options.externs.inline_const_values
+                    && let Some(folded) = fold_binary_expression(expr, &ctx.ast)
+                {
+                    *node = folded;
+                }
+            }
+            // Folds inlined const values into template literals
+            Expression::TemplateLiteral(tpl) => {
+                if self.options.externs.inline_const_values && fold_template_literal(tpl, &ctx.ast)
+                {
+                    let value = tpl.quasis[0].value.cooked.unwrap_or(tpl.quasis[0].value.raw);
+                    *node =
+                        Expression::StringLiteral(StringLiteral::boxed(tpl.span, value, None, ctx));
                 }
             }
             _ => {}
         }
     }
 
-    fn enter_function_body(&mut self, _node: &mut FunctionBody<'a>, ctx: &mut TraverseCtx<'a>) {
+    fn enter_function_body(&mut self, node: &mut FunctionBody<'a>, ctx: &mut TraverseCtx<'a>) {
         if self.options.hoist {
-            // push hoist scope
             let parent = ctx.parent();
+            if self.options.no_hoist_in_async_generators && is_async_or_generator(&parent) {
+                self.async_generator_depth += 1;
+            }
+
+            // push hoist scope
             if parent.is_arrow_function_expression() {
                 let address = parent.address();
-                if self.hoist_scope_expressions.remove(&address) {
-                    self.hoist_stack.push(HoistStackEntry {
-                        scope_id: ctx.current_scope_id(),
-                        kind: HoistStackEntryKind::Scope(HoistScope { current_statement: None }),
-                    });
+                if let Some(label) = self.hoist_scope_expressions.remove(&address) {
+                    self.push_hoist_entry(
+                        HoistStackEntry {
+                            scope_id: ctx.current_scope_id(),
+                            kind: HoistStackEntryKind::Scope(HoistScope {
+                                current_statement: None,
+                                label,
+                            }),
+                        },
+                        node.span,
+                    );
                     return;
                 }
             }
-            self.hoist_stack.push(HoistStackEntry {
-                scope_id: ctx.current_scope_id(),
-                kind: HoistStackEntryKind::FunctionBody,
-            });
+            self.push_hoist_entry(
+                HoistStackEntry {
+                    scope_id: ctx.current_scope_id(),
+                    kind: HoistStackEntryKind::FunctionBody,
+                },
+                node.span,
+            );
         }
     }
 
-    fn exit_function_body(&mut self, _node: &mut FunctionBody<'a>, _ctx: &mut TraverseCtx<'a>) {
+    fn exit_function_body(&mut self, _node: &mut FunctionBody<'a>, ctx: &mut TraverseCtx<'a>) {
         if self.options.hoist {
+            if self.options.no_hoist_in_async_generators && is_async_or_generator(&ctx.parent()) {
+                self.async_generator_depth -= 1;
+            }
+
             // pop hoist scope
-            self.hoist_stack.pop();
+            self.pop_hoist_entry();
+        }
+    }
+
+    fn exit_arrow_function_expression(
+        &mut self,
+        node: &mut ArrowFunctionExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        // Expression body of a Hoist Scope `scope((a) => () => hoist(f(a)))`
+        // with hoisted declarations is converted into a block body:
+        // `(a) => { const _HOISTED_ = f(a); return () => _HOISTED_; }`
+        if node.expression && node.body.statements.len() > 1 {
+            node.expression = false;
+            if let Some(stmt) = node.body.statements.last_mut()
+                && let Statement::ExpressionStatement(expr_stmt) = stmt
+            {
+                let span = expr_stmt.span;
+                let expr = expr_stmt.expression.take_in(ctx);
+                *stmt = Statement::ReturnStatement(ReturnStatement::boxed(span, Some(expr), ctx));
+            }
         }
     }
 
@@ -306,10 +700,10 @@ impl<'a> Traverse<'a, TraverseCtxState<'a>> for ModuleOptimizer<'a, '_> {
                 if arg.scope {
                     if let Some(Expression::ArrowFunctionExpression(expr)) = node.as_expression() {
                         let addr = expr.address();
-                        self.hoist_scope_expressions.insert(addr);
+                        self.hoist_scope_expressions.insert(addr, arg.label.clone());
                     }
                 }
-                if arg.hoist {
+                if arg.hoist && self.async_generator_depth == 0 {
                     let root_scope_id = ctx.scoping().root_scope_id();
                     let scope_id = ctx.current_hoist_scope_id();
                     if root_scope_id != scope_id {
@@ -327,11 +721,15 @@ impl<'a> Traverse<'a, TraverseCtxState<'a>> for ModuleOptimizer<'a, '_> {
                                     address,
                                     outermost_scope_id: root_scope_id,
                                     hoist_scope_id: Some(root_scope_id),
+                                    label: arg.label.clone(),
                                 });
-                                self.hoist_stack.push(HoistStackEntry {
-                                    scope_id: ctx.current_scope_id(),
-                                    kind: HoistStackEntryKind::HoistExpr,
-                                });
+                                self.push_hoist_entry(
+                                    HoistStackEntry {
+                                        scope_id: ctx.current_scope_id(),
+                                        kind: HoistStackEntryKind::HoistExpr,
+                                    },
+                                    node.span(),
+                                );
                             }
                         }
                     }
@@ -348,64 +746,16 @@ impl<'a> Traverse<'a, TraverseCtxState<'a>> for ModuleOptimizer<'a, '_> {
                 if s.address != address {
                     return;
                 }
-                self.hoist_stack.pop();
+                self.pop_hoist_entry();
                 let s = self.hoistable_expr_stack.pop().unwrap();
 
                 let Some(expr) = node.as_expression_mut() else {
                     return;
                 };
 
-                // Outer hoistable expr scope should be reduced to the outermost
-                // scope of the inner hoistable expr.
-                if let Some(last) = self.hoistable_expr_stack.last_mut() {
-                    reduce_hoistable_scope(
-                        last,
-                        ctx.scoping(),
-                        ctx.current_scope_id(),
-                        s.outermost_scope_id,
-                        &self.hoist_stack,
-                    );
-                }
-                if self.options.dedupe {
-                    *expr = annotate(expr.take_in(ctx), Annotation::dedupe(), &mut ctx.ast);
-                }
-                let Some(hoist_scope_id) = s.hoist_scope_id else {
-                    return;
-                };
-
-                let uid = ctx.generate_uid("_HOISTED_", hoist_scope_id, SymbolFlags::ConstVariable);
-
-                // const _HOISTED_ = expr;
-                let hoisted_var_decl =
-                    Declaration::VariableDeclaration(VariableDeclaration::boxed(
-                        SPAN,
-                        VariableDeclarationKind::Const,
-                        ArenaVec::from_value_in(
-                            VariableDeclarator::new(
-                                SPAN,
-                                VariableDeclarationKind::Const,
-                                BindingPattern::BindingIdentifier(BindingIdentifier::boxed(
-                                    SPAN, uid.name, ctx,
-                                )),
-                                NONE,
-                                Some(expr.take_in(ctx)),
-                                false,
-                                ctx,
-                            ),
-                            ctx,
-                        ),
-                        false,
-                        ctx,
-                    ));
-                *expr = uid.create_read_expression(ctx);
-
-                if let Some(scope) = self.hoist_stack.iter().find(|x| x.scope_id == hoist_scope_id)
-                {
-                    if let HoistStackEntryKind::Scope(scope) = &scope.kind {
-                        if let Some(address) = scope.current_statement {
-                            self.statements.insert_before(&address, hoisted_var_decl.into());
-                        }
-                    }
+                let span = expr.span();
+                if !self.hoist_expression(expr, s, ctx) {
+                    self.warn("unable to hoist expression", span);
                 }
             }
         }
@@ -428,12 +778,84 @@ impl<'a> Traverse<'a, TraverseCtxState<'a>> for ModuleOptimizer<'a, '_> {
                         ctx.current_scope_id(),
                         sym_scope_id,
                         &self.hoist_stack,
+                        &mut self.hoist_log,
                     );
                 }
             }
         }
     }
 
+    // Resolve extern modules from dynamic imports and `require()` calls in
+    // CommonJS modules
+    //
+    // const ns = await import("source")
+    // const { imported, imported: local } = await import("source")
+    // const ns = require("source")
+    fn exit_variable_declarator(
+        &mut self,
+        node: &mut VariableDeclarator<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !node.kind.is_const() {
+            return;
+        }
+        let Some(init) = &node.init else {
+            return;
+        };
+        let source = match init {
+            Expression::AwaitExpression(await_expr) => {
+                let Expression::ImportExpression(import_expr) =
+                    await_expr.argument.without_parentheses()
+                else {
+                    return;
+                };
+                let Expression::StringLiteral(source) = &import_expr.source else {
+                    return;
+                };
+                source
+            }
+            Expression::CallExpression(call_expr) if self.require_externs => {
+                let Some(source) = require_source(call_expr, ctx) else {
+                    return;
+                };
+                source
+            }
+            _ => return,
+        };
+        let Some(module) = self.externs.modules().get(source.value.as_str()).cloned() else {
+            return;
+        };
+
+        match &node.id {
+            BindingPattern::BindingIdentifier(id) => {
+                self.externs.insert(
+                    id.symbol_id(),
+                    ExternValue::Namespace(Arc::clone(&module)),
+                    &source.value,
+                    None,
+                );
+            }
+            BindingPattern::ObjectPattern(pattern) => {
+                for prop in &pattern.properties {
+                    // Computed keys and default values aren't statically analyzable.
+                    if prop.computed {
+                        continue;
+                    }
+                    let BindingPattern::BindingIdentifier(id) = &prop.value else {
+                        continue;
+                    };
+                    let Some(name) = prop.key.static_name() else {
+                        continue;
+                    };
+                    if let Some(v) = module.exports.get(name.as_ref()) {
+                        self.externs.insert(id.symbol_id(), v.clone(), &source.value, Some(&name));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
     fn exit_import_declaration(
         &mut self,
         node: &mut ImportDeclaration<'a>,
@@ -451,14 +873,25 @@ impl<'a> Traverse<'a, TraverseCtxState<'a>> for ModuleOptimizer<'a, '_> {
                     // import { imported } from "source"
                     // import { imported as local } from "source"
                     ImportDeclarationSpecifier::ImportSpecifier(spec) => {
-                        if let Some(v) = module.exports.get(spec.imported.name().as_str()) {
-                            self.externs.insert(spec.local.symbol_id(), v.clone());
+                        let name = spec.imported.name();
+                        if let Some(v) = module.exports.get(name.as_str()) {
+                            self.externs.insert(
+                                spec.local.symbol_id(),
+                                v.clone(),
+                                &source.value,
+                                Some(&name),
+                            );
                         }
                     }
                     // import local from "source"
                     ImportDeclarationSpecifier::ImportDefaultSpecifier(spec) => {
                         if let Some(v) = module.exports.get("default") {
-                            self.externs.insert(spec.local.symbol_id(), v.clone());
+                            self.externs.insert(
+                                spec.local.symbol_id(),
+                                v.clone(),
+                                &source.value,
+                                Some("default"),
+                            );
                         }
                     }
                     // import * as local from "source"
@@ -466,6 +899,8 @@ impl<'a> Traverse<'a, TraverseCtxState<'a>> for ModuleOptimizer<'a, '_> {
                         self.externs.insert(
                             spec.local.symbol_id(),
                             ExternValue::Namespace(Arc::clone(&module)),
+                            &source.value,
+                            None,
                         );
                     }
                 }
@@ -474,6 +909,26 @@ impl<'a> Traverse<'a, TraverseCtxState<'a>> for ModuleOptimizer<'a, '_> {
     }
 }
 
+/// Returns the source of a `require("source")` call, `require` should be
+/// a global reference.
+fn require_source<'b, 'a>(
+    call_expr: &'b CallExpression<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> Option<&'b StringLiteral<'a>> {
+    let Expression::Identifier(callee) = &call_expr.callee else {
+        return None;
+    };
+    if callee.name != "require"
+        || ctx.scoping().get_reference(callee.reference_id()).symbol_id().is_some()
+    {
+        return None;
+    }
+    match call_expr.arguments.as_slice() {
+        [Argument::StringLiteral(source)] => Some(source),
+        _ => None,
+    }
+}
+
 // __oveo__(expr, annotation_flags)
 fn annotate<'a>(
     expr: Expression<'a>,
@@ -503,8 +958,35 @@ fn annotate<'a>(
     ))
 }
 
+fn is_async_or_generator(ancestor: &Ancestor<'_, '_>) -> bool {
+    match ancestor {
+        Ancestor::FunctionBody(f) => *f.r#async() || *f.generator(),
+        Ancestor::ArrowFunctionExpressionBody(f) => *f.r#async(),
+        _ => false,
+    }
+}
+
+// `Object.freeze(expr)`
+fn create_object_freeze<'a>(expr: Expression<'a>, ast: &mut AstBuilder<'a>) -> Expression<'a> {
+    Expression::CallExpression(CallExpression::boxed(
+        SPAN,
+        Expression::StaticMemberExpression(StaticMemberExpression::boxed(
+            SPAN,
+            Expression::Identifier(IdentifierReference::boxed(SPAN, "Object", ast)),
+            IdentifierName::new(SPAN, "freeze", ast),
+            false,
+            ast,
+        )),
+        NONE,
+        ArenaVec::from_value_in(expr.into(), ast),
+        false,
+        ast,
+    ))
+}
+
 fn unwrap_call_expr<'a>(expr: &mut CallExpression<'a>, ast: &mut AstBuilder<'a>) -> Expression<'a> {
-    if let Some(arg) = expr.arguments.pop() {
+    // Additional arguments are scope labels `hoist(expr, "name")`.
+    if let Some(arg) = expr.arguments.drain(..).next() {
         arg.into_expression()
     } else {
         Expression::new_void_0(SPAN, ast)
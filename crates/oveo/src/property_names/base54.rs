@@ -11,7 +11,7 @@ struct Aligned64([u8; 64]);
 /// This list was generated by the following steps:
 /// 1. Generate a source code with replacing all manglable variable names with `$` (assuming `$` is the least used character).
 ///    You can do this by passing the following `blank` function to the `generate_name` parameter of [crate::Mangler::build_with_semantic_impl].
-///    ```no_run
+///    ```ignore
 ///    fn blank(_: usize) -> InlineString<12> {
 ///        let mut str = InlineString::new();
 ///        unsafe { str.push_unchecked(b"$"[0]); }
@@ -0,0 +1,79 @@
+use oxc_allocator::{Allocator, FromIn};
+use oxc_ast::ast::*;
+use oxc_semantic::Scoping;
+use oxc_traverse::{Traverse, traverse_mut};
+
+use crate::{
+    context::{TraverseCtx, TraverseCtxState},
+    property_names::{PLACEHOLDER_LEN, PLACEHOLDER_PREFIX, PropertyMap},
+};
+
+/// Replaces placeholders with names assigned in `PropertyMap::finalize()`.
+pub fn finalize_chunk<'a>(
+    program: &mut Program<'a>,
+    property_map: &PropertyMap,
+    allocator: &'a Allocator,
+    scoping: Scoping,
+) {
+    let mut finalizer = Finalizer { property_map };
+    traverse_mut(&mut finalizer, allocator, program, scoping, TraverseCtxState::default());
+}
+
+struct Finalizer<'ctx> {
+    property_map: &'ctx PropertyMap,
+}
+
+impl<'a> Traverse<'a, TraverseCtxState<'a>> for Finalizer<'_> {
+    fn exit_identifier_name(&mut self, node: &mut IdentifierName<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(v) = self.property_map.resolve_placeholder(node.name.as_str()) {
+            node.name = Str::from_in(v, ctx.ast.allocator).into();
+        }
+    }
+
+    // Private names `#key_`, name doesn't include the `#` prefix.
+    fn exit_private_identifier(
+        &mut self,
+        node: &mut PrivateIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(v) = self.property_map.resolve_placeholder(node.name.as_str()) {
+            node.name = Str::from_in(v, ctx.ast.allocator).into();
+        }
+    }
+
+    // Placeholders in string literals are created by `key()` annotations,
+    // `defineProperty()` keys and stringified literals (`JSON.parse('..')`).
+    fn exit_string_literal(&mut self, node: &mut StringLiteral<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(v) = self.replace_placeholders(node.value.as_str()) {
+            node.value = Str::from_in(v.as_str(), ctx.ast.allocator);
+            node.raw = None;
+        }
+    }
+}
+
+impl Finalizer<'_> {
+    fn replace_placeholders(&self, s: &str) -> Option<String> {
+        let mut result: Option<String> = None;
+        let mut start = 0;
+        let mut rest = s;
+        while let Some(i) = rest.find(PLACEHOLDER_PREFIX) {
+            let offset = s.len() - rest.len() + i;
+            let Some(placeholder) = s.get(offset..offset + PLACEHOLDER_LEN) else {
+                break;
+            };
+            if let Some(v) = self.property_map.resolve_placeholder(placeholder) {
+                let r = result.get_or_insert_with(String::new);
+                r.push_str(&s[start..offset]);
+                r.push_str(v);
+                start = offset + PLACEHOLDER_LEN;
+                rest = &s[start..];
+            } else {
+                rest = &s[offset + PLACEHOLDER_PREFIX.len()..];
+            }
+        }
+        if let Some(r) = &mut result {
+            r.push_str(&s[start..]);
+        }
+        result
+    }
+}
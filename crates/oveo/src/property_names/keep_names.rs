@@ -0,0 +1,144 @@
+//! Standard property names that are never renamed.
+//!
+//! Renamed properties are often accessed on DOM objects and plain objects
+//! that inherit from `Object.prototype`, so standard names should be
+//! preserved even when they match a rename pattern. Names are also never
+//! used as mangled property names, so that renamed properties don't shadow
+//! standard properties (e.g. `id`).
+
+pub const STANDARD_NAMES: &[&str] = &[
+    // Object.prototype
+    "__proto__",
+    "__defineGetter__",
+    "__defineSetter__",
+    "__lookupGetter__",
+    "__lookupSetter__",
+    "constructor",
+    "hasOwnProperty",
+    "isPrototypeOf",
+    "propertyIsEnumerable",
+    "toLocaleString",
+    "toString",
+    "valueOf",
+    "toJSON",
+    "then",
+    // EventTarget
+    "addEventListener",
+    "removeEventListener",
+    "dispatchEvent",
+    // Node
+    "appendChild",
+    "baseURI",
+    "childNodes",
+    "cloneNode",
+    "compareDocumentPosition",
+    "contains",
+    "firstChild",
+    "getRootNode",
+    "hasChildNodes",
+    "insertBefore",
+    "isConnected",
+    "isEqualNode",
+    "isSameNode",
+    "lastChild",
+    "nextSibling",
+    "nodeName",
+    "nodeType",
+    "nodeValue",
+    "normalize",
+    "ownerDocument",
+    "parentElement",
+    "parentNode",
+    "previousSibling",
+    "removeChild",
+    "replaceChild",
+    "textContent",
+    // Element
+    "after",
+    "animate",
+    "append",
+    "attachShadow",
+    "attributes",
+    "before",
+    "childElementCount",
+    "children",
+    "classList",
+    "className",
+    "clientHeight",
+    "clientLeft",
+    "clientTop",
+    "clientWidth",
+    "closest",
+    "firstElementChild",
+    "getAttribute",
+    "getBoundingClientRect",
+    "getElementsByClassName",
+    "getElementsByTagName",
+    "hasAttribute",
+    "id",
+    "innerHTML",
+    "insertAdjacentElement",
+    "insertAdjacentHTML",
+    "insertAdjacentText",
+    "lastElementChild",
+    "localName",
+    "matches",
+    "namespaceURI",
+    "nextElementSibling",
+    "outerHTML",
+    "part",
+    "prepend",
+    "previousElementSibling",
+    "querySelector",
+    "querySelectorAll",
+    "remove",
+    "removeAttribute",
+    "replaceChildren",
+    "replaceWith",
+    "scrollHeight",
+    "scrollIntoView",
+    "scrollLeft",
+    "scrollTop",
+    "scrollWidth",
+    "setAttribute",
+    "shadowRoot",
+    "slot",
+    "tagName",
+    "toggleAttribute",
+    // HTMLElement
+    "blur",
+    "click",
+    "contentEditable",
+    "dataset",
+    "dir",
+    "draggable",
+    "focus",
+    "hidden",
+    "innerText",
+    "inert",
+    "lang",
+    "offsetHeight",
+    "offsetLeft",
+    "offsetParent",
+    "offsetTop",
+    "offsetWidth",
+    "outerText",
+    "style",
+    "tabIndex",
+    "title",
+    // Form elements
+    "checked",
+    "disabled",
+    "form",
+    "name",
+    "placeholder",
+    "readOnly",
+    "required",
+    "selected",
+    "type",
+    "value",
+    // Links and media
+    "href",
+    "src",
+    "alt",
+];
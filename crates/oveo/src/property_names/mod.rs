@@ -9,33 +9,292 @@ use rustc_hash::{FxHashMap, FxHashSet};
 use crate::{OptimizerError, property_names::base54::base54};
 
 mod base54;
+pub(crate) mod finalize;
+mod keep_names;
 
 pub struct PropertyMap {
     regex: Option<regex::Regex>,
+    /// Property map is shared between chunks that are rendered concurrently,
+    /// new names are assigned while the `index` entry is locked, so that each
+    /// property gets exactly one name. `used` is always locked after the
+    /// `index` entry and it is never held while accessing `index`.
     index: DashMap<Box<str>, CompactStr>,
     used: Mutex<UsedIds>,
+    /// New names are assigned in `finalize()` in a sorted order, so they
+    /// don't depend on the order in which chunks are processed.
+    deterministic: bool,
+    /// Final names for placeholders that were assigned in `finalize()`.
+    placeholders: FxHashMap<CompactStr, CompactStr>,
+    /// Names that can't be used as mangled property names in addition to
+    /// reserved keywords.
+    reserved: Vec<CompactStr>,
+    /// Names that are never renamed and never used as mangled property names,
+    /// standard DOM and `Object.prototype` names are always included.
+    keep_names: FxHashSet<CompactStr>,
+    naming: PropertyNaming,
+    /// Properties aren't renamed when their mangled names would be longer
+    /// than this limit.
+    max_mangled_len: Option<usize>,
+}
+
+#[derive(Default, Debug, Clone, Copy)]
+pub struct PropertyMapStats {
+    /// Number of renamed properties.
+    pub count: usize,
+    /// Total length of original property names.
+    pub original_len: usize,
+    /// Total length of mangled property names.
+    pub mangled_len: usize,
+    /// Number of properties that weren't renamed because their mangled
+    /// names would exceed the maximum length.
+    pub skipped: usize,
+}
+
+/// How new property names are assigned.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PropertyNaming {
+    /// Names are assigned in the order in which properties are discovered.
+    #[default]
+    Sequential,
+    /// Names are derived from a hash of the property name, so the same
+    /// property gets the same name regardless of the discovery order,
+    /// unless its name is already taken by a property with a colliding hash.
+    Hash,
+}
+
+impl std::str::FromStr for PropertyNaming {
+    type Err = OptimizerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sequential" => Ok(Self::Sequential),
+            "hash" => Ok(Self::Hash),
+            _ => Err(OptimizerError::OptionsParseError(format!("Invalid property naming: {s}"))),
+        }
+    }
 }
 
 #[derive(Default)]
 struct UsedIds {
     index: FxHashSet<CompactStr>,
     next_id: u32,
+    next_placeholder_id: u32,
+    /// Properties that weren't renamed because their mangled names would
+    /// exceed the maximum length.
+    skipped: FxHashSet<CompactStr>,
+    /// New names were assigned.
+    dirty: bool,
+}
+
+impl UsedIds {
+    /// Returns `None` when the new name would be longer than `max_len`.
+    fn new_uid(
+        &mut self,
+        key: &str,
+        naming: PropertyNaming,
+        max_len: Option<usize>,
+    ) -> Option<CompactStr> {
+        let max_len = max_len.unwrap_or(usize::MAX);
+        let uid = match naming {
+            PropertyNaming::Sequential => self.next_uid(max_len),
+            PropertyNaming::Hash => {
+                self.hashed_uid(key, max_len).or_else(|| self.next_uid(max_len))
+            }
+        };
+        if uid.is_some() {
+            self.dirty = true;
+        } else {
+            self.skipped.insert(key.into());
+        }
+        uid
+    }
+
+    fn next_uid(&mut self, max_len: usize) -> Option<CompactStr> {
+        loop {
+            let i = self.next_id;
+            let uid = base54(i);
+            // Names are generated in the order of their length, so all
+            // following names are also too long.
+            if uid.as_str().len() > max_len {
+                return None;
+            }
+            self.next_id += 1;
+            let uid: CompactStr = uid.as_str().into();
+            if self.index.insert(uid.clone()) {
+                return Some(uid);
+            }
+        }
+    }
+
+    /// Probes a few names of each length derived from the hash of the key,
+    /// shorter names are probed first.
+    fn hashed_uid(&mut self, key: &str, max_len: usize) -> Option<CompactStr> {
+        const PROBES: u32 = 4;
+        let mut h = fnv1a(key.as_bytes());
+        let mut start: u64 = 0;
+        let mut size: u64 = 54;
+        let mut len = 1;
+        while start + size <= u64::from(u32::MAX) && len <= max_len {
+            for _ in 0..PROBES {
+                let i = start + h % size;
+                let uid: CompactStr = base54(i as u32).as_str().into();
+                if self.index.insert(uid.clone()) {
+                    return Some(uid);
+                }
+                h = splitmix64(h);
+            }
+            start += size;
+            size *= 64;
+            len += 1;
+        }
+        None
+    }
+}
+
+/// Hash function that is stable across platforms and builds.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut h: u64 = 0xcbf2_9ce4_8422_2325;
+    for b in bytes {
+        h ^= u64::from(*b);
+        h = h.wrapping_mul(0x0100_0000_01b3);
+    }
+    h
+}
+
+fn splitmix64(mut h: u64) -> u64 {
+    h = h.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    h = (h ^ (h >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    h = (h ^ (h >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    h ^ (h >> 31)
 }
 
 impl PropertyMap {
     pub fn new(regex: Option<regex::Regex>) -> Self {
+        let keep_names: FxHashSet<CompactStr> =
+            keep_names::STANDARD_NAMES.iter().map(|name| CompactStr::from(*name)).collect();
         let used = Mutex::new(UsedIds::default());
-        add_reserved_keywords(&mut used.lock().unwrap().index);
+        {
+            let index = &mut used.lock().unwrap().index;
+            add_reserved_keywords(index);
+            index.extend(keep_names.iter().cloned());
+        }
+
+        Self {
+            regex,
+            index: DashMap::default(),
+            used,
+            deterministic: false,
+            placeholders: FxHashMap::default(),
+            reserved: Vec::new(),
+            keep_names,
+            naming: PropertyNaming::Sequential,
+            max_mangled_len: None,
+        }
+    }
+
+    pub fn with_naming(mut self, naming: PropertyNaming) -> Self {
+        self.naming = naming;
+        self
+    }
+
+    /// Reserves names, so that they are never used as mangled property
+    /// names (e.g. framework-injected globals).
+    pub fn with_reserved<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.reserved.extend(names.into_iter().map(|name| CompactStr::from(name.as_ref())));
+        self.used.get_mut().unwrap().index.extend(self.reserved.iter().cloned());
+        self
+    }
+
+    /// Names that are never renamed in addition to standard DOM and
+    /// `Object.prototype` names, they are also never used as mangled property
+    /// names.
+    pub fn with_keep_names<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let used = self.used.get_mut().unwrap();
+        for name in names {
+            let name = CompactStr::from(name.as_ref());
+            used.index.insert(name.clone());
+            self.keep_names.insert(name);
+        }
+        self
+    }
+
+    /// Properties aren't renamed when their mangled names would be longer
+    /// than `len`, it is a safety valve for patterns that match too many
+    /// properties.
+    pub fn with_max_mangled_len(mut self, len: Option<usize>) -> Self {
+        self.max_mangled_len = len;
+        self
+    }
 
-        Self { regex, index: DashMap::default(), used }
+    /// Enables deterministic mode.
+    ///
+    /// New properties are renamed to placeholders, `finalize()` assigns them
+    /// names in a sorted order and `finalize_chunk()` replaces placeholders
+    /// in the chunk output.
+    pub fn with_deterministic(mut self, deterministic: bool) -> Self {
+        self.deterministic = deterministic;
+        self
+    }
+
+    pub fn is_deterministic(&self) -> bool {
+        self.deterministic
+    }
+
+    /// Removes all renamed properties, configured pattern is preserved.
+    pub fn reset(&mut self) {
+        let used = self.used.get_mut().unwrap();
+        used.next_id = 0;
+        used.next_placeholder_id = 0;
+        used.dirty = false;
+        used.skipped.clear();
+        used.index.clear();
+        add_reserved_keywords(&mut used.index);
+        used.index.extend(self.reserved.iter().cloned());
+        used.index.extend(self.keep_names.iter().cloned());
+        self.index.clear();
+        self.placeholders.clear();
+    }
+
+    /// Assigns names to properties that were renamed to placeholders.
+    pub fn finalize(&mut self) {
+        let mut pending: Vec<(Box<str>, CompactStr)> = self
+            .index
+            .iter()
+            .filter(|e| is_placeholder(e.value()))
+            .map(|e| (e.key().clone(), e.value().clone()))
+            .collect();
+        pending.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+
+        let used = self.used.get_mut().unwrap();
+        for (key, placeholder) in pending {
+            if let Some(uid) = used.new_uid(&key, self.naming, self.max_mangled_len) {
+                self.index.insert(key, uid.clone());
+                self.placeholders.insert(placeholder, uid);
+            } else {
+                // Placeholders of skipped properties are replaced with
+                // original names.
+                self.index.remove(&key);
+                self.placeholders.insert(placeholder, key.as_ref().into());
+            }
+        }
+    }
+
+    fn resolve_placeholder(&self, placeholder: &str) -> Option<&str> {
+        self.placeholders.get(placeholder).map(|v| v.as_str())
     }
 
     pub fn import(&mut self, data: &[u8]) -> Result<(), OptimizerError> {
+        self.reset();
         {
             let mut used = self.used.lock().unwrap();
-            used.next_id = 0;
-            used.index.clear();
-            self.index.clear();
 
             for (i, line) in data.split(|c| *c == b'\n').enumerate() {
                 let line = line.trim_ascii();
@@ -45,6 +304,22 @@ impl PropertyMap {
                         i + 1
                     )));
                 };
+                // Property maps exported by older versions don't have a header.
+                if i == 0 && line.starts_with('#') {
+                    match line.strip_prefix(PROPERTY_MAP_HEADER_PREFIX) {
+                        Some(PROPERTY_MAP_VERSION) => continue,
+                        Some(version) => {
+                            return Err(OptimizerError::PropertyMapParseError(format!(
+                                "unsupported version '{version}', expected '{PROPERTY_MAP_VERSION}'"
+                            )));
+                        }
+                        None => {
+                            return Err(OptimizerError::PropertyMapParseError(format!(
+                                "invalid header '{line}'"
+                            )));
+                        }
+                    }
+                }
                 if !line.is_empty() {
                     let mut split = line.split('=');
                     let Some(key) = split.next() else {
@@ -59,9 +334,33 @@ impl PropertyMap {
                             i + 1
                         )));
                     };
+                    if RESERVED_KEYWORDS.contains(&value) {
+                        return Err(OptimizerError::PropertyMapParseError(format!(
+                            "reserved keyword '{value}' at line '{}'",
+                            i + 1
+                        )));
+                    }
+                    // Kept names could be renamed by previous builds before
+                    // they were added to the keep list.
+                    if self.keep_names.contains(key) {
+                        continue;
+                    }
+                    if self.reserved.iter().any(|name| name == value)
+                        || self.keep_names.contains(value)
+                    {
+                        return Err(OptimizerError::PropertyMapParseError(format!(
+                            "reserved name '{value}' at line '{}'",
+                            i + 1
+                        )));
+                    }
                     let v: CompactStr = value.into();
-                    self.index.insert(key.into(), v.clone());
-                    used.index.insert(v);
+                    if !used.index.insert(v.clone()) {
+                        return Err(OptimizerError::PropertyMapParseError(format!(
+                            "duplicate value '{value}' at line '{}'",
+                            i + 1
+                        )));
+                    }
+                    self.index.insert(key.into(), v);
                 }
             }
         }
@@ -69,17 +368,40 @@ impl PropertyMap {
     }
 
     pub fn is_dirty(&self) -> bool {
-        self.used.lock().unwrap().next_id != 0
+        self.used.lock().unwrap().dirty
+    }
+
+    /// Statistics of renamed properties, placeholders that weren't finalized
+    /// are ignored.
+    pub fn stats(&self) -> PropertyMapStats {
+        let mut stats = PropertyMapStats {
+            skipped: self.used.lock().unwrap().skipped.len(),
+            ..Default::default()
+        };
+        for i in self.index.iter() {
+            if !is_placeholder(i.value()) {
+                stats.count += 1;
+                stats.original_len += i.key().len();
+                stats.mangled_len += i.value().len();
+            }
+        }
+        stats
     }
 
     pub fn export(&self) -> Vec<u8> {
         let mut props = Vec::new();
         for i in self.index.iter() {
-            props.push((i.key().to_string(), i.value().to_string()))
+            // Placeholders are exported after `finalize()`.
+            if !is_placeholder(i.value()) {
+                props.push((i.key().to_string(), i.value().to_string()))
+            }
         }
         props.sort_by(|a, b| a.0.cmp(&b.0));
 
         let mut b: Vec<u8> = Vec::new();
+        b.extend(PROPERTY_MAP_HEADER_PREFIX.as_bytes());
+        b.extend(PROPERTY_MAP_VERSION.as_bytes());
+        b.push(b'\n');
         for i in &props {
             b.extend(i.0.as_bytes());
             b.push(b'=');
@@ -90,6 +412,9 @@ impl PropertyMap {
     }
 
     pub fn matches(&self, s: &str) -> bool {
+        if self.keep_names.contains(s) {
+            return false;
+        }
         if let Some(re) = &self.regex { re.is_match(s) } else { false }
     }
 }
@@ -116,18 +441,27 @@ impl<'a, 'ctx> LocalPropertyMap<'a, 'ctx> {
                         if !self.map.matches(key.as_str()) {
                             None
                         } else {
+                            // Vacant entry keeps the `index` shard locked
+                            // until the new name is inserted.
                             let mut used = self.map.used.lock().unwrap();
-                            let uid = loop {
-                                let i = used.next_id;
-                                used.next_id += 1;
-                                let s = base54(i);
-                                let uid: CompactStr = s.as_str().into();
-                                if used.index.insert(uid.clone()) {
-                                    index_entry.insert(uid);
-                                    break Str::from_in(s.as_str(), ast.allocator);
-                                }
+                            let uid = if used.skipped.contains(key.as_str()) {
+                                None
+                            } else if self.map.deterministic {
+                                let i = used.next_placeholder_id;
+                                used.next_placeholder_id += 1;
+                                Some(placeholder(i))
+                            } else {
+                                used.new_uid(
+                                    key.as_str(),
+                                    self.map.naming,
+                                    self.map.max_mangled_len,
+                                )
                             };
-                            Some(uid)
+                            uid.map(|uid| {
+                                let s = Str::from_in(uid.as_str(), ast.allocator);
+                                index_entry.insert(uid);
+                                s
+                            })
                         }
                     }
                 };
@@ -138,31 +472,32 @@ impl<'a, 'ctx> LocalPropertyMap<'a, 'ctx> {
     }
 }
 
+/// The first line of exported property maps, the version is changed when the
+/// format becomes incompatible.
+const PROPERTY_MAP_HEADER_PREFIX: &str = "# oveo-propmap ";
+const PROPERTY_MAP_VERSION: &str = "v1";
+
+/// Placeholders have a fixed width, so that size-dependent optimizations
+/// (e.g. stringify threshold) don't depend on the order of chunks.
+const PLACEHOLDER_PREFIX: &str = "$oveo$";
+const PLACEHOLDER_LEN: usize = PLACEHOLDER_PREFIX.len() + 9;
+
+fn placeholder(id: u32) -> CompactStr {
+    format!("{PLACEHOLDER_PREFIX}{id:08}$").into()
+}
+
+fn is_placeholder(s: &str) -> bool {
+    s.len() == PLACEHOLDER_LEN && s.starts_with(PLACEHOLDER_PREFIX)
+}
+
+/// Short identifiers that can't be used as mangled property names.
+const RESERVED_KEYWORDS: &[&str] = &[
+    "as", "do", "if", "in", "is", "of", "any", "for", "get", "let", "new", "out", "set", "try",
+    "var", "case", "else", "enum", "from", "meta", "null", "this", "true", "type", "void", "with",
+];
+
 fn add_reserved_keywords(index: &mut FxHashSet<CompactStr>) {
-    index.insert("as".into());
-    index.insert("do".into());
-    index.insert("if".into());
-    index.insert("in".into());
-    index.insert("is".into());
-    index.insert("of".into());
-    index.insert("any".into());
-    index.insert("for".into());
-    index.insert("get".into());
-    index.insert("let".into());
-    index.insert("new".into());
-    index.insert("out".into());
-    index.insert("set".into());
-    index.insert("try".into());
-    index.insert("var".into());
-    index.insert("case".into());
-    index.insert("else".into());
-    index.insert("enum".into());
-    index.insert("from".into());
-    index.insert("meta".into());
-    index.insert("null".into());
-    index.insert("this".into());
-    index.insert("true".into());
-    index.insert("type".into());
-    index.insert("void".into());
-    index.insert("with".into());
+    for k in RESERVED_KEYWORDS {
+        index.insert((*k).into());
+    }
 }
@@ -33,6 +33,33 @@ impl<'a> Statements<'a> {
         modifications.insertions.push(stmt);
     }
 
+    /// Inserts statement after target statement, statements inserted after
+    /// the same target are emitted in the order of insertion.
+    #[inline]
+    pub fn insert_after<A: GetAddress>(&self, target: &A, stmt: Statement<'a>) {
+        self.insert_after_address(target.address(), stmt);
+    }
+
+    fn insert_after_address(&self, target: Address, stmt: Statement<'a>) {
+        let mut insertions = self.modifications.borrow_mut();
+        let modifications = insertions.entry(target).or_default();
+        modifications.after_insertions.push(stmt);
+    }
+
+    /// Replaces target statement, replacement takes precedence over removal
+    /// and target can be replaced only once.
+    #[inline]
+    pub fn replace<A: GetAddress>(&self, target: &A, stmt: Statement<'a>) {
+        self.replace_address(target.address(), stmt);
+    }
+
+    fn replace_address(&self, target: Address, stmt: Statement<'a>) {
+        let mut modifications = self.modifications.borrow_mut();
+        let modification = modifications.entry(target).or_default();
+        debug_assert!(modification.replacement.is_none());
+        modification.replacement = Some(stmt);
+    }
+
     #[inline]
     pub fn remove<A: GetAddress>(&self, target: A) {
         self.remove_address(target.address());
@@ -45,7 +72,7 @@ impl<'a> Statements<'a> {
                 entry.into_mut().remove = true;
             }
             Entry::Vacant(entry) => {
-                entry.insert(StatementModification { insertions: Vec::new(), remove: true });
+                entry.insert(StatementModification { remove: true, ..Default::default() });
             }
         }
     }
@@ -66,8 +93,8 @@ impl<'a> Traverse<'a, TraverseCtxState<'a>> for Statements<'a> {
         let mut dirty = false;
         for s in statements.iter() {
             if let Some(m) = modifications.get(&s.address()) {
-                new_statement_count += m.insertions.len();
-                if m.remove {
+                new_statement_count += m.insertions.len() + m.after_insertions.len();
+                if m.remove && m.replacement.is_none() {
                     new_statement_count -= 1;
                 }
                 dirty = true;
@@ -83,9 +110,12 @@ impl<'a> Traverse<'a, TraverseCtxState<'a>> for Statements<'a> {
             match modifications.remove(&stmt.address()) {
                 Some(modifications) => {
                     new_statements.extend(modifications.insertions);
-                    if !modifications.remove {
+                    if let Some(replacement) = modifications.replacement {
+                        new_statements.push(replacement);
+                    } else if !modifications.remove {
                         new_statements.push(stmt);
                     }
+                    new_statements.extend(modifications.after_insertions);
                 }
                 _ => {
                     new_statements.push(stmt);
@@ -104,7 +134,9 @@ impl<'a> Traverse<'a, TraverseCtxState<'a>> for Statements<'a> {
             return;
         }
 
-        // Insert statements before the first non-import statement
+        // Insert statements before the first non-import statement. Hashbang and
+        // directive prologue aren't stored in the program body, so inserted
+        // statements always come after them.
         let index = program
             .body
             .iter()
@@ -118,5 +150,112 @@ impl<'a> Traverse<'a, TraverseCtxState<'a>> for Statements<'a> {
 #[derive(Default, Debug)]
 struct StatementModification<'a> {
     insertions: Vec<Statement<'a>>,
+    after_insertions: Vec<Statement<'a>>,
+    replacement: Option<Statement<'a>>,
     remove: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use oxc_allocator::Allocator;
+    use oxc_codegen::CommentOptions;
+    use oxc_span::{SPAN, SourceType};
+    use oxc_traverse::{Traverse, traverse_mut};
+
+    use super::*;
+    use crate::{OptimizerStats, codegen, parse};
+
+    type Modify = for<'a> fn(&Statements<'a>, &Statement<'a>, &TraverseCtx<'a>);
+
+    /// Invokes `modify` on the `target;` statement.
+    struct Modifier<'a> {
+        statements: Statements<'a>,
+        modify: Modify,
+    }
+
+    impl<'a> Traverse<'a, TraverseCtxState<'a>> for Modifier<'a> {
+        fn enter_statement(&mut self, node: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+            if let Statement::ExpressionStatement(stmt) = node
+                && let Expression::Identifier(id) = &stmt.expression
+                && id.name == "target"
+            {
+                (self.modify)(&self.statements, node, ctx);
+            }
+        }
+
+        fn exit_statements(
+            &mut self,
+            node: &mut ArenaVec<'a, Statement<'a>>,
+            ctx: &mut TraverseCtx<'a>,
+        ) {
+            self.statements.exit_statements(node, ctx);
+        }
+
+        fn exit_program(&mut self, node: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+            self.statements.exit_program(node, ctx);
+        }
+    }
+
+    fn run(modify: Modify) -> String {
+        let allocator = Allocator::default();
+        let (mut program, scoping) =
+            parse(&allocator, "a;\ntarget;\nb;\n", SourceType::mjs()).unwrap();
+        let mut modifier = Modifier { statements: Statements::new(), modify };
+        traverse_mut(&mut modifier, &allocator, &mut program, scoping, TraverseCtxState::default());
+        let output = codegen(&program, OptimizerStats::default(), "", CommentOptions::default());
+        output.code.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+
+    fn stmt<'a>(name: &'static str, ctx: &TraverseCtx<'a>) -> Statement<'a> {
+        Statement::ExpressionStatement(ExpressionStatement::boxed(
+            SPAN,
+            Expression::Identifier(IdentifierReference::boxed(SPAN, name, ctx)),
+            ctx,
+        ))
+    }
+
+    #[test]
+    fn insert_before_and_after() {
+        let code = run(|statements, target, ctx| {
+            statements.insert_after(target, stmt("after1", ctx));
+            statements.insert_before(target, stmt("before1", ctx));
+            statements.insert_after(target, stmt("after2", ctx));
+            statements.insert_before(target, stmt("before2", ctx));
+        });
+        assert_eq!(code, "a; before1; before2; target; after1; after2; b;");
+    }
+
+    #[test]
+    fn replace() {
+        let code = run(|statements, target, ctx| {
+            statements.replace(target, stmt("replacement", ctx));
+        });
+        assert_eq!(code, "a; replacement; b;");
+    }
+
+    #[test]
+    fn replace_and_insert_before() {
+        let code = run(|statements, target, ctx| {
+            statements.replace(target, stmt("replacement", ctx));
+            statements.insert_before(target, stmt("before", ctx));
+        });
+        assert_eq!(code, "a; before; replacement; b;");
+    }
+
+    #[test]
+    fn replace_and_remove() {
+        let code = run(|statements, target, ctx| {
+            statements.remove_address(target.address());
+            statements.replace(target, stmt("replacement", ctx));
+        });
+        assert_eq!(code, "a; replacement; b;");
+    }
+
+    #[test]
+    fn remove() {
+        let code = run(|statements, target, _ctx| {
+            statements.remove_address(target.address());
+        });
+        assert_eq!(code, "a; b;");
+    }
+}
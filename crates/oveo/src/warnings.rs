@@ -0,0 +1,33 @@
+use oxc_span::Span;
+
+use crate::{SourceLocation, WarningSink};
+
+/// Non-fatal issues reported by optimizers, each warning is forwarded to the
+/// warning sink as soon as it is reported.
+pub struct Warnings<'s> {
+    messages: Vec<String>,
+    sink: Option<WarningSink<'s>>,
+}
+
+impl<'s> Warnings<'s> {
+    pub fn new(sink: Option<WarningSink<'s>>) -> Self {
+        Self { messages: Vec::new(), sink }
+    }
+
+    /// Adds a warning with a location of the span, e.g. `unable to inline
+    /// call at 3:10`.
+    pub fn warn(&mut self, source_text: &str, message: &str, span: Span) {
+        let message = match SourceLocation::from_offset(source_text, span.start) {
+            Some(loc) => format!("{message} at {}:{}", loc.line, loc.column),
+            None => message.to_string(),
+        };
+        if let Some(sink) = self.sink {
+            sink(&message);
+        }
+        self.messages.push(message);
+    }
+
+    pub fn into_messages(self) -> Vec<String> {
+        self.messages
+    }
+}
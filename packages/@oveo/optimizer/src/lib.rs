@@ -1,7 +1,14 @@
-use napi::{Env, bindgen_prelude::*};
+use napi::{
+    Env,
+    bindgen_prelude::*,
+    threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode},
+};
 use napi_derive::napi;
 use oveo::PropertyMap;
-use oveo::{externs::ExternMap, optimize_chunk, optimize_module};
+use oveo::{
+    collect_properties, externs::ExternMap, finalize_chunk, optimize_chunk, optimize_chunks_with,
+    optimize_module, optimize_module_single_phase,
+};
 
 use std::sync::Arc;
 use std::sync::RwLock;
@@ -13,24 +20,109 @@ pub struct Optimizer {
 
 struct OptimizerState {
     options: oveo::OptimizerOptions,
+    single_phase: bool,
     externs: RwLock<ExternMap>,
     property_map: RwLock<PropertyMap>,
 }
 
-#[napi]
+#[napi(object)]
 pub struct OptimizerOutput {
     pub code: String,
     pub map: String,
+    pub stats: OptimizerStats,
+    /// Annotated expressions that couldn't be optimized and were emitted
+    /// unchanged.
+    pub warnings: Vec<String>,
+}
+
+#[napi(object)]
+pub struct OptimizerStats {
+    pub used_externs: Vec<UsedExtern>,
+    pub hoist_log: Vec<String>,
+    /// Number of duplicate expressions replaced with references to
+    /// deduplicated expressions.
+    pub duplicates_collapsed: u32,
+    /// Number of injected declarations with hoisted globals.
+    pub globals_hoisted: u32,
+    /// Number of renamed property names.
+    pub properties_renamed: u32,
+}
+
+#[napi(object)]
+pub struct UsedExtern {
+    pub module: String,
+    pub export: String,
 }
 
+#[napi(object)]
+pub struct PropertyMapStats {
+    /// Number of renamed properties.
+    pub count: u32,
+    pub average_original_len: f64,
+    pub average_mangled_len: f64,
+    /// Number of properties that weren't renamed because their mangled
+    /// names would exceed `maxMangledLen`.
+    pub skipped: u32,
+}
+
+/// Chunk that was optimized by `renderChunks()`.
+#[napi(object)]
+pub struct RenderedChunk {
+    /// Index of the chunk source.
+    pub index: u32,
+    /// Error message when chunk failed to optimize.
+    pub error: Option<String>,
+    /// Non-fatal issues reported while the chunk was optimized.
+    pub warnings: Vec<String>,
+}
+
+/// Warning reported by `renderChunks()`.
+#[napi(object)]
+pub struct ChunkWarning {
+    /// Index of the chunk source.
+    pub index: u32,
+    pub message: String,
+}
+
+type RenderedChunkCallback = ThreadsafeFunction<RenderedChunk, (), RenderedChunk, Status, false>;
+type ChunkWarningCallback = ThreadsafeFunction<ChunkWarning, (), ChunkWarning, Status, false>;
+type WarningCallback = ThreadsafeFunction<String, (), String, Status, false>;
+
 #[napi(object)]
 pub struct OptimizerOptions {
     pub hoist: Option<bool>,
+    pub no_hoist_in_async_generators: Option<bool>,
+    /// Collects hoist stack entries and Hoist Scope decisions in
+    /// `stats.hoistLog`.
+    pub debug_hoist: Option<bool>,
     pub dedupe: Option<bool>,
+    pub dedupe_isolate_module_wrappers: Option<bool>,
+    pub dedupe_sibling_scopes: Option<bool>,
+    /// Deduplicates call expressions without `/* @__PURE__ */` annotations.
+    pub dedupe_calls: Option<bool>,
     pub globals: Option<GlobalsOptions>,
     pub externs: Option<ExternsOptions>,
     pub rename_properties: Option<RenamePropertiesOptions>,
+    pub pure: Option<bool>,
+    pub dce: Option<bool>,
+    pub const_propagation: Option<bool>,
+    pub inline: Option<bool>,
+    pub mark_pure_constructors: Option<bool>,
+    pub stringify_large_literals: Option<StringifyLargeLiteralsOptions>,
     pub url: Option<URLOptions>,
+    /// `"all"` (default), `"legal"` or `"none"`.
+    pub preserve_comments: Option<String>,
+    /// Appends comments with the originating pass to injected declarations.
+    pub debug_comments: Option<bool>,
+    /// Names injected declarations after the original global or the
+    /// deduplicated expression.
+    pub debug_names: Option<bool>,
+    /// `renderChunk()` leaves `__oveo__(expr, flags)` annotation calls
+    /// unchanged, so that the `transform()` output can be inspected.
+    pub keep_annotations: Option<bool>,
+    /// Resolves annotations in `transform()`, output doesn't need to be
+    /// processed by `renderChunk()`.
+    pub single_phase: Option<bool>,
 }
 
 #[napi(object)]
@@ -38,16 +130,71 @@ pub struct GlobalsOptions {
     pub include: Option<Vec<String>>,
     pub hoist: Option<bool>,
     pub singletons: Option<bool>,
+    pub min_uses: Option<u32>,
+    pub singleton_constructors: Option<Vec<String>>,
+    /// `"root"` (default) or `"nearest"`.
+    pub scope: Option<String>,
+    pub auto_hoist_literals: Option<bool>,
+    /// Globals that are referenced only at the top level aren't hoisted.
+    pub no_hoist_in_top_scope: Option<bool>,
 }
 
 #[napi(object)]
 pub struct ExternsOptions {
     pub inline_const_values: Option<bool>,
+    pub max_inline_size: Option<u32>,
 }
 
 #[napi(object)]
 pub struct RenamePropertiesOptions {
     pub pattern: Option<String>,
+    pub key_objects_only: Option<bool>,
+    /// New property names are assigned in `finalizePropertyMap()` in a sorted
+    /// order, chunks should be processed with `finalizeChunk()` after that.
+    pub deterministic: Option<bool>,
+    /// Names that are never used as renamed property names.
+    pub reserved: Option<Vec<String>>,
+    /// Names that are never renamed in addition to standard DOM and
+    /// `Object.prototype` names.
+    pub keep_names: Option<Vec<String>>,
+    /// `"sequential"` (default) or `"hash"`.
+    pub naming: Option<String>,
+    /// Properties aren't renamed when their mangled names would be longer
+    /// than this limit.
+    pub max_mangled_len: Option<u32>,
+}
+
+#[napi(object)]
+pub struct StringifyLargeLiteralsOptions {
+    pub threshold: Option<u32>,
+}
+
+#[napi(object, object_to_js = false)]
+pub struct TransformOptions {
+    /// File name that is used in the `file` and `sources` fields of the output
+    /// source map.
+    pub file_name: Option<String>,
+    /// Source map of the input source text (JSON string).
+    pub input_source_map: Option<String>,
+    /// Invoked as soon as each warning is reported, warnings are also
+    /// returned in `OptimizerOutput.warnings`.
+    #[napi(ts_type = "(message: string) => void")]
+    pub on_warning: Option<WarningCallback>,
+}
+
+#[napi(object, object_to_js = false)]
+pub struct RenderChunkOptions {
+    /// File name that is used in the `file` and `sources` fields of the output
+    /// source map.
+    pub file_name: Option<String>,
+    /// Source map produced by plugins that transformed the chunk before it
+    /// (JSON string), the output source map is remapped to the original
+    /// sources.
+    pub input_source_map: Option<String>,
+    /// Invoked as soon as each warning is reported, warnings are also
+    /// returned in `OptimizerOutput.warnings`.
+    #[napi(ts_type = "(message: string) => void")]
+    pub on_warning: Option<WarningCallback>,
 }
 
 #[napi(object)]
@@ -60,7 +207,51 @@ pub struct URLOptions {
 impl Optimizer {
     #[napi(constructor)]
     pub fn new(options: Option<OptimizerOptions>) -> Result<Self> {
+        let single_phase = options.as_ref().and_then(|v| v.single_phase).unwrap_or_default();
+        let deterministic = options
+            .as_ref()
+            .and_then(|v| v.rename_properties.as_ref())
+            .and_then(|v| v.deterministic)
+            .unwrap_or_default();
+        let reserved = options
+            .as_ref()
+            .and_then(|v| v.rename_properties.as_ref())
+            .and_then(|v| v.reserved.clone())
+            .unwrap_or_default();
+        let keep_names = options
+            .as_ref()
+            .and_then(|v| v.rename_properties.as_ref())
+            .and_then(|v| v.keep_names.clone())
+            .unwrap_or_default();
+        let naming = options
+            .as_ref()
+            .and_then(|v| v.rename_properties.as_ref())
+            .and_then(|v| v.naming.as_deref())
+            .map(str::parse::<oveo::PropertyNaming>)
+            .transpose()
+            .map_err(|err| napi::Error::from_reason(err.to_string()))?
+            .unwrap_or_default();
+        let max_mangled_len = options
+            .as_ref()
+            .and_then(|v| v.rename_properties.as_ref())
+            .and_then(|v| v.max_mangled_len)
+            .map(|v| v as usize);
         let (options, pattern) = if let Some(options) = options {
+            let globals_scope = options
+                .globals
+                .as_ref()
+                .and_then(|v| v.scope.as_deref())
+                .map(str::parse::<oveo::GlobalsScope>)
+                .transpose()
+                .map_err(|err| napi::Error::from_reason(err.to_string()))?
+                .unwrap_or_default();
+            let preserve_comments = options
+                .preserve_comments
+                .as_deref()
+                .map(str::parse::<oveo::PreserveComments>)
+                .transpose()
+                .map_err(|err| napi::Error::from_reason(err.to_string()))?
+                .unwrap_or_default();
             let (rename_properties, pattern) =
                 if let Some(rename_propeties) = &options.rename_properties {
                     let pattern = if let Some(str_pat) = &rename_propeties.pattern {
@@ -78,7 +269,16 @@ impl Optimizer {
             (
                 oveo::OptimizerOptions {
                     hoist: options.hoist.unwrap_or_default(),
+                    no_hoist_in_async_generators: options
+                        .no_hoist_in_async_generators
+                        .unwrap_or_default(),
+                    debug_hoist: options.debug_hoist.unwrap_or_default(),
                     dedupe: options.dedupe.unwrap_or_default(),
+                    dedupe_isolate_module_wrappers: options
+                        .dedupe_isolate_module_wrappers
+                        .unwrap_or_default(),
+                    dedupe_sibling_scopes: options.dedupe_sibling_scopes.unwrap_or_default(),
+                    dedupe_calls: options.dedupe_calls.unwrap_or_default(),
                     globals: options
                         .globals
                         .as_ref()
@@ -94,10 +294,50 @@ impl Optimizer {
                                 .unwrap_or_default(),
                             hoist: v.hoist.unwrap_or_default(),
                             singletons: v.singletons.unwrap_or_default(),
+                            min_uses: v.min_uses.unwrap_or(1),
+                            singleton_constructors: v
+                                .singleton_constructors
+                                .clone()
+                                .unwrap_or_default(),
+                            scope: globals_scope,
+                            auto_hoist_literals: v.auto_hoist_literals.unwrap_or_default(),
+                            no_hoist_in_top_scope: v.no_hoist_in_top_scope.unwrap_or_default(),
                         })
                         .unwrap_or_default(),
+                    externs: oveo::ExternsOptions {
+                        inline_const_values: options
+                            .externs
+                            .as_ref()
+                            .and_then(|v| v.inline_const_values)
+                            .unwrap_or_default(),
+                        max_inline_size: options
+                            .externs
+                            .as_ref()
+                            .and_then(|v| v.max_inline_size)
+                            .map(|v| v as usize),
+                    },
                     rename_properties,
+                    rename_key_objects_only: options
+                        .rename_properties
+                        .as_ref()
+                        .and_then(|v| v.key_objects_only)
+                        .unwrap_or_default(),
+                    pure: options.pure.unwrap_or_default(),
+                    dce: options.dce.unwrap_or_default(),
+                    const_propagation: options.const_propagation.unwrap_or_default(),
+                    inline: options.inline.unwrap_or_default(),
+                    mark_pure_constructors: options.mark_pure_constructors.unwrap_or_default(),
+                    stringify_large_literals: options.stringify_large_literals.is_some(),
+                    stringify_threshold: options
+                        .stringify_large_literals
+                        .as_ref()
+                        .and_then(|v| v.threshold)
+                        .map_or(oveo::DEFAULT_STRINGIFY_THRESHOLD, |v| v as usize),
                     url: options.url.map(|o| o.base_url),
+                    preserve_comments,
+                    debug_comments: options.debug_comments.unwrap_or_default(),
+                    debug_names: options.debug_names.unwrap_or_default(),
+                    keep_annotations: options.keep_annotations.unwrap_or_default(),
                 },
                 pattern,
             )
@@ -108,16 +348,42 @@ impl Optimizer {
         Ok(Self {
             inner: Arc::new(OptimizerState {
                 options,
+                single_phase,
                 externs: RwLock::new(ExternMap::new()),
-                property_map: RwLock::new(PropertyMap::new(pattern)),
+                property_map: RwLock::new(
+                    PropertyMap::new(pattern)
+                        .with_deterministic(deterministic)
+                        .with_reserved(reserved)
+                        .with_keep_names(keep_names)
+                        .with_naming(naming)
+                        .with_max_mangled_len(max_mangled_len),
+                ),
             }),
         })
     }
 
+    /// Imports externs and merges them into already imported externs, exports
+    /// of modules that were already imported are merged and exports from
+    /// `data` win on conflicts. Use `clear_externs()` to reset imported
+    /// externs.
     #[napi]
     pub fn import_externs(&mut self, data: &[u8]) -> Result<()> {
         let mut externs = self.inner.externs.write().unwrap();
-        externs.import_from_json(data).map_err(|err| Error::from_reason(err.to_string()))
+        externs.merge_from_json(data).map_err(|err| Error::from_reason(err.to_string()))
+    }
+
+    /// Same as `import_externs()`.
+    #[napi]
+    pub fn merge_externs(&mut self, data: &[u8]) -> Result<()> {
+        let mut externs = self.inner.externs.write().unwrap();
+        externs.merge_from_json(data).map_err(|err| Error::from_reason(err.to_string()))
+    }
+
+    /// Removes all imported externs, e.g. when an externs file is changed in
+    /// watch mode. Intrinsic functions from the `oveo` module are preserved.
+    #[napi]
+    pub fn clear_externs(&mut self) {
+        *self.inner.externs.write().unwrap() = ExternMap::new();
     }
 
     #[napi]
@@ -131,28 +397,238 @@ impl Optimizer {
         Ok(())
     }
 
+    /// Removes all renamed properties, e.g. before a full rebuild in watch
+    /// mode.
+    #[napi]
+    pub fn reset_property_map(&mut self) {
+        self.inner.property_map.write().unwrap().reset();
+    }
+
+    /// Assigns names to properties that were renamed in deterministic mode.
+    #[napi]
+    pub fn finalize_property_map(&mut self) {
+        self.inner.property_map.write().unwrap().finalize();
+    }
+
+    /// Replaces property name placeholders in the chunk output, it should be
+    /// invoked after `finalizePropertyMap()`.
+    #[napi]
+    pub fn finalize_chunk(
+        &self,
+        env: Env,
+        source_text: String,
+        map: String,
+    ) -> Result<OptimizerOutput> {
+        let property_map = self.inner.property_map.read().unwrap();
+        finalize_chunk(&source_text, &map, &property_map)
+            .map(into_output)
+            .map_err(|err| into_error(&env, err))
+    }
+
+    /// Statistics of renamed properties, it can be used to estimate savings
+    /// of a rename pattern.
+    #[napi]
+    pub fn property_map_stats(&self) -> PropertyMapStats {
+        let stats = self.inner.property_map.read().unwrap().stats();
+        let average = |len: usize| {
+            if stats.count == 0 { 0.0 } else { len as f64 / stats.count as f64 }
+        };
+        PropertyMapStats {
+            count: stats.count as u32,
+            average_original_len: average(stats.original_len),
+            average_mangled_len: average(stats.mangled_len),
+            skipped: stats.skipped as u32,
+        }
+    }
+
     #[napi]
     pub fn update_property_map(&mut self) -> Option<Uint8Array> {
         let map = self.inner.property_map.read().unwrap();
         if map.is_dirty() { Some(map.export().into()) } else { None }
     }
 
+    /// Adds properties that would be renamed in the chunk to the property
+    /// map without renaming them and returns the updated property map.
+    ///
+    /// It can be used to build a complete property map from all chunks before
+    /// they are processed with `renderChunk()`.
+    #[napi]
+    pub fn collect_properties(&self, env: Env, source_text: String) -> Result<Uint8Array> {
+        let property_map = self.inner.property_map.read().unwrap();
+        collect_properties(&source_text, &self.inner.options, &property_map)
+            .map_err(|err| into_error(&env, err))?;
+        Ok(property_map.export().into())
+    }
+
     #[napi(ts_return_type = "Promise<OptimizerOutput>")]
     pub fn transform(
         &self,
         source_text: String,
         module_type: String,
+        options: Option<TransformOptions>,
     ) -> AsyncTask<TransformModuleTask> {
         AsyncTask::new(TransformModuleTask {
             optimizer: Arc::clone(&self.inner),
             source_text,
             module_type,
+            options,
         })
     }
 
     #[napi(ts_return_type = "Promise<OptimizerOutput>")]
-    pub fn render_chunk(&self, source_text: String) -> AsyncTask<RenderChunkTask> {
-        AsyncTask::new(RenderChunkTask { optimizer: Arc::clone(&self.inner), source_text })
+    pub fn render_chunk(
+        &self,
+        source_text: String,
+        options: Option<RenderChunkOptions>,
+    ) -> AsyncTask<RenderChunkTask> {
+        AsyncTask::new(RenderChunkTask { optimizer: Arc::clone(&self.inner), source_text, options })
+    }
+
+    /// Optimizes a batch of chunks, outputs are in the same order as sources.
+    ///
+    /// Chunks that failed to optimize are returned as errors. `onChunk` is
+    /// invoked as soon as each chunk is optimized and `onWarning` is invoked
+    /// as soon as each warning is reported.
+    #[napi(
+        ts_args_type = "sources: Array<string>, onChunk?: (chunk: RenderedChunk) => void, onWarning?: (warning: ChunkWarning) => void",
+        ts_return_type = "Promise<Array<OptimizerOutput | Error>>"
+    )]
+    pub fn render_chunks(
+        &self,
+        sources: Vec<String>,
+        on_chunk: Option<RenderedChunkCallback>,
+        on_warning: Option<ChunkWarningCallback>,
+    ) -> AsyncTask<RenderChunksTask> {
+        AsyncTask::new(RenderChunksTask {
+            optimizer: Arc::clone(&self.inner),
+            sources,
+            on_chunk,
+            on_warning,
+        })
+    }
+
+    /// Synchronous version of `transform()`.
+    ///
+    /// Module is optimized on the calling thread and it blocks the JS thread
+    /// until optimization is finished.
+    #[napi]
+    pub fn transform_sync(
+        &self,
+        env: Env,
+        source_text: String,
+        module_type: String,
+        options: Option<TransformOptions>,
+    ) -> Result<OptimizerOutput> {
+        self.inner
+            .transform(&source_text, &module_type, options.as_ref())
+            .map_err(|err| into_error(&env, err))
+    }
+
+    /// Synchronous version of `renderChunk()`.
+    ///
+    /// Chunk is optimized on the calling thread and it blocks the JS thread
+    /// until optimization is finished.
+    #[napi]
+    pub fn render_chunk_sync(
+        &self,
+        env: Env,
+        source_text: String,
+        options: Option<RenderChunkOptions>,
+    ) -> Result<OptimizerOutput> {
+        self.inner.render_chunk(&source_text, options.as_ref()).map_err(|err| into_error(&env, err))
+    }
+
+    /// Synchronous version of `renderChunks()`.
+    #[napi(ts_return_type = "Array<OptimizerOutput | Error>")]
+    pub fn render_chunks_sync(
+        &self,
+        env: Env,
+        sources: Vec<String>,
+    ) -> Vec<Either<OptimizerOutput, Error>> {
+        into_outputs(&env, self.inner.render_chunks(&sources, None, None))
+    }
+}
+
+impl OptimizerState {
+    fn transform(
+        &self,
+        source_text: &str,
+        module_type: &str,
+        options: Option<&TransformOptions>,
+    ) -> std::result::Result<OptimizerOutput, oveo::OptimizerError> {
+        let on_warning = options.and_then(|v| v.on_warning.as_ref()).map(warning_sink);
+        let source = oveo::SourceOptions {
+            file_name: options.and_then(|v| v.file_name.as_deref()).unwrap_or_default(),
+            input_map: options.and_then(|v| v.input_source_map.as_deref()),
+            on_warning: on_warning.as_ref().map(|v| v as &dyn Fn(&str)),
+        };
+        let externs = self.externs.read().unwrap();
+        if self.single_phase {
+            let property_map = self.property_map.read().unwrap();
+            optimize_module_single_phase(
+                source_text,
+                module_type,
+                &self.options,
+                &externs,
+                &property_map,
+                &source,
+            )
+            .map(into_output)
+        } else {
+            optimize_module(source_text, module_type, &self.options, &externs, &source)
+                .map(into_output)
+        }
+    }
+
+    fn render_chunk(
+        &self,
+        source_text: &str,
+        options: Option<&RenderChunkOptions>,
+    ) -> std::result::Result<OptimizerOutput, oveo::OptimizerError> {
+        let on_warning = options.and_then(|v| v.on_warning.as_ref()).map(warning_sink);
+        let source = oveo::SourceOptions {
+            file_name: options.and_then(|v| v.file_name.as_deref()).unwrap_or_default(),
+            input_map: options.and_then(|v| v.input_source_map.as_deref()),
+            on_warning: on_warning.as_ref().map(|v| v as &dyn Fn(&str)),
+        };
+        let property_map = self.property_map.read().unwrap();
+        optimize_chunk(source_text, &self.options, &property_map, &source).map(into_output)
+    }
+
+    fn render_chunks(
+        &self,
+        sources: &[String],
+        on_chunk: Option<&RenderedChunkCallback>,
+        on_warning: Option<&ChunkWarningCallback>,
+    ) -> Vec<std::result::Result<OptimizerOutput, oveo::OptimizerError>> {
+        let property_map = self.property_map.read().unwrap();
+        optimize_chunks_with(
+            sources,
+            &self.options,
+            &property_map,
+            |index, message| {
+                if let Some(on_warning) = on_warning {
+                    let warning =
+                        ChunkWarning { index: index as u32, message: message.to_string() };
+                    on_warning.call(warning, ThreadsafeFunctionCallMode::NonBlocking);
+                }
+            },
+            |index, output| {
+                if let Some(on_chunk) = on_chunk {
+                    let chunk = RenderedChunk {
+                        index: index as u32,
+                        error: output.as_ref().err().map(ToString::to_string),
+                        warnings: output
+                            .as_ref()
+                            .map_or_else(|_| Vec::new(), |v| v.warnings.clone()),
+                    };
+                    on_chunk.call(chunk, ThreadsafeFunctionCallMode::NonBlocking);
+                }
+            },
+        )
+        .into_iter()
+        .map(|output| output.map(into_output))
+        .collect()
     }
 }
 
@@ -160,41 +636,126 @@ pub struct TransformModuleTask {
     optimizer: Arc<OptimizerState>,
     source_text: String,
     module_type: String,
+    options: Option<TransformOptions>,
 }
 
 impl Task for TransformModuleTask {
-    type Output = OptimizerOutput;
+    type Output = std::result::Result<OptimizerOutput, oveo::OptimizerError>;
     type JsValue = OptimizerOutput;
 
     fn compute(&mut self) -> Result<Self::Output> {
-        let externs = self.optimizer.externs.read().unwrap();
-        optimize_module(&self.source_text, &self.module_type, &self.optimizer.options, &externs)
-            .map(|v| OptimizerOutput { code: v.code, map: v.map })
-            .map_err(|err| Error::from_reason(err.to_string()))
+        Ok(self.optimizer.transform(&self.source_text, &self.module_type, self.options.as_ref()))
     }
 
-    fn resolve(&mut self, _env: Env, output: OptimizerOutput) -> Result<Self::JsValue> {
-        Ok(output)
+    fn resolve(&mut self, env: Env, output: Self::Output) -> Result<Self::JsValue> {
+        output.map_err(|err| into_error(&env, err))
     }
 }
 
 pub struct RenderChunkTask {
     optimizer: Arc<OptimizerState>,
     source_text: String,
+    options: Option<RenderChunkOptions>,
 }
 
 impl Task for RenderChunkTask {
-    type Output = OptimizerOutput;
+    type Output = std::result::Result<OptimizerOutput, oveo::OptimizerError>;
     type JsValue = OptimizerOutput;
 
     fn compute(&mut self) -> Result<Self::Output> {
-        let property_map = self.optimizer.property_map.read().unwrap();
-        optimize_chunk(&self.source_text, &self.optimizer.options, &property_map)
-            .map(|v| OptimizerOutput { code: v.code, map: v.map })
-            .map_err(|err| Error::from_reason(err.to_string()))
+        Ok(self.optimizer.render_chunk(&self.source_text, self.options.as_ref()))
+    }
+
+    fn resolve(&mut self, env: Env, output: Self::Output) -> Result<Self::JsValue> {
+        output.map_err(|err| into_error(&env, err))
+    }
+}
+
+pub struct RenderChunksTask {
+    optimizer: Arc<OptimizerState>,
+    sources: Vec<String>,
+    on_chunk: Option<RenderedChunkCallback>,
+    on_warning: Option<ChunkWarningCallback>,
+}
+
+impl Task for RenderChunksTask {
+    type Output = Vec<std::result::Result<OptimizerOutput, oveo::OptimizerError>>;
+    type JsValue = Vec<Either<OptimizerOutput, Error>>;
+
+    fn compute(&mut self) -> Result<Self::Output> {
+        Ok(self.optimizer.render_chunks(
+            &self.sources,
+            self.on_chunk.as_ref(),
+            self.on_warning.as_ref(),
+        ))
+    }
+
+    fn resolve(&mut self, env: Env, output: Self::Output) -> Result<Self::JsValue> {
+        Ok(into_outputs(&env, output))
+    }
+}
+
+/// Forwards warnings to a JS callback, callback is invoked on the JS thread
+/// after the warning is reported.
+fn warning_sink(callback: &WarningCallback) -> impl Fn(&str) + '_ {
+    |message| {
+        callback.call(message.to_string(), ThreadsafeFunctionCallMode::NonBlocking);
     }
+}
+
+fn into_output(output: oveo::OptimizerOutput) -> OptimizerOutput {
+    OptimizerOutput {
+        code: output.code,
+        map: output.map,
+        stats: OptimizerStats {
+            used_externs: output
+                .stats
+                .used_externs
+                .into_iter()
+                .map(|(module, export)| UsedExtern { module, export })
+                .collect(),
+            hoist_log: output.stats.hoist_log,
+            duplicates_collapsed: output.stats.duplicates_collapsed,
+            globals_hoisted: output.stats.globals_hoisted,
+            properties_renamed: output.stats.properties_renamed,
+        },
+        warnings: output.warnings,
+    }
+}
+
+/// Converts batch results into outputs and JS errors.
+fn into_outputs(
+    env: &Env,
+    outputs: Vec<std::result::Result<OptimizerOutput, oveo::OptimizerError>>,
+) -> Vec<Either<OptimizerOutput, Error>> {
+    outputs
+        .into_iter()
+        .map(|output| match output {
+            Ok(output) => Either::A(output),
+            Err(err) => Either::B(into_error(env, err)),
+        })
+        .collect()
+}
 
-    fn resolve(&mut self, _env: Env, output: OptimizerOutput) -> Result<Self::JsValue> {
-        Ok(output)
+/// Converts optimizer errors into JS errors, syntax errors are extended with
+/// `offset`, `line`, `column` and `source` properties.
+fn into_error(env: &Env, err: oveo::OptimizerError) -> Error {
+    let reason = err.to_string();
+    let Some(location) = err.location() else {
+        return Error::from_reason(reason);
+    };
+    let mut obj = match env.create_error(Error::from_reason(reason)) {
+        Ok(obj) => obj,
+        Err(err) => return err,
+    };
+    let result = (|| {
+        obj.set_named_property("offset", location.offset)?;
+        obj.set_named_property("line", location.line)?;
+        obj.set_named_property("column", location.column)?;
+        obj.set_named_property("source", location.source.as_str())
+    })();
+    match result {
+        Ok(()) => Error::from(obj.to_unknown()),
+        Err(err) => err,
     }
 }
@@ -4,7 +4,16 @@ import { Optimizer, type OptimizerOptions } from '@oveo/optimizer';
 export interface PluginOptions extends OptimizerOptions {
   readonly filter?: HookFilter;
   readonly externs?: { inlineConstValues?: boolean; import?: string[] };
-  readonly renameProperties?: { pattern?: string; map?: string };
+  readonly renameProperties?: {
+    pattern?: string;
+    map?: string;
+    keyObjectsOnly?: boolean;
+    deterministic?: boolean;
+    reserved?: string[];
+    keepNames?: string[];
+    naming?: 'sequential' | 'hash';
+    maxMangledLen?: number;
+  };
 }
 
 export function oveo(options: PluginOptions = {}): RolldownPlugin & { apply?: 'build' } {
@@ -43,7 +52,7 @@ export function oveo(options: PluginOptions = {}): RolldownPlugin & { apply?: 'b
             this.addWatchFile(resolved.id);
             try {
               const data = await this.fs.readFile(resolved.id);
-              opt.importExterns(data);
+              opt.mergeExterns(data);
             } catch (err) {
               this.warn(`Unable to import extern file '${extern}': ${String(err)}`);
             }
@@ -61,6 +70,9 @@ export function oveo(options: PluginOptions = {}): RolldownPlugin & { apply?: 'b
       async handler(code, id, { moduleType }) {
         try {
           const result = await opt.transform(code, moduleType);
+          for (const warning of result.warnings) {
+            this.warn(warning);
+          }
           const map = result.map;
           code = result.code;
           return map ? { code, map } : { code };
@@ -74,6 +86,9 @@ export function oveo(options: PluginOptions = {}): RolldownPlugin & { apply?: 'b
       async handler(code) {
         try {
           const result = await opt.renderChunk(code);
+          for (const warning of result.warnings) {
+            this.warn(warning);
+          }
           const map = result.map;
           code = result.code;
           return map ? { code, map } : { code };
@@ -83,6 +98,26 @@ export function oveo(options: PluginOptions = {}): RolldownPlugin & { apply?: 'b
       },
     },
 
+    generateBundle(_, bundle) {
+      // Replaces placeholders when property names are assigned in a sorted order.
+      if (options.renameProperties?.deterministic) {
+        opt.finalizePropertyMap();
+        for (const chunk of Object.values(bundle)) {
+          if (chunk.type === 'chunk') {
+            try {
+              const result = opt.finalizeChunk(chunk.code, chunk.map?.toString() ?? '');
+              chunk.code = result.code;
+              if (chunk.map && result.map) {
+                Object.assign(chunk.map, JSON.parse(result.map));
+              }
+            } catch (err) {
+              this.error(`Unable to finalize chunk file '${chunk.fileName}': ${String(err)}`);
+            }
+          }
+        }
+      }
+    },
+
     async writeBundle() {
       const propertyMap = options.renameProperties?.map;
       // If minified names are generated dynamically
@@ -1,18 +1,21 @@
 /**
- * Hoists expression to the outermost Hoisting Scope.
+ * Hoists expression to the outermost Hoisting Scope, or to the closest
+ * Hoisting Scope with the same label.
  *
  * @param v Expression.
+ * @param label Hoisting Scope label.
  * @returns Expression annotated for hoisting.
  */
-export function hoist<T>(v: T): T;
+export function hoist<T>(v: T, label?: string): T;
 
 /**
  * Creates a new Hoisting Scope.
  *
  * @param v Internal function.
+ * @param label Hoisting Scope label.
  * @returns Internal function annotated as Hoisting Scope.
  */
-export function scope<T extends Function>(v: T): T;
+export function scope<T extends Function>(v: T, label?: string): T;
 
 /**
  * Annotates expression for deduplication.
@@ -29,3 +32,35 @@ export function dedupe<T>(v: T): T;
  * @returns Renamed property name.
  */
 export function key(v: string): string;
+
+/**
+ * Annotates object literal as an object with renamed property keys.
+ *
+ * @param v Object literal.
+ * @returns Object with renamed property keys.
+ */
+export function keyObject<T extends object>(v: T): T;
+
+/**
+ * Annotates expression as pure, unused pure expressions are removed.
+ *
+ * @param v Expression.
+ * @returns Expression annotated as pure.
+ */
+export function pure<T>(v: T): T;
+
+/**
+ * Inlines a call to a local arrow function.
+ *
+ * @param v Call expression.
+ * @returns Call expression annotated for inlining.
+ */
+export function inline<T>(v: T): T;
+
+/**
+ * Freezes object with `Object.freeze()` and annotates it for deduplication.
+ *
+ * @param v Object.
+ * @returns Frozen object.
+ */
+export function freeze<T>(v: T): Readonly<T>;
@@ -1,7 +1,9 @@
 /**
- * Hoists expression to the outermost Hoisting Scope.
+ * Hoists expression to the outermost Hoisting Scope, or to the closest
+ * Hoisting Scope with the same label.
  *
  * @param v Expression.
+ * @param label Hoisting Scope label.
  * @returns Expression annotated for hoisting.
  */
 export const hoist = (v) => v;
@@ -10,6 +12,7 @@ export const hoist = (v) => v;
  * Creates a new Hoisting Scope.
  *
  * @param v Internal function.
+ * @param label Hoisting Scope label.
  * @returns Internal function annotated as Hoisting Scope.
  */
 export const scope = (v) => v;
@@ -29,3 +32,35 @@ export const dedupe = (v) => v;
  * @returns Renamed property name.
  */
 export const key = (v) => v;
+
+/**
+ * Annotates object literal as an object with renamed property keys.
+ *
+ * @param v Object literal.
+ * @returns Object with renamed property keys.
+ */
+export const keyObject = (v) => v;
+
+/**
+ * Annotates expression as pure, unused pure expressions are removed.
+ *
+ * @param v Expression.
+ * @returns Expression annotated as pure.
+ */
+export const pure = (v) => v;
+
+/**
+ * Inlines a call to a local arrow function.
+ *
+ * @param v Call expression.
+ * @returns Call expression annotated for inlining.
+ */
+export const inline = (v) => v;
+
+/**
+ * Freezes object with `Object.freeze()` and annotates it for deduplication.
+ *
+ * @param v Object.
+ * @returns Frozen object.
+ */
+export const freeze = (v) => Object.freeze(v);
@@ -0,0 +1,53 @@
+import { expect, test } from 'bun:test';
+import { Optimizer } from '@oveo/optimizer';
+
+const optimizer = new Optimizer({
+  globals: { include: ['js'], hoist: true },
+  renameProperties: { pattern: '_$' },
+});
+
+const chunks = [
+  `export function a(o) {\n  return Math.max(o.left_, 1);\n}\n`,
+  `export function b(o) {\n  return Math.min(o.right_, o.left_);\n}\n`,
+];
+
+test('batch/render-chunks', async () => {
+  const results = await optimizer.renderChunks(chunks);
+  expect(results.length).toBe(2);
+  for (let i = 0; i < chunks.length; i++) {
+    expect(results[i]).not.toBeInstanceOf(Error);
+    expect((results[i] as any).code).toBe(optimizer.renderChunkSync(chunks[i]).code);
+  }
+});
+
+test('batch/render-chunks-sync', async () => {
+  const results = optimizer.renderChunksSync(chunks);
+  expect(results.map((r: any) => r.code)).toEqual(
+    (await optimizer.renderChunks(chunks)).map((r: any) => r.code),
+  );
+});
+
+test('batch/errors', async () => {
+  const results = await optimizer.renderChunks([chunks[0], `const b = ;\n`, chunks[1]]);
+  expect(results[0]).not.toBeInstanceOf(Error);
+  expect(results[1]).toBeInstanceOf(Error);
+  expect((results[1] as any).line).toBe(1);
+  expect(results[2]).not.toBeInstanceOf(Error);
+});
+
+test('batch/on-chunk', async () => {
+  const rendered: { index: number; error?: string | null }[] = [];
+  const done = new Promise<void>((resolve) => {
+    optimizer.renderChunks([chunks[0], `const b = ;\n`, chunks[1]], (chunk) => {
+      rendered.push(chunk);
+      if (rendered.length === 3) {
+        resolve();
+      }
+    });
+  });
+  await done;
+  expect(rendered.map((chunk) => chunk.index)).toEqual([0, 1, 2]);
+  expect(rendered[0].error).toBeFalsy();
+  expect(rendered[1].error).toContain('Unable to parse javascript file');
+  expect(rendered[2].error).toBeFalsy();
+});
@@ -0,0 +1,3 @@
+export function fn() {
+	return alloc(16);
+}
@@ -0,0 +1,4 @@
+export function fn() {
+  const size = 16;
+  return alloc(size, size);
+}
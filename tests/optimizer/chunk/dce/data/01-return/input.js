@@ -0,0 +1,8 @@
+export function fn(x) {
+  return inner(x);
+  console.log('unreachable');
+  const y = x + 1;
+  function inner(v) {
+    return v * 2;
+  }
+}
@@ -0,0 +1,6 @@
+export function fn(x) {
+	return inner(x);
+	function inner(v) {
+		return v * 2;
+	}
+}
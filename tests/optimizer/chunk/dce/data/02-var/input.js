@@ -0,0 +1,13 @@
+export function fn(x) {
+  return inner();
+  var a = 1;
+  if (x) {
+    var { b } = x;
+  }
+  (function () {
+    var c = 2;
+  })();
+  function inner() {
+    return a + b;
+  }
+}
@@ -0,0 +1,8 @@
+export function fn(x) {
+	return inner();
+	var a;
+	var b;
+	function inner() {
+		return a + b;
+	}
+}
@@ -0,0 +1,16 @@
+export function fn(items) {
+  for (const item of items) {
+    if (item) {
+      continue;
+      console.log(item);
+    }
+    switch (item) {
+      case 0:
+        throw new Error('zero');
+        break;
+      default:
+        break;
+        item++;
+    }
+  }
+}
@@ -0,0 +1,11 @@
+export function fn(items) {
+	for (const item of items) {
+		if (item) {
+			continue;
+		}
+		switch (item) {
+			case 0: throw new Error("zero");
+			default: break;
+		}
+	}
+}
@@ -0,0 +1,9 @@
+export function fn(x) {
+  return inner(x);
+  const k = 1;
+  let unused = 2;
+  class C {}
+  function inner(v) {
+    return () => v * k + new C();
+  }
+}
@@ -0,0 +1,8 @@
+export function fn(x) {
+	return inner(x);
+	const k = 1;
+	class C {}
+	function inner(v) {
+		return () => v * k + new C();
+	}
+}
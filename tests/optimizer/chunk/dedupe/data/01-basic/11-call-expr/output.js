@@ -1,5 +1,3 @@
-const _DEDUPE_ = x();
-const _DEDUPE_2 = { a: _DEDUPE_ };
-_DEDUPE_2;
-_DEDUPE_2;
+({ a: x() });
+({ a: x() });
 ({ a: y() });
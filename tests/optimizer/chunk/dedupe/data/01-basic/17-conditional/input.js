@@ -0,0 +1,8 @@
+import { dedupe } from "oveo";
+
+dedupe({ a: x ? 1 : "a" });
+dedupe({ a: x ? 1 : "a" });
+dedupe({ a: x ? "a" : 1 });
+dedupe({ a: x ? 1 : y() });
+dedupe({ a: x ? 1 : (y = 1) });
+dedupe({ a: x ? 1 : (y = 1) });
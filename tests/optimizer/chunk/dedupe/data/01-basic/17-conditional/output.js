@@ -0,0 +1,8 @@
+const _DEDUPE_ = x ? 1 : "a";
+const _DEDUPE_2 = { a: _DEDUPE_ };
+_DEDUPE_2;
+_DEDUPE_2;
+({ a: x ? "a" : 1 });
+({ a: x ? 1 : y() });
+({ a: x ? 1 : y = 1 });
+({ a: x ? 1 : y = 1 });
@@ -0,0 +1,2 @@
+sideEffect();
+sideEffect();
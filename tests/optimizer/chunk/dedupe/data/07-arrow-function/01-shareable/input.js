@@ -0,0 +1,5 @@
+import { dedupe } from "oveo";
+
+export function handlers() {
+  return [dedupe((x) => x * 2), dedupe((y) => y * 2), dedupe(() => {}), dedupe(() => {})];
+}
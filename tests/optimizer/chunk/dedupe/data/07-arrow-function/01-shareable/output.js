@@ -0,0 +1,10 @@
+export function handlers() {
+	const _DEDUPE_ = (x) => x * 2;
+	const _DEDUPE_2 = () => {};
+	return [
+		_DEDUPE_,
+		_DEDUPE_,
+		_DEDUPE_2,
+		_DEDUPE_2
+	];
+}
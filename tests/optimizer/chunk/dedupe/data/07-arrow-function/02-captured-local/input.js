@@ -0,0 +1,5 @@
+import { dedupe } from "oveo";
+
+export function handlers(k) {
+  return [dedupe((x) => x + k), dedupe((x) => x + k)];
+}
@@ -0,0 +1,3 @@
+export function handlers(k) {
+	return [(x) => x + k, (x) => x + k];
+}
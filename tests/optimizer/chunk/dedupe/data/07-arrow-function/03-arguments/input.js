@@ -0,0 +1,5 @@
+import { dedupe } from "oveo";
+
+export function handlers() {
+  return [dedupe(() => arguments.length), dedupe(() => arguments.length)];
+}
@@ -0,0 +1,3 @@
+export function handlers() {
+	return [() => arguments.length, () => arguments.length];
+}
@@ -0,0 +1,6 @@
+import { dedupe } from "oveo";
+
+dedupe({ a: /* @__PURE__ */ x() });
+dedupe([/* @__PURE__ */ x()]);
+dedupe({ a: y() });
+dedupe([y()]);
@@ -0,0 +1,5 @@
+const _DEDUPE_ = /* @__PURE__ */ x();
+({ a: _DEDUPE_ });
+[_DEDUPE_];
+({ a: y() });
+[y()];
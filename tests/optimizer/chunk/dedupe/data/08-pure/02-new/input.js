@@ -0,0 +1,6 @@
+import { dedupe } from "oveo";
+
+dedupe({ a: /* @__PURE__ */ new X() });
+dedupe([/* @__PURE__ */ new X()]);
+dedupe({ a: new Y() });
+dedupe({ a: new Y() });
@@ -0,0 +1,5 @@
+const _DEDUPE_ = /* @__PURE__ */ new X();
+({ a: _DEDUPE_ });
+[_DEDUPE_];
+({ a: new Y() });
+({ a: new Y() });
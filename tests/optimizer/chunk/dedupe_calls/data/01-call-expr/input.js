@@ -0,0 +1,5 @@
+import { dedupe } from "oveo";
+
+dedupe({ a: x() });
+dedupe({ a: x() });
+dedupe({ a: y() });
@@ -0,0 +1,4 @@
+const _DEDUPE_ = { a: x() };
+_DEDUPE_;
+_DEDUPE_;
+({ a: y() });
@@ -0,0 +1,4 @@
+import { dedupe } from "oveo";
+
+dedupe(sideEffect());
+dedupe(sideEffect());
@@ -0,0 +1,3 @@
+const _DEDUPE_ = sideEffect();
+_DEDUPE_;
+_DEDUPE_;
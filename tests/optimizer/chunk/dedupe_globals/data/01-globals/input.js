@@ -0,0 +1,11 @@
+import { dedupe } from "oveo";
+
+export const a = dedupe({ n: Number, max: Math.max, list: [1, 2] });
+export const b = dedupe({ n: Number, max: Math.max, list: [1, 2] });
+export function f() {
+  const c = dedupe({ n: Number, max: Math.max, list: [1, 2] });
+  return [c, dedupe([Number.MAX_SAFE_INTEGER])];
+}
+export function g() {
+  return dedupe([Number.MAX_SAFE_INTEGER]);
+}
@@ -0,0 +1,19 @@
+const _GLOBAL_ = Number;
+const _GLOBAL_2 = Math;
+const _GLOBAL_3 = _GLOBAL_2.max;
+const _GLOBAL_4 = _GLOBAL_.MAX_SAFE_INTEGER;
+const _DEDUPE_ = [1, 2];
+const _DEDUPE_2 = {
+	n: _GLOBAL_,
+	max: _GLOBAL_3,
+	list: _DEDUPE_
+};
+export const a = _DEDUPE_2;
+export const b = _DEDUPE_2;
+export function f() {
+	const c = _DEDUPE_2;
+	return [c, [_GLOBAL_4]];
+}
+export function g() {
+	return [_GLOBAL_4];
+}
@@ -0,0 +1,11 @@
+import { dedupe, hoist } from "oveo";
+
+export function f() {
+  const enc = new TextEncoder();
+  return hoist({ enc: Math.floor, v: [1] });
+}
+export function g() {
+  const enc = new TextEncoder();
+  return hoist({ enc: Math.floor, v: [1] });
+}
+export const x = dedupe([Math.floor]);
@@ -0,0 +1,20 @@
+const _GLOBAL_ = Math;
+const _GLOBAL_2 = _GLOBAL_.floor;
+const _GLOBAL_3 = TextEncoder;
+const _SINGLETON_ = new _GLOBAL_3();
+const _DEDUPE_ = [1];
+const _DEDUPE_2 = {
+	enc: _GLOBAL_2,
+	v: _DEDUPE_
+};
+const _HOISTED_ = _DEDUPE_2;
+export function f() {
+	const enc = _SINGLETON_;
+	return _HOISTED_;
+}
+const _HOISTED_2 = _DEDUPE_2;
+export function g() {
+	const enc = _SINGLETON_;
+	return _HOISTED_2;
+}
+export const x = [_GLOBAL_2];
@@ -0,0 +1,5 @@
+import { dedupe, pure } from "oveo";
+
+pure(dedupe({ n: Number }));
+export const a = dedupe({ n: Number });
+export const b = dedupe({ n: Number });
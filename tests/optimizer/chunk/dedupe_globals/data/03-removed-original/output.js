@@ -0,0 +1,4 @@
+const _GLOBAL_ = Number;
+const _DEDUPE_ = { n: _GLOBAL_ };
+export const a = _DEDUPE_;
+export const b = _DEDUPE_;
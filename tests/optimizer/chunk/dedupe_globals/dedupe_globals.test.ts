@@ -0,0 +1,28 @@
+import { expect, test } from 'bun:test';
+import { readdir } from 'node:fs/promises';
+import * as path from 'node:path';
+import { Optimizer } from '@oveo/optimizer';
+
+import { normalizeNewlines } from '../../normalize.js';
+
+const optimizer = new Optimizer({
+  hoist: true,
+  dedupe: true,
+  pure: true,
+  globals: { include: ['js', 'web'], hoist: true, singletons: true },
+});
+
+const units = path.join(import.meta.dir, 'data');
+const entries = await readdir(units, { recursive: true });
+for (const entry of entries) {
+  try {
+    const input = await Bun.file(path.join(units, entry, 'input.js')).text();
+
+    test(`chunk/dedupe_globals/${entry}`, async () => {
+      const output = Bun.file(path.join(units, entry, 'output.js'));
+      const moduleResult = await optimizer.transform(input, 'js');
+      const chunkResult = await optimizer.renderChunk(moduleResult.code);
+      expect(normalizeNewlines(chunkResult.code)).toBe(normalizeNewlines(await output.text()));
+    });
+  } catch {}
+}
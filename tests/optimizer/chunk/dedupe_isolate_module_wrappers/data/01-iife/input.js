@@ -0,0 +1,14 @@
+import { dedupe } from "oveo";
+
+const shared = dedupe([1, 2]);
+(function () {
+  const a = dedupe([1, 2]);
+  const b = dedupe([3, 4]);
+  const c = dedupe([3, 4]);
+  console.log(a, b, c);
+})();
+(() => {
+  const a = dedupe([1, 2]);
+  const b = dedupe([3, 4]);
+  console.log(a, b, shared);
+})();
@@ -0,0 +1,13 @@
+const shared = [1, 2];
+(function() {
+	const a = [1, 2];
+	const _DEDUPE_ = [3, 4];
+	const b = _DEDUPE_;
+	const c = _DEDUPE_;
+	console.log(a, b, c);
+})();
+(() => {
+	const a = [1, 2];
+	const b = [3, 4];
+	console.log(a, b, shared);
+})();
@@ -0,0 +1,8 @@
+import { dedupe } from "oveo";
+
+function a() {
+  return dedupe({ theme: "dark", size: 12 });
+}
+function b() {
+  return dedupe({ theme: "dark", size: 12 });
+}
@@ -0,0 +1,10 @@
+const _DEDUPE_ = {
+	theme: "dark",
+	size: 12
+};
+function a() {
+	return _DEDUPE_;
+}
+function b() {
+	return _DEDUPE_;
+}
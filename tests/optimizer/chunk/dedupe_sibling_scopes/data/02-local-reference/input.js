@@ -0,0 +1,8 @@
+import { dedupe } from "oveo";
+
+function a(x) {
+  return dedupe({ value: x, size: 12 });
+}
+function b(x) {
+  return dedupe({ value: x, size: 12 });
+}
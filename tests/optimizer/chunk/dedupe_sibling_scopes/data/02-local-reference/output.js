@@ -0,0 +1,12 @@
+function a(x) {
+	return {
+		value: x,
+		size: 12
+	};
+}
+function b(x) {
+	return {
+		value: x,
+		size: 12
+	};
+}
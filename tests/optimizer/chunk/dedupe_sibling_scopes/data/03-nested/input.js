@@ -0,0 +1,10 @@
+import { dedupe } from "oveo";
+
+function a() {
+  dedupe("long string literal value");
+  return dedupe({ s: "long string literal value" });
+}
+function b() {
+  dedupe("long string literal value");
+  return dedupe({ s: "long string literal value" });
+}
@@ -0,0 +1,10 @@
+const _DEDUPE_ = "long string literal value";
+const _DEDUPE_2 = { s: _DEDUPE_ };
+function a() {
+	_DEDUPE_;
+	return _DEDUPE_2;
+}
+function b() {
+	_DEDUPE_;
+	return _DEDUPE_2;
+}
@@ -0,0 +1,5 @@
+import { freeze } from "oveo";
+
+export const a = freeze({ a: 1 });
+export const b = freeze({ a: 1 });
+export const c = freeze({ a: 2 });
@@ -0,0 +1,7 @@
+const _GLOBAL_ = Object;
+const _GLOBAL_2 = _GLOBAL_.freeze;
+const _DEDUPE_ = { a: 1 };
+const _DEDUPE_2 = _GLOBAL_2(_DEDUPE_);
+export const a = _DEDUPE_2;
+export const b = _DEDUPE_2;
+export const c = _GLOBAL_2({ a: 2 });
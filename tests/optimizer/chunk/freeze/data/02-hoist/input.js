@@ -0,0 +1,9 @@
+import { freeze, hoist } from "oveo";
+
+export function f() {
+  return hoist(freeze({ a: 1 }));
+}
+
+export function g() {
+  return hoist(freeze({ a: 1 }));
+}
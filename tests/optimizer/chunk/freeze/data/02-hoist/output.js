@@ -0,0 +1,12 @@
+const _GLOBAL_ = Object;
+const _GLOBAL_2 = _GLOBAL_.freeze;
+const _DEDUPE_ = { a: 1 };
+const _DEDUPE_2 = _GLOBAL_2(_DEDUPE_);
+const _HOISTED_ = _DEDUPE_2;
+export function f() {
+	return _HOISTED_;
+}
+const _HOISTED_2 = _DEDUPE_2;
+export function g() {
+	return _HOISTED_2;
+}
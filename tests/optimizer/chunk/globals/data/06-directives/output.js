@@ -0,0 +1,8 @@
+#!/usr/bin/env node
+"use strict";
+import { a } from "a";
+const _GLOBAL_ = Math;
+const _GLOBAL_2 = _GLOBAL_.random;
+function test() {
+	return _GLOBAL_2() + a;
+}
@@ -0,0 +1,11 @@
+export function updateBadge(count) {
+  if (count > 0) {
+    navigator.setAppBadge(count);
+  } else {
+    navigator.clearAppBadge();
+  }
+}
+
+export function resetBadge() {
+  navigator.setAppBadge(0);
+}
@@ -0,0 +1,13 @@
+const _GLOBAL_ = navigator;
+const _GLOBAL_2 = _GLOBAL_.setAppBadge;
+const _GLOBAL_3 = _GLOBAL_.clearAppBadge;
+export function updateBadge(count) {
+	if (count > 0) {
+		_GLOBAL_2(count);
+	} else {
+		_GLOBAL_3();
+	}
+}
+export function resetBadge() {
+	_GLOBAL_2(0);
+}
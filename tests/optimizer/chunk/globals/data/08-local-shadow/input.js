@@ -0,0 +1,19 @@
+import { x } from "x";
+export function load(url) {
+  return fetch(url);
+}
+const fetch = window.fetch;
+export function get() {
+  return fetch(x);
+}
+export function max(a, b) {
+  const Math = { max: (a, b) => a };
+  return Math.max(a, b);
+}
+export function min(a, b) {
+  return Math.min(a, b);
+}
+let JSON = null;
+export function parse(s) {
+  return JSON.parse(s);
+}
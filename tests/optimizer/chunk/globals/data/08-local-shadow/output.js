@@ -0,0 +1,23 @@
+import { x } from "x";
+const _GLOBAL_ = window;
+const _GLOBAL_2 = _GLOBAL_.fetch;
+const _GLOBAL_3 = Math;
+const _GLOBAL_4 = _GLOBAL_3.min;
+export function load(url) {
+	return fetch(url);
+}
+const fetch = _GLOBAL_2;
+export function get() {
+	return fetch(x);
+}
+export function max(a, b) {
+	const Math = { max: (a, b) => a };
+	return Math.max(a, b);
+}
+export function min(a, b) {
+	return _GLOBAL_4(a, b);
+}
+let JSON = null;
+export function parse(s) {
+	return JSON.parse(s);
+}
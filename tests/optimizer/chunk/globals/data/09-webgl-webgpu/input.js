@@ -0,0 +1,11 @@
+export function isWebGL2(ctx) {
+  return ctx instanceof WebGL2RenderingContext;
+}
+
+export function isGPUDevice(device) {
+  return device instanceof GPUDevice;
+}
+
+export function createBuffer(device, size) {
+  return device.createBuffer({ size, usage: GPUBufferUsage.VERTEX | GPUBufferUsage.COPY_DST });
+}
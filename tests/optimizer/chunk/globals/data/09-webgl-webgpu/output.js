@@ -0,0 +1,15 @@
+const _GLOBAL_ = WebGL2RenderingContext;
+const _GLOBAL_2 = GPUDevice;
+const _GLOBAL_3 = GPUBufferUsage;
+export function isWebGL2(ctx) {
+	return ctx instanceof _GLOBAL_;
+}
+export function isGPUDevice(device) {
+	return device instanceof _GLOBAL_2;
+}
+export function createBuffer(device, size) {
+	return device.createBuffer({
+		size,
+		usage: _GLOBAL_3.VERTEX | _GLOBAL_3.COPY_DST
+	});
+}
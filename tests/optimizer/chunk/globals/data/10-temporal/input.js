@@ -0,0 +1,11 @@
+export function today() {
+  return Temporal.Now.plainDateISO();
+}
+
+export function parseDate(s) {
+  return Temporal.PlainDate.from(s);
+}
+
+export function take(values, n) {
+  return Iterator.from(values).take(n).toArray();
+}
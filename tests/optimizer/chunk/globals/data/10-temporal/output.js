@@ -0,0 +1,16 @@
+const _GLOBAL_ = Temporal;
+const _GLOBAL_2 = _GLOBAL_.Now;
+const _GLOBAL_3 = _GLOBAL_2.plainDateISO;
+const _GLOBAL_4 = _GLOBAL_.PlainDate;
+const _GLOBAL_5 = _GLOBAL_4.from;
+const _GLOBAL_6 = Iterator;
+const _GLOBAL_7 = _GLOBAL_6.from;
+export function today() {
+	return _GLOBAL_3();
+}
+export function parseDate(s) {
+	return _GLOBAL_5(s);
+}
+export function take(values, n) {
+	return _GLOBAL_7(values).take(n).toArray();
+}
@@ -0,0 +1,10 @@
+export function startWorker(url) {
+  return new Worker(url, { type: "module" });
+}
+
+export function notify(title) {
+  if (Notification.permission === "granted") {
+    return new Notification(title);
+  }
+  return Notification.requestPermission();
+}
@@ -0,0 +1,12 @@
+const _GLOBAL_ = Worker;
+const _GLOBAL_2 = Notification;
+const _GLOBAL_3 = _GLOBAL_2.requestPermission;
+export function startWorker(url) {
+	return new _GLOBAL_(url, { type: "module" });
+}
+export function notify(title) {
+	if (_GLOBAL_2.permission === "granted") {
+		return new _GLOBAL_2(title);
+	}
+	return _GLOBAL_3();
+}
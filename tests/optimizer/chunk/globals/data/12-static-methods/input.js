@@ -0,0 +1,19 @@
+export function fetchWithTimeout(url, signal) {
+  return fetch(url, { signal: AbortSignal.any([signal, AbortSignal.timeout(1000)]) });
+}
+
+export function grow(buffer, size) {
+  return ArrayBuffer.prototype.transfer.call(buffer, size);
+}
+
+export function tag(x) {
+  return Object.prototype.toString.call(x);
+}
+
+export function toArray(list) {
+  return Array.prototype.slice.call(list);
+}
+
+export function notFound() {
+  return Response.json({ error: "not found" }, { status: 404 });
+}
@@ -0,0 +1,30 @@
+const _GLOBAL_ = fetch;
+const _GLOBAL_2 = AbortSignal;
+const _GLOBAL_3 = _GLOBAL_2.any;
+const _GLOBAL_4 = _GLOBAL_2.timeout;
+const _GLOBAL_5 = ArrayBuffer;
+const _GLOBAL_6 = _GLOBAL_5.prototype;
+const _GLOBAL_7 = _GLOBAL_6.transfer;
+const _GLOBAL_8 = Object;
+const _GLOBAL_9 = _GLOBAL_8.prototype;
+const _GLOBAL_10 = _GLOBAL_9.toString;
+const _GLOBAL_11 = Array;
+const _GLOBAL_12 = _GLOBAL_11.prototype;
+const _GLOBAL_13 = _GLOBAL_12.slice;
+const _GLOBAL_14 = Response;
+const _GLOBAL_15 = _GLOBAL_14.json;
+export function fetchWithTimeout(url, signal) {
+	return _GLOBAL_(url, { signal: _GLOBAL_3([signal, _GLOBAL_4(1e3)]) });
+}
+export function grow(buffer, size) {
+	return _GLOBAL_7.call(buffer, size);
+}
+export function tag(x) {
+	return _GLOBAL_10.call(x);
+}
+export function toArray(list) {
+	return _GLOBAL_13.call(list);
+}
+export function notFound() {
+	return _GLOBAL_15({ error: "not found" }, { status: 404 });
+}
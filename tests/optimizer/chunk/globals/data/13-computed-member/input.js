@@ -0,0 +1,11 @@
+export function area(r) {
+  return Math.PI * r * r;
+}
+
+export function circumference(r) {
+  return 2 * Math["PI"] * r;
+}
+
+export function local(key) {
+  return [Math[key], Math["E"]];
+}
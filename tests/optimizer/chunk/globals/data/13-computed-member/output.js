@@ -0,0 +1,12 @@
+const _GLOBAL_ = Math;
+const _GLOBAL_2 = _GLOBAL_.PI;
+const _GLOBAL_3 = _GLOBAL_.E;
+export function area(r) {
+	return _GLOBAL_2 * r * r;
+}
+export function circumference(r) {
+	return 2 * _GLOBAL_2 * r;
+}
+export function local(key) {
+	return [_GLOBAL_[key], _GLOBAL_3];
+}
@@ -0,0 +1,7 @@
+export function readDeno(path) {
+  return Deno.readTextFile(path);
+}
+
+export function readBun(path) {
+  return Bun.file(path).text();
+}
@@ -0,0 +1,3 @@
+function test() {
+  return Math.max(Date.now(), Math.random(), Math.abs(-1));
+}
@@ -0,0 +1,7 @@
+const _GLOBAL_ = Math;
+const _GLOBAL_2 = _GLOBAL_.max;
+const _GLOBAL_3 = _GLOBAL_.random;
+const _GLOBAL_4 = _GLOBAL_.abs;
+function test() {
+	return _GLOBAL_2(Date.now(), _GLOBAL_3(), _GLOBAL_4(-1));
+}
@@ -0,0 +1,2 @@
+export const TAU = 2 * Math.PI;
+export const HALF_PI = Math.PI / 2;
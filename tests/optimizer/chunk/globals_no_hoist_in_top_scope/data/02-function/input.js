@@ -0,0 +1,4 @@
+export const TAU = 2 * Math.PI;
+export function area(r) {
+  return Math.PI * r * r;
+}
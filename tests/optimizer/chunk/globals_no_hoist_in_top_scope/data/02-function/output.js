@@ -0,0 +1,6 @@
+const _GLOBAL_ = Math;
+const _GLOBAL_2 = _GLOBAL_.PI;
+export const TAU = 2 * _GLOBAL_2;
+export function area(r) {
+	return _GLOBAL_2 * r * r;
+}
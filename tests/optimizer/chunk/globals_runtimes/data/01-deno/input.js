@@ -0,0 +1,14 @@
+export async function readConfig(path) {
+  return JSON.parse(await Deno.readTextFile(path));
+}
+
+export async function readTemplate(path) {
+  try {
+    return await Deno.readTextFile(path);
+  } catch (err) {
+    if (err instanceof Deno.errors.NotFound) {
+      return Deno.env.get("DEFAULT_TEMPLATE");
+    }
+    throw err;
+  }
+}
@@ -0,0 +1,21 @@
+const _GLOBAL_ = JSON;
+const _GLOBAL_2 = _GLOBAL_.parse;
+const _GLOBAL_3 = Deno;
+const _GLOBAL_4 = _GLOBAL_3.readTextFile;
+const _GLOBAL_5 = _GLOBAL_3.errors;
+const _GLOBAL_6 = _GLOBAL_5.NotFound;
+const _GLOBAL_7 = _GLOBAL_3.env;
+const _GLOBAL_8 = _GLOBAL_7.get;
+export async function readConfig(path) {
+	return _GLOBAL_2(await _GLOBAL_4(path));
+}
+export async function readTemplate(path) {
+	try {
+		return await _GLOBAL_4(path);
+	} catch (err) {
+		if (err instanceof _GLOBAL_6) {
+			return _GLOBAL_8("DEFAULT_TEMPLATE");
+		}
+		throw err;
+	}
+}
@@ -0,0 +1,7 @@
+export function readConfig(path) {
+  return Bun.file(path).json();
+}
+
+export function writeConfig(path, config) {
+  return Bun.write(Bun.file(path), JSON.stringify(config));
+}
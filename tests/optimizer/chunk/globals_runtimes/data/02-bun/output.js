@@ -0,0 +1,11 @@
+const _GLOBAL_ = Bun;
+const _GLOBAL_2 = _GLOBAL_.file;
+const _GLOBAL_3 = _GLOBAL_.write;
+const _GLOBAL_4 = JSON;
+const _GLOBAL_5 = _GLOBAL_4.stringify;
+export function readConfig(path) {
+	return _GLOBAL_2(path).json();
+}
+export function writeConfig(path, config) {
+	return _GLOBAL_3(_GLOBAL_2(path), _GLOBAL_5(config));
+}
@@ -0,0 +1,19 @@
+import { x } from "x";
+export function a(data) {
+  if (Array.isArray(data)) {
+    return Math.max(1, 2);
+  }
+  return () => Math.min(x, 1);
+}
+export function b() {
+  "use strict";
+  function inner() {
+    return new TextEncoder().encode(Array.isArray);
+  }
+  return inner;
+}
+export const c = () => Object.keys(x);
+export const d = () => {
+  return Object.keys(x);
+};
+Object.freeze(x);
@@ -0,0 +1,33 @@
+import { x } from "x";
+const _GLOBAL_9 = Object;
+const _GLOBAL_10 = _GLOBAL_9.keys;
+const _GLOBAL_13 = _GLOBAL_9.freeze;
+export function a(data) {
+	const _GLOBAL_ = Array;
+	const _GLOBAL_2 = _GLOBAL_.isArray;
+	const _GLOBAL_3 = Math;
+	const _GLOBAL_4 = _GLOBAL_3.max;
+	const _GLOBAL_5 = _GLOBAL_3.min;
+	if (_GLOBAL_2(data)) {
+		return _GLOBAL_4(1, 2);
+	}
+	return () => _GLOBAL_5(x, 1);
+}
+export function b() {
+	"use strict";
+	function inner() {
+		const _GLOBAL_6 = TextEncoder;
+		const _SINGLETON_ = new _GLOBAL_6();
+		const _GLOBAL_7 = Array;
+		const _GLOBAL_8 = _GLOBAL_7.isArray;
+		return _SINGLETON_.encode(_GLOBAL_8);
+	}
+	return inner;
+}
+export const c = () => _GLOBAL_10(x);
+export const d = () => {
+	const _GLOBAL_11 = Object;
+	const _GLOBAL_12 = _GLOBAL_11.keys;
+	return _GLOBAL_12(x);
+};
+_GLOBAL_13(x);
@@ -0,0 +1,29 @@
+import { x } from "x";
+const _GLOBAL_ = Array;
+const _GLOBAL_2 = _GLOBAL_.isArray;
+const _GLOBAL_3 = Math;
+const _GLOBAL_4 = _GLOBAL_3.max;
+const _GLOBAL_5 = _GLOBAL_3.min;
+const _GLOBAL_6 = TextEncoder;
+const _SINGLETON_ = new _GLOBAL_6();
+const _GLOBAL_7 = Object;
+const _GLOBAL_8 = _GLOBAL_7.keys;
+const _GLOBAL_9 = _GLOBAL_7.freeze;
+export function a(data) {
+	if (_GLOBAL_2(data)) {
+		return _GLOBAL_4(1, 2);
+	}
+	return () => _GLOBAL_5(x, 1);
+}
+export function b() {
+	"use strict";
+	function inner() {
+		return _SINGLETON_.encode(_GLOBAL_2);
+	}
+	return inner;
+}
+export const c = () => _GLOBAL_8(x);
+export const d = () => {
+	return _GLOBAL_8(x);
+};
+_GLOBAL_9(x);
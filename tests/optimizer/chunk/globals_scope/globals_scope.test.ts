@@ -0,0 +1,28 @@
+import { expect, test } from 'bun:test';
+import { readdir } from 'node:fs/promises';
+import * as path from 'node:path';
+import { Optimizer } from '@oveo/optimizer';
+
+import { normalizeNewlines } from '../../normalize.js';
+
+const root = new Optimizer({ globals: { include: ['js', 'web'], hoist: true, singletons: true } });
+const nearest = new Optimizer({
+  globals: { include: ['js', 'web'], hoist: true, singletons: true, scope: 'nearest' },
+});
+
+const units = path.join(import.meta.dir, 'data');
+const entries = await readdir(units, { recursive: true });
+for (const entry of entries) {
+  try {
+    const input = await Bun.file(path.join(units, entry, 'input.js')).text();
+
+    for (const [name, optimizer] of [['root', root], ['nearest', nearest]] as const) {
+      test(`chunk/globals_scope/${entry}/${name}`, async () => {
+        const output = Bun.file(path.join(units, entry, `output-${name}.js`));
+        const moduleResult = await optimizer.transform(input, 'js');
+        const chunkResult = await optimizer.renderChunk(moduleResult.code);
+        expect(normalizeNewlines(chunkResult.code)).toBe(normalizeNewlines(await output.text()));
+      });
+    }
+  } catch {}
+}
@@ -0,0 +1,8 @@
+import { inline } from "oveo";
+
+const px = (v) => `${v}px`;
+const add = (a, b) => a + b;
+
+export function test(size) {
+  return [inline(px(10)), inline(add(size, 2)), inline(add(1, 2)) * 3];
+}
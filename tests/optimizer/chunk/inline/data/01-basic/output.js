@@ -0,0 +1,9 @@
+const px = (v) => `${v}px`;
+const add = (a, b) => a + b;
+export function test(size) {
+	return [
+		`${10}px`,
+		size + 2,
+		(1 + 2) * 3
+	];
+}
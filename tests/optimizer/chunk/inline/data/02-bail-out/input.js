@@ -0,0 +1,10 @@
+import { inline } from "oveo";
+
+let counter = 0;
+const next = (v) => v + counter;
+const log = (v) => console.log(v);
+const add = (a, b) => a + b;
+
+export function test(size) {
+  return [inline(next(1)), inline(log(1)), inline(add(size(), 1))];
+}
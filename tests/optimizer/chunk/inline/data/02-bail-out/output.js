@@ -0,0 +1,11 @@
+let counter = 0;
+const next = (v) => v + counter;
+const log = (v) => console.log(v);
+const add = (a, b) => a + b;
+export function test(size) {
+	return [
+		next(1),
+		log(1),
+		add(size(), 1)
+	];
+}
@@ -0,0 +1,9 @@
+import { pure } from "oveo";
+import { create } from "./module.js";
+
+pure(create());
+const value = pure(create());
+function test() {
+  pure(create(1));
+  return pure(create(2));
+}
@@ -0,0 +1,5 @@
+import { create } from "./module.js";
+const value = /* @__PURE__ */ create();
+function test() {
+	return /* @__PURE__ */ create(2);
+}
@@ -0,0 +1,11 @@
+new URL("x", "http://h");
+new DecompressionStream("gzip");
+new URL(location.href);
+
+export const a = new URL("x", "http://h");
+export const b = new URLSearchParams("a=1");
+export const c = new WebSocket("ws://h");
+
+function test(URL) {
+  return new URL("x");
+}
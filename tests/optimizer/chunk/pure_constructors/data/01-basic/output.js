@@ -0,0 +1,7 @@
+new URL(location.href);
+export const a = /* @__PURE__ */ new URL("x", "http://h");
+export const b = /* @__PURE__ */ new URLSearchParams("a=1");
+export const c = new WebSocket("ws://h");
+function test(URL) {
+	return new URL("x");
+}
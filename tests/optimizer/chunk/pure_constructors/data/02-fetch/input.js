@@ -0,0 +1,8 @@
+new Headers();
+new Request("/api");
+new Response("ok");
+
+export const a = new Headers();
+export const b = new Request("/api");
+export const c = new Response(null);
+export const d = new Response(body);
@@ -0,0 +1,4 @@
+export const a = /* @__PURE__ */ new Headers();
+export const b = /* @__PURE__ */ new Request("/api");
+export const c = /* @__PURE__ */ new Response(null);
+export const d = new Response(body);
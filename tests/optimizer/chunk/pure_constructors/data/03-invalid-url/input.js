@@ -0,0 +1,6 @@
+new URL("./a");
+new URL("::bad", "also bad");
+new URL("./a", "http://h/b/");
+
+export const a = new URL("./a");
+export const b = new URL("./a", "http://h/b/");
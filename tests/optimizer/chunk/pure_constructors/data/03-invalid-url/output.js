@@ -0,0 +1,4 @@
+new URL("./a");
+new URL("::bad", "also bad");
+export const a = new URL("./a");
+export const b = /* @__PURE__ */ new URL("./a", "http://h/b/");
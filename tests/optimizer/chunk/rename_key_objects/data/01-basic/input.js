@@ -0,0 +1,13 @@
+import { keyObject } from "oveo";
+
+const annotated = keyObject({
+  foo_: 1,
+  bar_() {
+    return 2;
+  },
+});
+const plain = { foo_: 1, baz_: 2 };
+
+export function test(foo_) {
+  return annotated.foo_ + annotated.bar_() + plain.foo_ + plain.baz_ + keyObject({ foo_ });
+}
@@ -0,0 +1,13 @@
+const annotated = {
+	e: 1,
+	t() {
+		return 2;
+	}
+};
+const plain = {
+	foo_: 1,
+	baz_: 2
+};
+export function test(foo_) {
+	return annotated.e + annotated.t() + plain.foo_ + plain.baz_ + { foo_ };
+}
@@ -0,0 +1,10 @@
+import { keyObject } from "oveo";
+
+export function test() {
+  return alias.foo_;
+}
+
+export const obj = keyObject({ foo_: 1, "bar_": 2 });
+const alias = obj;
+Object.defineProperty(obj, "baz_", { value: 3 });
+Object.defineProperty({}, "baz_", { value: 3 });
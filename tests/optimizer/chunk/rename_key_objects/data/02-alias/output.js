@@ -0,0 +1,10 @@
+export function test() {
+	return alias.e;
+}
+export const obj = {
+	e: 1,
+	"bar_": 2
+};
+const alias = obj;
+Object.defineProperty(obj, "t", { value: 3 });
+Object.defineProperty({}, "baz_", { value: 3 });
@@ -0,0 +1,5 @@
+const obj = {};
+Object.defineProperty(obj, "key1_", { value: 1 });
+Reflect.defineProperty(obj, "key2_", { value: 2 });
+Object.defineProperty(obj, "key3", { value: 3 });
+obj.key1_ + obj.key2_ + obj.key3;
@@ -0,0 +1,5 @@
+const obj = {};
+Object.defineProperty(obj, "e", { value: 1 });
+Reflect.defineProperty(obj, "t", { value: 2 });
+Object.defineProperty(obj, "key3", { value: 3 });
+obj.e + obj.t + obj.key3;
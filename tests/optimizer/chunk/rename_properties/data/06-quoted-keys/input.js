@@ -0,0 +1,7 @@
+import { key } from "oveo";
+const obj = {
+  key1_: 1,
+  "key2_": 2,
+  [key("key3_")]: 3,
+};
+obj.key1_ + obj["key2_"] + obj.key3_;
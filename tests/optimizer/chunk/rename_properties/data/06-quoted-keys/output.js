@@ -0,0 +1,6 @@
+const obj = {
+	e: 1,
+	"key2_": 2,
+	["t"]: 3
+};
+obj.e + obj["key2_"] + obj.t;
@@ -0,0 +1,6 @@
+import { key } from "oveo";
+({
+  [key(`key1_`)]: 1,
+  [key("key2_")]: 2,
+  [key(`key3_${x}`)]: 3,
+});
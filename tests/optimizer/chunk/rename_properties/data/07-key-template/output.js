@@ -0,0 +1,5 @@
+({
+	["e"]: 1,
+	["t"]: 2,
+	[`key3_${x}`]: 3
+});
@@ -0,0 +1,30 @@
+class C {
+  get value_() {
+    return this.v_;
+  }
+  set value_(v) {
+    this.v_ = v;
+  }
+  static get default_() {
+    return new C();
+  }
+  accessor count_ = 0;
+  get ["computed_"]() {
+    return 1;
+  }
+  get [name_]() {
+    return 2;
+  }
+}
+
+const o = {
+  get value_() {
+    return 1;
+  },
+  set value_(v) {},
+  get ["computed_"]() {
+    return 2;
+  },
+};
+
+console.log(C.default_.value_, C.default_.count_, o.value_);
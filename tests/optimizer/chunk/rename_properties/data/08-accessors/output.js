@@ -0,0 +1,28 @@
+class C {
+	get e() {
+		return this.t;
+	}
+	set e(v) {
+		this.t = v;
+	}
+	static get n() {
+		return new C();
+	}
+	accessor r = 0;
+	get ["computed_"]() {
+		return 1;
+	}
+	get [name_]() {
+		return 2;
+	}
+}
+const o = {
+	get e() {
+		return 1;
+	},
+	set e(v) {},
+	get ["computed_"]() {
+		return 2;
+	}
+};
+console.log(C.n.e, C.n.r, o.e);
@@ -0,0 +1,17 @@
+export class Counter {
+  #count_ = 0;
+  #step = 1;
+
+  #increment_() {
+    this.#count_ += this.#step;
+  }
+
+  get value_() {
+    this.#increment_();
+    return this.#count_;
+  }
+
+  static isCounter(obj) {
+    return #count_ in obj;
+  }
+}
@@ -0,0 +1,14 @@
+export class Counter {
+	#e = 0;
+	#step = 1;
+	#t() {
+		this.#e += this.#step;
+	}
+	get n() {
+		this.#t();
+		return this.#e;
+	}
+	static isCounter(obj) {
+		return #e in obj;
+	}
+}
@@ -0,0 +1,14 @@
+const foo_ = 1;
+export const o = { foo_ };
+
+export function f(bar_) {
+  return { bar_, baz_: bar_ };
+}
+
+export const { foo_: x, qux_ = 2 } = o;
+
+let a_, b_;
+export function assign(v) {
+  ({ a_, b_ = 3 } = v);
+  return a_ + b_;
+}
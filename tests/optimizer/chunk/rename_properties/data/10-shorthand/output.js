@@ -0,0 +1,14 @@
+const foo_ = 1;
+export const o = { e: foo_ };
+export function f(bar_) {
+	return {
+		t: bar_,
+		n: bar_
+	};
+}
+export const { e: x, r: qux_ = 2 } = o;
+let a_, b_;
+export function assign(v) {
+	({i: a_, a: b_ = 3} = v);
+	return a_ + b_;
+}
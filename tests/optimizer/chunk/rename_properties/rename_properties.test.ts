@@ -37,3 +37,15 @@ for (const entry of entries) {
     });
   } catch {}
 }
+
+test('chunk/rename_properties/dataset', async () => {
+  const optimizer = new Optimizer({ renameProperties: { pattern: '^[a-z]+[A-Z]' } });
+  const result = await optimizer.renderChunk(
+    `export function setUser(el, id) {\n  el.dataset.userId = id;\n  el.userId = id;\n}\n` +
+      `export function getUser(el) {\n  const { userId } = el.dataset;\n  let groupId;\n  ({ groupId } = el.dataset);\n  return [userId, groupId];\n}\n`,
+  );
+  expect(normalizeNewlines(result.code)).toBe(
+    'export function setUser(el, id) {\n\tel.dataset.userId = id;\n\tel.e = id;\n}\n' +
+      'export function getUser(el) {\n\tconst { userId } = el.dataset;\n\tlet groupId;\n\t({groupId} = el.dataset);\n\treturn [userId, groupId];\n}\n',
+  );
+});
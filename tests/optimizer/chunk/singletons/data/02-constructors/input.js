@@ -0,0 +1,15 @@
+function test1() {
+  return {
+    f: new Intl.NumberFormat(),
+    p: new DOMParser(),
+    c: new Intl.Collator(),
+  };
+}
+
+function test2() {
+  return {
+    f: new Intl.NumberFormat(),
+    p: new DOMParser(),
+    e: new Intl.NumberFormat("en-US"),
+  };
+}
@@ -0,0 +1,20 @@
+const _GLOBAL_ = Intl;
+const _GLOBAL_2 = _GLOBAL_.NumberFormat;
+const _SINGLETON_ = new _GLOBAL_2();
+const _GLOBAL_3 = DOMParser;
+const _SINGLETON_2 = new _GLOBAL_3();
+const _GLOBAL_4 = _GLOBAL_.Collator;
+function test1() {
+	return {
+		f: _SINGLETON_,
+		p: _SINGLETON_2,
+		c: new _GLOBAL_4()
+	};
+}
+function test2() {
+	return {
+		f: _SINGLETON_,
+		p: _SINGLETON_2,
+		e: new _GLOBAL_2("en-US")
+	};
+}
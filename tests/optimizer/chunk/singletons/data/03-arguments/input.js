@@ -0,0 +1,6 @@
+function test() {
+  return {
+    a: new TextDecoder(),
+    b: new TextDecoder("utf-16"),
+  };
+}
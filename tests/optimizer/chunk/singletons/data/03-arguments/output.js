@@ -0,0 +1,8 @@
+const _GLOBAL_ = TextDecoder;
+const _SINGLETON_ = new _GLOBAL_();
+function test() {
+	return {
+		a: _SINGLETON_,
+		b: new _GLOBAL_("utf-16")
+	};
+}
@@ -0,0 +1,8 @@
+function test(obj) {
+  return {
+    a: new WeakRef(obj),
+    b: new WeakRef(obj),
+    c: new FinalizationRegistry(),
+    d: new FinalizationRegistry(),
+  };
+}
@@ -0,0 +1,10 @@
+const _GLOBAL_ = WeakRef;
+const _GLOBAL_2 = FinalizationRegistry;
+function test(obj) {
+	return {
+		a: new _GLOBAL_(obj),
+		b: new _GLOBAL_(obj),
+		c: new _GLOBAL_2(),
+		d: new _GLOBAL_2()
+	};
+}
@@ -0,0 +1,23 @@
+export function usd(v) {
+  return new Intl.NumberFormat("en-US", { style: "currency", currency: "USD" }).format(v);
+}
+
+export function usdTotal(v) {
+  return new Intl.NumberFormat("en-US", { style: "currency", currency: "USD" }).format(v);
+}
+
+export function eur(v) {
+  return new Intl.NumberFormat("de-DE", { style: "currency", currency: "EUR" }).format(v);
+}
+
+export function percent(v) {
+  return new Intl.NumberFormat("en-US", { style: "percent" }).format(v);
+}
+
+export function local(v, locale) {
+  return new Intl.NumberFormat(locale).format(v);
+}
+
+export function percentTotal(v) {
+  return new Intl.NumberFormat("en-US", { style: "percent" }).format(v);
+}
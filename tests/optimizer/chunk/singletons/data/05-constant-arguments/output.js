@@ -0,0 +1,28 @@
+const _GLOBAL_ = Intl;
+const _GLOBAL_2 = _GLOBAL_.NumberFormat;
+const _SINGLETON_ = new _GLOBAL_2("en-US", {
+	style: "currency",
+	currency: "USD"
+});
+const _SINGLETON_2 = new _GLOBAL_2("en-US", { style: "percent" });
+export function usd(v) {
+	return _SINGLETON_.format(v);
+}
+export function usdTotal(v) {
+	return _SINGLETON_.format(v);
+}
+export function eur(v) {
+	return new _GLOBAL_2("de-DE", {
+		style: "currency",
+		currency: "EUR"
+	}).format(v);
+}
+export function percent(v) {
+	return _SINGLETON_2.format(v);
+}
+export function local(v, locale) {
+	return new _GLOBAL_2(locale).format(v);
+}
+export function percentTotal(v) {
+	return _SINGLETON_2.format(v);
+}
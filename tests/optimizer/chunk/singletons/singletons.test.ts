@@ -5,7 +5,14 @@ import { Optimizer } from '@oveo/optimizer';
 
 import { normalizeNewlines } from '../../normalize.js';
 
-const optimizer = new Optimizer({ globals: { include: ['js'], hoist: true, singletons: true } });
+const optimizer = new Optimizer({
+  globals: {
+    include: ['js', 'web'],
+    hoist: true,
+    singletons: true,
+    singletonConstructors: ['Intl.NumberFormat', 'DOMParser', 'WeakRef', 'FinalizationRegistry'],
+  },
+});
 
 const units = path.join(import.meta.dir, 'data');
 const entries = await readdir(units, { recursive: true });
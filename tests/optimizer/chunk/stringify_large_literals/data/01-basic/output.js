@@ -0,0 +1,31 @@
+export const data = JSON.parse("{\"name\":\"large\",\"values\":[1,2.5,-3,null,true,false],\"nested\":{\"quoted key\":\"value\",\"items\":[\"a\",\"b\",\"c\"]}}");
+export const small = [
+	1,
+	2,
+	3
+];
+export const withFunction = {
+	items: [
+		1,
+		2,
+		3,
+		4,
+		5,
+		6,
+		7,
+		8,
+		9,
+		10,
+		11,
+		12,
+		13,
+		14,
+		15,
+		16,
+		17,
+		18,
+		19,
+		20
+	],
+	fn() {}
+};
@@ -0,0 +1,7 @@
+function test() {
+  return [
+    new URL("../test.css", import.meta.url).href,
+    new URL(".././test.css", import.meta.url).href,
+    new URL("../../test.css", import.meta.url).href,
+  ];
+}
@@ -0,0 +1,7 @@
+function test() {
+	return [
+		"/test.css",
+		"/test.css",
+		new URL("../../test.css", import.meta.url).href
+	];
+}
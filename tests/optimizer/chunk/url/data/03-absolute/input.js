@@ -0,0 +1,9 @@
+function test() {
+  return [
+    new URL("https://x/y", import.meta.url).href,
+    new URL("data:text/plain,test", import.meta.url).href,
+    new URL("blob:https://x/y", import.meta.url).href,
+    new URL("/test.css", import.meta.url).href,
+    new URL("test.css", import.meta.url).href,
+  ];
+}
@@ -0,0 +1,8 @@
+function test() {
+	return [
+		new URL("/test/test.css", import.meta.url),
+		"/test/test.css",
+		new URL("https://x/y", import.meta.url),
+		new URL("test.css", import.meta.url)
+	];
+}
@@ -0,0 +1,3 @@
+function test() {
+	return new URL("/assets/test.css", import.meta.url);
+}
@@ -0,0 +1,8 @@
+function test() {
+  return [
+    import.meta.resolve("./a.js"),
+    import.meta.resolve("../b.js"),
+    import.meta.resolve("pkg"),
+    import.meta.resolve("./a.js", "x"),
+  ];
+}
@@ -0,0 +1,8 @@
+function test() {
+	return [
+		"/test/a.js",
+		"/b.js",
+		import.meta.resolve("pkg"),
+		import.meta.resolve("./a.js", "x")
+	];
+}
@@ -21,3 +21,14 @@ for (const entry of entries) {
     });
   } catch {}
 }
+
+test(`chunk/url/absolute-base`, async () => {
+  const optimizer = new Optimizer({ globals: { include: ['js'] }, url: { baseURL: 'https://cdn.example.com/a/' } });
+  const input = `function test() {
+  return [new URL("./test.css", import.meta.url), new URL("./test.css", import.meta.url).href];
+}`;
+  const moduleResult = await optimizer.transform(input, 'js');
+  const chunkResult = await optimizer.renderChunk(moduleResult.code);
+  expect(chunkResult.code).toContain(`new URL("https://cdn.example.com/a/test.css")`);
+  expect(chunkResult.code).toContain(`"https://cdn.example.com/a/test.css"`);
+});
@@ -0,0 +1,29 @@
+import { expect, test } from 'bun:test';
+import { Optimizer } from '@oveo/optimizer';
+
+const input = `/*! banner */
+// normal
+export const a = /* @__PURE__ */ f();
+`;
+
+test('comments/all', async () => {
+  const optimizer = new Optimizer({});
+  const result = await optimizer.renderChunk(input);
+  expect(result.code).toContain('/*! banner */');
+  expect(result.code).toContain('// normal');
+});
+
+test('comments/legal', async () => {
+  const optimizer = new Optimizer({ preserveComments: 'legal' });
+  const result = await optimizer.renderChunk(input);
+  expect(result.code).toContain('/*! banner */');
+  expect(result.code).not.toContain('// normal');
+  expect(result.code).toContain('/* @__PURE__ */');
+});
+
+test('comments/none', async () => {
+  const optimizer = new Optimizer({ preserveComments: 'none' });
+  const result = await optimizer.renderChunk(input);
+  expect(result.code).not.toContain('/*! banner */');
+  expect(result.code).toContain('/* @__PURE__ */');
+});
@@ -0,0 +1,39 @@
+import { expect, test } from 'bun:test';
+import { Optimizer } from '@oveo/optimizer';
+
+const input = `
+import { hoist } from "oveo";
+export function a(data) {
+  const x = hoist((c) => c + 1);
+  return Math.max(Array.isArray(data), new TextEncoder(), x);
+}
+`;
+
+const options = {
+  hoist: true,
+  globals: { include: ['js', 'web'], hoist: true, singletons: true },
+};
+
+test('debug_comments/enabled', async () => {
+  const optimizer = new Optimizer({ ...options, debugComments: true });
+  const moduleResult = await optimizer.transform(input, 'js');
+  const chunkResult = await optimizer.renderChunk(moduleResult.code);
+  expect(chunkResult.code).toContain('const _GLOBAL_ = Math; // oveo:global Math\n');
+  expect(chunkResult.code).toContain('const _GLOBAL_2 = _GLOBAL_.max; // oveo:global Math.max\n');
+  expect(chunkResult.code).toContain('// oveo:singleton new TextEncoder()\n');
+  expect(chunkResult.code).toContain('const _HOISTED_ = (c) => c + 1; // oveo:hoist (c) => c + 1\n');
+});
+
+test('debug_comments/disabled', async () => {
+  const optimizer = new Optimizer(options);
+  const moduleResult = await optimizer.transform(input, 'js');
+  const chunkResult = await optimizer.renderChunk(moduleResult.code);
+  expect(chunkResult.code).not.toContain('// oveo:');
+});
+
+test('debug_comments/preserve-comments-none', async () => {
+  const optimizer = new Optimizer({ ...options, debugComments: true, preserveComments: 'none' });
+  const moduleResult = await optimizer.transform(input, 'js');
+  const chunkResult = await optimizer.renderChunk(moduleResult.code);
+  expect(chunkResult.code).not.toContain('// oveo:');
+});
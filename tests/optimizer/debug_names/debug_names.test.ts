@@ -0,0 +1,33 @@
+import { expect, test } from 'bun:test';
+import { Optimizer } from '@oveo/optimizer';
+
+const input = `
+import { dedupe } from "oveo";
+export function a() {
+  return [Math.PI, Math.LN2, new TextEncoder(), dedupe({ a: 1 }), dedupe({ a: 1 })];
+}
+`;
+
+const options = {
+  dedupe: true,
+  globals: { include: ['js', 'web'], hoist: true, singletons: true },
+};
+
+test('debug_names/enabled', async () => {
+  const optimizer = new Optimizer({ ...options, debugNames: true });
+  const moduleResult = await optimizer.transform(input, 'js');
+  const chunkResult = await optimizer.renderChunk(moduleResult.code);
+  expect(chunkResult.code).toContain('const _GLOBAL_Math = Math;\n');
+  expect(chunkResult.code).toContain('const _GLOBAL_Math_PI = _GLOBAL_Math.PI;\n');
+  expect(chunkResult.code).toContain('const _GLOBAL_Math_LN2_ = _GLOBAL_Math.LN2;\n');
+  expect(chunkResult.code).toContain('const _SINGLETON_TextEncoder = new _GLOBAL_TextEncoder();\n');
+  expect(chunkResult.code).toContain('const _DEDUPE_object = { a: 1 };\n');
+});
+
+test('debug_names/disabled', async () => {
+  const optimizer = new Optimizer(options);
+  const moduleResult = await optimizer.transform(input, 'js');
+  const chunkResult = await optimizer.renderChunk(moduleResult.code);
+  expect(chunkResult.code).toContain('const _GLOBAL_ = Math;\n');
+  expect(chunkResult.code).not.toContain('_GLOBAL_Math');
+});
@@ -0,0 +1,26 @@
+import { expect, test } from 'bun:test';
+import { Optimizer } from '@oveo/optimizer';
+
+const optimizer = new Optimizer({});
+
+const input = `const a = 1;\nconst b = ;\n`;
+
+test('errors/syntax-location', async () => {
+  const err = await optimizer.transform(input, 'js').catch((e) => e);
+  expect(err).toBeInstanceOf(Error);
+  expect(err.offset).toBe(23);
+  expect(err.line).toBe(2);
+  expect(err.column).toBe(10);
+  expect(err.source).toBe('const b = ;');
+});
+
+test('errors/syntax-location-sync', () => {
+  let err: any;
+  try {
+    optimizer.renderChunkSync(input);
+  } catch (e) {
+    err = e;
+  }
+  expect(err.line).toBe(2);
+  expect(err.column).toBe(10);
+});
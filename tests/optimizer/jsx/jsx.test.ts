@@ -0,0 +1,24 @@
+import { expect, test } from 'bun:test';
+import { Optimizer } from '@oveo/optimizer';
+
+const SOURCE = `export function Button({ label, onClick }) {
+	return <button class="btn" onClick={onClick}>
+		{label}
+		<>
+			<span>{label.length}</span>
+		</>
+	</button>;
+}
+`;
+
+test('jsx/transform', async () => {
+  const optimizer = new Optimizer({ hoist: true, dedupe: true });
+  const result = await optimizer.transform(SOURCE, 'jsx');
+  expect(result.code).toBe(SOURCE);
+});
+
+test('jsx/renderChunk', async () => {
+  const optimizer = new Optimizer({ hoist: true, dedupe: true });
+  const result = await optimizer.renderChunk(SOURCE);
+  expect(result.code).toBe(SOURCE);
+});
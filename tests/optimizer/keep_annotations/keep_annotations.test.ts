@@ -0,0 +1,29 @@
+import { expect, test } from 'bun:test';
+import { Optimizer } from '@oveo/optimizer';
+
+const input = `
+import { dedupe, key, pure } from "oveo";
+export const a = dedupe({ x: 1 });
+export const b = dedupe({ x: 1 });
+export const k = key("foo_");
+export function f() {
+  return pure(g());
+}
+`;
+
+const options = { dedupe: true, pure: true, renameProperties: { pattern: '_$' } };
+
+test('keep_annotations/enabled', async () => {
+  const optimizer = new Optimizer({ ...options, keepAnnotations: true });
+  const moduleResult = await optimizer.transform(input, 'js');
+  expect(moduleResult.code).toContain('__oveo__(');
+  const chunkResult = await optimizer.renderChunk(moduleResult.code);
+  expect(chunkResult.code).toBe(moduleResult.code);
+});
+
+test('keep_annotations/disabled', async () => {
+  const optimizer = new Optimizer(options);
+  const moduleResult = await optimizer.transform(input, 'js');
+  const chunkResult = await optimizer.renderChunk(moduleResult.code);
+  expect(chunkResult.code).not.toContain('__oveo__');
+});
@@ -0,0 +1,13 @@
+export function Button(text) {
+  return {
+    type: "button",
+    props: { class: "btn", tabIndex: -1 },
+    children: [text],
+  };
+}
+
+export function sizes() {
+  return [16, 24, 32];
+}
+
+export const TOP_LEVEL = [1, 2, 3];
@@ -0,0 +1,24 @@
+const _HOISTED_ = {
+	class: "btn",
+	tabIndex: -1
+};
+export function Button(text) {
+	return {
+		type: "button",
+		props: _HOISTED_,
+		children: [text]
+	};
+}
+const _HOISTED_2 = [
+	16,
+	24,
+	32
+];
+export function sizes() {
+	return _HOISTED_2;
+}
+export const TOP_LEVEL = [
+	1,
+	2,
+	3
+];
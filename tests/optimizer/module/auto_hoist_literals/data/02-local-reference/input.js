@@ -0,0 +1,9 @@
+export function point(x) {
+  const y = x * 2;
+  const state = { items: [] };
+  return [x, y, state];
+}
+
+export function config(debug) {
+  return { debug, level: 1 };
+}
@@ -0,0 +1,35 @@
+import { expect, test } from 'bun:test';
+import { Optimizer } from '@oveo/optimizer';
+
+test('module/debug_hoist', async () => {
+  const optimizer = new Optimizer({ hoist: true, debugHoist: true });
+  const result = await optimizer.transform(
+    'import { hoist, scope } from "oveo";\n\nexport function test() {\n  scope(() => {\n    const a = 1;\n    return () => {\n      hoist(() => a);\n    };\n  });\n}\n',
+    'js',
+  );
+  expect(result.stats.hoistLog).toEqual([
+    'push Scope scope=0 0..152',
+    '  push FunctionBody scope=1 61..151',
+    '    push Scope scope=2 77..147',
+    '      push FunctionBody scope=3 113..142',
+    '        push HoistExpr scope=3 127..134',
+    '          push FunctionBody scope=4 133..134',
+    '            reduce symbol_scope=2 outermost=2 hoist_scope=2',
+    '          pop FunctionBody',
+    '        pop HoistExpr',
+    '        hoist 127..134 hoist_scope=2',
+    '      pop FunctionBody',
+    '    pop Scope',
+    '  pop FunctionBody',
+    'pop Scope',
+  ]);
+});
+
+test('module/debug_hoist/disabled', async () => {
+  const optimizer = new Optimizer({ hoist: true });
+  const result = await optimizer.transform(
+    'import { hoist } from "oveo";\n\nfunction test(a) {\n  hoist(() => a);\n}\n',
+    'js',
+  );
+  expect(result.stats.hoistLog).toEqual([]);
+});
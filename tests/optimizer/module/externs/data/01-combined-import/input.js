@@ -0,0 +1,5 @@
+import Foo, { bar } from "@test/externs";
+
+function test() {
+  return bar(() => Foo);
+}
@@ -0,0 +1,5 @@
+import { bar } from "@test/externs";
+const _HOISTED_ = () => "default-value";
+function test() {
+	return bar(_HOISTED_);
+}
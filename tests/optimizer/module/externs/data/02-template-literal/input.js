@@ -0,0 +1,7 @@
+import { CONFIG } from "@test/externs";
+
+export function banner(name) {
+  return `${name} v${CONFIG.version} (build ${CONFIG.build})`;
+}
+
+export const escaped = `\`${CONFIG.version}\` $${CONFIG.debug}`;
@@ -0,0 +1,4 @@
+export function banner(name) {
+	return `${name} v1.2.0 (build 42)`;
+}
+export const escaped = "`1.2.0` $false";
@@ -0,0 +1,4 @@
+import { CONFIG } from "@test/externs";
+
+export const VERSION = `v${CONFIG.version}-${CONFIG.build}`;
+export const DEBUG = `${CONFIG.debug}`;
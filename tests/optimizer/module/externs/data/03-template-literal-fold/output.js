@@ -0,0 +1,2 @@
+export const VERSION = "v1.2.0-42";
+export const DEBUG = "false";
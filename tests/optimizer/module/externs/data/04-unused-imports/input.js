@@ -0,0 +1,8 @@
+import { CONFIG } from "@test/externs";
+import Foo, { bar } from "@test/externs";
+
+export const VERSION = CONFIG.version;
+
+export function test() {
+  return bar(() => Foo);
+}
@@ -0,0 +1,6 @@
+import { bar } from "@test/externs";
+export const VERSION = "1.2.0";
+const _HOISTED_ = () => "default-value";
+export function test() {
+	return bar(_HOISTED_);
+}
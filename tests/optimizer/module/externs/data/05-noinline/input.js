@@ -0,0 +1,4 @@
+import { INLINED, NOT_INLINED } from "@test/externs";
+
+export const a = INLINED;
+export const b = NOT_INLINED;
@@ -0,0 +1,3 @@
+import { NOT_INLINED } from "@test/externs";
+export const a = 1;
+export const b = NOT_INLINED;
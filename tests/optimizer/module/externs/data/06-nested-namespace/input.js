@@ -0,0 +1,4 @@
+import * as ns from "@test/externs";
+
+export const version = ns.CONFIG.version;
+export const ssr = ns.CONFIG.features.ssr;
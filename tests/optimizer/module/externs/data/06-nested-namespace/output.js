@@ -0,0 +1,2 @@
+export const version = "1.2.0";
+export const ssr = true;
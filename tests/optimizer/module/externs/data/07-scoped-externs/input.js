@@ -0,0 +1,6 @@
+import { provide } from "@test/externs";
+
+const deps = { VERSION: "dev" };
+
+export const a = provide(deps, () => deps.VERSION);
+export const b = deps.VERSION;
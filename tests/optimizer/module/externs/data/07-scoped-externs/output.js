@@ -0,0 +1,4 @@
+import { provide } from "@test/externs";
+const deps = { VERSION: "dev" };
+export const a = provide(deps, () => "2.0.0");
+export const b = deps.VERSION;
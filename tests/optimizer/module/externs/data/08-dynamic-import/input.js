@@ -0,0 +1,10 @@
+const { INLINED, LONG: long, NOT_INLINED, [key]: computed, bar = 1 } = await import("@test/externs");
+const CONFIG = await import("@test/externs");
+let { INLINED: notConst } = await import("@test/externs");
+
+export async function f() {
+  const { CONFIG } = await import("@test/externs");
+  return CONFIG.version;
+}
+
+console.log(INLINED, long, NOT_INLINED, computed, bar, CONFIG.INLINED, notConst);
@@ -0,0 +1,8 @@
+const { INLINED, LONG: long, NOT_INLINED, [key]: computed, bar = 1 } = await import("@test/externs");
+const CONFIG = await import("@test/externs");
+let { INLINED: notConst } = await import("@test/externs");
+export async function f() {
+	const { CONFIG } = await import("@test/externs");
+	return "1.2.0";
+}
+console.log(1, "long string value", NOT_INLINED, computed, bar, 1, notConst);
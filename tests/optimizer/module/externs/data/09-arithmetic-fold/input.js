@@ -0,0 +1,8 @@
+import { CONFIG, INLINED } from "@test/externs";
+
+export const BUILD = CONFIG.build * 2 + INLINED;
+export const HALF = CONFIG.build / 4;
+export const REM = CONFIG.build % 5;
+export const NEG = INLINED - CONFIG.build;
+export const INEXACT = 0.1 + CONFIG.build / 10;
+export const ZERO = INLINED / 0;
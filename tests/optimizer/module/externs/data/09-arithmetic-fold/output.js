@@ -0,0 +1,6 @@
+export const BUILD = 85;
+export const HALF = 10.5;
+export const REM = 2;
+export const NEG = -41;
+export const INEXACT = .1 + 4.2;
+export const ZERO = 1 / 0;
@@ -0,0 +1,5 @@
+import { CONFIG, LONG } from "@test/externs";
+
+export const LABEL = "build-" + CONFIG.build + "-" + CONFIG.version;
+export const PREFIX = LONG + x + "!";
+export const SUFFIX = x + (LONG + "!");
@@ -0,0 +1,3 @@
+export const LABEL = "build-42-1.2.0";
+export const PREFIX = "long string value" + x + "!";
+export const SUFFIX = x + "long string value!";
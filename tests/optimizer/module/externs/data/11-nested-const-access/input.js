@@ -0,0 +1,7 @@
+import { SETTINGS } from "@test/externs";
+
+export const a = SETTINGS.theme.dark;
+export const b = SETTINGS.theme["name"];
+export const c = SETTINGS["key-name"];
+export const d = SETTINGS.theme.missing;
+export const e = SETTINGS.theme;
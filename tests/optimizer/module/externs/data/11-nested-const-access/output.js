@@ -0,0 +1,6 @@
+import { SETTINGS } from "@test/externs";
+export const a = true;
+export const b = "light";
+export const c = "a";
+export const d = SETTINGS.theme.missing;
+export const e = SETTINGS.theme;
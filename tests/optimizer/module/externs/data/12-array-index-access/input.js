@@ -0,0 +1,8 @@
+import { NESTED, SETTINGS } from "@test/externs";
+
+export const a = SETTINGS.sizes[1];
+export const b = NESTED.a.b[0] + NESTED.a.b[2];
+export const c = SETTINGS.sizes[2];
+export function d(i) {
+	return SETTINGS.sizes[i];
+}
@@ -0,0 +1,7 @@
+import { SETTINGS } from "@test/externs";
+export const a = 20;
+export const b = 4;
+export const c = SETTINGS.sizes[2];
+export function d(i) {
+	return SETTINGS.sizes[i];
+}
@@ -0,0 +1,174 @@
+import { beforeAll, expect, test } from 'bun:test';
+import { readdir } from 'node:fs/promises';
+import * as path from 'node:path';
+import { Optimizer } from '@oveo/optimizer';
+
+import { normalizeNewlines } from '../../normalize.js';
+
+const EXTERNS = `{
+  "@test/externs": {
+    "exports": {
+      "default": { "type": "const", "value": "default-value" },
+      "bar": {
+        "type": "function",
+        "arguments": [{ "hoist": true }]
+      },
+      "provide": {
+        "type": "function",
+        "arguments": [
+          {
+            "externs": {
+              "exports": {
+                "VERSION": { "type": "const", "value": "2.0.0" }
+              }
+            }
+          }
+        ]
+      },
+      "LONG": { "type": "const", "value": "long string value" },
+      "NESTED": { "type": "const", "value": { "a": { "b": [1, 2, 3] } } },
+      "INLINED": { "type": "const", "value": 1 },
+      "NOT_INLINED": { "type": "const", "value": 2, "inline": false },
+      "SETTINGS": {
+        "type": "const",
+        "value": { "theme": { "dark": true, "name": "light" }, "sizes": [10, 20], "key-name": "a" }
+      },
+      "CONFIG": {
+        "type": "namespace",
+        "exports": {
+          "version": { "type": "const", "value": "1.2.0" },
+          "build": { "type": "const", "value": 42 },
+          "debug": { "type": "const", "value": false },
+          "features": {
+            "type": "namespace",
+            "exports": {
+              "ssr": { "type": "const", "value": true }
+            }
+          }
+        }
+      }
+    }
+  }
+}`;
+const optimizer = new Optimizer({ hoist: true, externs: { inlineConstValues: true } });
+beforeAll(() => {
+  optimizer.importExterns(new TextEncoder().encode(EXTERNS));
+});
+
+const units = path.join(import.meta.dir, 'data');
+const entries = await readdir(units, { recursive: true });
+for (const entry of entries) {
+  try {
+    const input = await Bun.file(path.join(units, entry, 'input.js')).text();
+
+    test(`module/externs/${entry}`, async () => {
+      const output = Bun.file(path.join(units, entry, 'output.js'));
+      const result = await optimizer.transform(input, 'js');
+      expect(normalizeNewlines(result.code)).toBe(normalizeNewlines(await output.text()));
+    });
+  } catch {}
+}
+
+test('module/externs/stats', async () => {
+  const result = await optimizer.transform(
+    `import * as ns from "@test/externs";
+import Foo, { bar, INLINED } from "@test/externs";
+export const a = ns.CONFIG.version;
+export const b = bar(() => Foo);`,
+    'js',
+  );
+  expect(result.stats.usedExterns).toEqual([
+    { module: '@test/externs', export: 'CONFIG' },
+    { module: '@test/externs', export: 'bar' },
+    { module: '@test/externs', export: 'default' },
+  ]);
+});
+
+test('module/externs/max-inline-size', async () => {
+  const opt = new Optimizer({ hoist: true, externs: { inlineConstValues: true, maxInlineSize: 8 } });
+  opt.importExterns(new TextEncoder().encode(EXTERNS));
+  const result = await opt.transform(
+    `import { INLINED, LONG, NESTED } from "@test/externs";
+export const a = [INLINED, LONG, NESTED];`,
+    'js',
+  );
+  expect(normalizeNewlines(result.code)).toBe(
+    'import { LONG, NESTED } from "@test/externs";\nexport const a = [\n\t1,\n\tLONG,\n\tNESTED\n];\n',
+  );
+});
+
+test('module/externs/merge', async () => {
+  const encoder = new TextEncoder();
+  const opt = new Optimizer({ externs: { inlineConstValues: true } });
+  opt.mergeExterns(
+    encoder.encode(
+      `{ "@test/merge": { "exports": { "A": { "type": "const", "value": 1 }, "B": { "type": "const", "value": 1 } } } }`,
+    ),
+  );
+  opt.mergeExterns(
+    encoder.encode(
+      `{ "@test/merge": { "exports": { "B": { "type": "const", "value": 2 }, "C": { "type": "const", "value": 3 } } } }`,
+    ),
+  );
+  const result = await opt.transform(
+    `import { A, B, C } from "@test/merge";
+export const a = [A, B, C];`,
+    'js',
+  );
+  expect(normalizeNewlines(result.code)).toBe('export const a = [\n\t1,\n\t2,\n\t3\n];\n');
+});
+
+test('module/externs/import-merge', async () => {
+  const encoder = new TextEncoder();
+  const opt = new Optimizer({ externs: { inlineConstValues: true } });
+  opt.importExterns(
+    encoder.encode(`{ "@test/merge": { "exports": { "A": { "type": "const", "value": 1 } } } }`),
+  );
+  opt.importExterns(
+    encoder.encode(`{ "@test/merge": { "exports": { "B": { "type": "const", "value": 2 } } } }`),
+  );
+  const result = await opt.transform(
+    `import { A, B } from "@test/merge";
+export const a = [A, B];`,
+    'js',
+  );
+  expect(normalizeNewlines(result.code)).toBe('export const a = [1, 2];\n');
+});
+
+test('module/externs/clear', async () => {
+  const opt = new Optimizer({ hoist: true, externs: { inlineConstValues: true } });
+  opt.importExterns(new TextEncoder().encode(EXTERNS));
+  opt.clearExterns();
+  const result = await opt.transform(
+    `import { hoist } from "oveo";
+import { INLINED } from "@test/externs";
+export const a = () => hoist(() => INLINED);`,
+    'js',
+  );
+  expect(normalizeNewlines(result.code)).toBe(
+    'import { INLINED } from "@test/externs";\nconst _HOISTED_ = () => INLINED;\nexport const a = () => _HOISTED_;\n',
+  );
+});
+
+test('module/externs/require', async () => {
+  const input = `const { INLINED } = require("@test/externs");
+const ns = require("@test/externs");
+module.exports = [INLINED, ns.CONFIG.version];`;
+  const cjs = await optimizer.transform(input, 'cjs');
+  expect(normalizeNewlines(cjs.code)).toBe(
+    'const { INLINED } = require("@test/externs");\nconst ns = require("@test/externs");\nmodule.exports = [1, "1.2.0"];\n',
+  );
+  // `require()` calls aren't resolved in ES modules.
+  const esm = await optimizer.transform(input, 'js');
+  expect(normalizeNewlines(esm.code)).toContain('module.exports = [INLINED, ns.CONFIG.version];\n');
+});
+
+test('module/externs/require-local', async () => {
+  const result = await optimizer.transform(
+    `const require = (source) => ({ INLINED: 2 });
+const { INLINED } = require("@test/externs");
+module.exports = INLINED;`,
+    'cjs',
+  );
+  expect(normalizeNewlines(result.code)).toContain('module.exports = INLINED;\n');
+});
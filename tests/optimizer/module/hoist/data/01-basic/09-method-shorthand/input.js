@@ -0,0 +1,8 @@
+import { hoist } from "oveo";
+
+const expensive = 1;
+const o = {
+	method() {
+		return hoist(() => expensive);
+	},
+};
@@ -0,0 +1,5 @@
+const expensive = 1;
+const _HOISTED_ = () => expensive;
+const o = { method() {
+	return _HOISTED_;
+} };
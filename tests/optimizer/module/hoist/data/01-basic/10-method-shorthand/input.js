@@ -0,0 +1,11 @@
+import { hoist } from "oveo";
+
+const o = {
+	method(a) {
+		return hoist(() => a);
+	},
+	get value() {
+		const b = 1;
+		return hoist(() => b);
+	},
+};
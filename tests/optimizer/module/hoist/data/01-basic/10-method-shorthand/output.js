@@ -0,0 +1,9 @@
+const o = {
+	method(a) {
+		return () => a;
+	},
+	get value() {
+		const b = 1;
+		return () => b;
+	}
+};
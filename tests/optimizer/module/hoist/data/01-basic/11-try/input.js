@@ -0,0 +1,8 @@
+import { hoist } from "oveo";
+
+const f = () => 1;
+function test(b) {
+	try {
+		hoist(f());
+	} catch {}
+}
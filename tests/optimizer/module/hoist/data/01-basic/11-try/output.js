@@ -0,0 +1,6 @@
+const f = () => 1;
+function test(b) {
+	try {
+		f();
+	} catch {}
+}
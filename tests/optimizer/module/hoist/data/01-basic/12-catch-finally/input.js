@@ -0,0 +1,12 @@
+import { hoist } from "oveo";
+
+const f = () => 1;
+function test(b) {
+	try {
+		b();
+	} catch {
+		hoist(f());
+	} finally {
+		hoist(() => f);
+	}
+}
@@ -0,0 +1,10 @@
+const f = () => 1;
+function test(b) {
+	try {
+		b();
+	} catch {
+		f();
+	} finally {
+		() => f;
+	}
+}
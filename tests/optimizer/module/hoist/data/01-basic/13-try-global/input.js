@@ -0,0 +1,8 @@
+import { hoist } from "oveo";
+
+function test() {
+	try {
+		hoist(f());
+	} catch {}
+	return hoist(g());
+}
@@ -0,0 +1,7 @@
+const _HOISTED_ = g();
+function test() {
+	try {
+		f();
+	} catch {}
+	return _HOISTED_;
+}
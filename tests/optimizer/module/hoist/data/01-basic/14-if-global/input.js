@@ -0,0 +1,8 @@
+import { hoist } from "oveo";
+
+function test(b) {
+	if (b) {
+		return hoist(f());
+	}
+	return b ? hoist(g()) : hoist([1, 2]);
+}
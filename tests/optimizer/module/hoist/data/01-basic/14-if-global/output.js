@@ -0,0 +1,7 @@
+const _HOISTED_ = [1, 2];
+function test(b) {
+	if (b) {
+		return f();
+	}
+	return b ? g() : _HOISTED_;
+}
@@ -0,0 +1,14 @@
+import { hoist, scope } from "oveo";
+
+const f = () => 1;
+function test(b) {
+	try {
+		scope(() => {
+			const d = 2;
+			return () => {
+				hoist(() => d);
+				hoist(f());
+			};
+		});
+	} catch {}
+}
@@ -0,0 +1,13 @@
+const f = () => 1;
+function test(b) {
+	try {
+		() => {
+			const d = 2;
+			const _HOISTED_ = () => d;
+			return () => {
+				_HOISTED_;
+				f();
+			};
+		};
+	} catch {}
+}
@@ -0,0 +1,7 @@
+import { hoist, scope } from "oveo";
+
+export function test(f) {
+  return scope((a) => {
+    return () => hoist(f(a));
+  })(1);
+}
@@ -0,0 +1,5 @@
+import { hoist, scope } from "oveo";
+
+export function test(f) {
+  return scope((a) => () => hoist(f(a)))(1);
+}
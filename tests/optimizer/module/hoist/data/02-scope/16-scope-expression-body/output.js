@@ -0,0 +1,6 @@
+export function test(f) {
+	return ((a) => {
+		const _HOISTED_ = f(a);
+		return () => _HOISTED_;
+	})(1);
+}
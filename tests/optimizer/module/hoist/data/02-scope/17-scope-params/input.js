@@ -0,0 +1,13 @@
+import { hoist, scope } from "oveo";
+
+export function test(f) {
+  return scope((a, b) => {
+    return () => [hoist(() => b), hoist(() => f(a))];
+  });
+}
+
+export function destructured() {
+  return scope((a, { b }, ...c) => {
+    return () => [hoist(() => b), hoist(() => c)];
+  });
+}
@@ -0,0 +1,14 @@
+export function test(f) {
+	return (a, b) => {
+		const _HOISTED_ = () => b;
+		const _HOISTED_2 = () => f(a);
+		return () => [_HOISTED_, _HOISTED_2];
+	};
+}
+export function destructured() {
+	return (a, { b }, ...c) => {
+		const _HOISTED_3 = () => b;
+		const _HOISTED_4 = () => c;
+		return () => [_HOISTED_3, _HOISTED_4];
+	};
+}
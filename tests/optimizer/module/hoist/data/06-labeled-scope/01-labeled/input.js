@@ -0,0 +1,9 @@
+import { hoist, scope } from "oveo";
+
+function test(b) {
+	scope(() => {
+		scope(() => {
+			return () => hoist((c) => c, "component");
+		});
+	}, "component");
+}
@@ -0,0 +1,9 @@
+import { hoist, scope } from "oveo";
+
+function test(b) {
+	scope(() => {
+		scope((d) => {
+			return () => hoist((c) => d, "component");
+		});
+	}, "component");
+}
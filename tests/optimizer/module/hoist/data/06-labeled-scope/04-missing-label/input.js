@@ -0,0 +1,7 @@
+import { hoist, scope } from "oveo";
+
+function test(b) {
+	scope(() => {
+		return () => hoist((c) => c, "component");
+	});
+}
@@ -0,0 +1,11 @@
+import { hoist, scope } from "oveo";
+
+function test(b) {
+	scope(() => {
+		scope(() => {
+			scope(() => {
+				return () => hoist((c) => c, "a");
+			});
+		}, "a");
+	}, "a");
+}
@@ -0,0 +1,9 @@
+import { hoist as h, scope as s } from "oveo";
+
+function test(b) {
+	s((e) => {
+		return () => {
+			h((c) => e);
+		};
+	});
+}
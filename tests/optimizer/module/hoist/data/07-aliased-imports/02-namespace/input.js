@@ -0,0 +1,6 @@
+import * as oveo from "oveo";
+
+const a = 1;
+function test(b) {
+	oveo.hoist((c) => a);
+}
@@ -0,0 +1,9 @@
+import * as oveo from "oveo";
+
+function test(b) {
+	oveo.scope((e) => {
+		return () => {
+			oveo.hoist((c) => e);
+		};
+	});
+}
@@ -0,0 +1,8 @@
+function test(b) {
+	(e) => {
+		const _HOISTED_ = (c) => e;
+		return () => {
+			_HOISTED_;
+		};
+	};
+}
@@ -0,0 +1,13 @@
+import { hoist } from "oveo";
+function f() {
+  return hoist(() => 1);
+}
+async function g() {
+  return hoist(() => 2);
+}
+function* h() {
+  yield hoist(() => 3);
+}
+const i = async () => {
+  const j = () => hoist(() => 4);
+};
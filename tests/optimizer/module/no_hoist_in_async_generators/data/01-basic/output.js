@@ -0,0 +1,13 @@
+const _HOISTED_ = () => 1;
+function f() {
+	return _HOISTED_;
+}
+async function g() {
+	return () => 2;
+}
+function* h() {
+	yield () => 3;
+}
+const i = async () => {
+	const j = () => () => 4;
+};
@@ -0,0 +1,31 @@
+import { expect, test } from 'bun:test';
+import { Optimizer } from '@oveo/optimizer';
+
+const decoder = new TextDecoder();
+
+const chunks = [
+  'export const o = { foo_: 1, bar_: 2 };\n',
+  'export const p = (v) => v.baz_ + v.bar_;\n',
+];
+
+test('property_map/collect', () => {
+  const optimizer = new Optimizer({ renameProperties: { pattern: '_$' } });
+  expect(decoder.decode(optimizer.collectProperties(chunks[0]))).toBe(
+    '# oveo-propmap v1\nbar_=t\nfoo_=e\n',
+  );
+  expect(decoder.decode(optimizer.collectProperties(chunks[1]))).toBe(
+    '# oveo-propmap v1\nbar_=t\nbaz_=n\nfoo_=e\n',
+  );
+});
+
+test('property_map/collect/render', async () => {
+  const optimizer = new Optimizer({ renameProperties: { pattern: '_$' } });
+  for (const chunk of chunks) {
+    optimizer.collectProperties(chunk);
+  }
+  const result = await optimizer.renderChunk(chunks[1]);
+  expect(result.code).toBe('export const p = (v) => v.n + v.t;\n');
+  expect(decoder.decode(optimizer.updatePropertyMap()!)).toBe(
+    '# oveo-propmap v1\nbar_=t\nbaz_=n\nfoo_=e\n',
+  );
+});
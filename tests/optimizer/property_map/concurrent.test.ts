@@ -0,0 +1,38 @@
+import { expect, test } from 'bun:test';
+import { Optimizer } from '@oveo/optimizer';
+
+const decoder = new TextDecoder();
+
+const CHUNKS = 64;
+const PROPERTIES = 200;
+
+// Each chunk renames a window of properties that overlaps with other chunks.
+const chunks = Array.from({ length: CHUNKS }, (_, i) => {
+  const keys = Array.from({ length: PROPERTIES }, (_, j) => `p${(i * 7 + j) % 500}_`);
+  return `export const o${i} = { ${keys.map((k) => `${k}: 1`).join(', ')} };\n`;
+});
+
+function parsePropertyMap(data: Uint8Array) {
+  const map = new Map<string, string>();
+  for (const line of decoder.decode(data).split('\n')) {
+    if (line === '' || line.startsWith('#')) continue;
+    const [key, value] = line.split('=');
+    map.set(key!, value!);
+  }
+  return map;
+}
+
+test('property_map/concurrent', async () => {
+  const optimizer = new Optimizer({ renameProperties: { pattern: '_$' } });
+  const results = await Promise.all(chunks.map((chunk) => optimizer.renderChunk(chunk)));
+
+  const map = parsePropertyMap(optimizer.updatePropertyMap()!);
+  const keys = new Set(chunks.flatMap((chunk) => chunk.match(/p\d+_/g)!));
+  expect(map.size).toBe(keys.size);
+  expect(new Set(map.values()).size).toBe(map.size);
+
+  for (let i = 0; i < CHUNKS; i++) {
+    const expected = chunks[i]!.replace(/p\d+_/g, (key) => map.get(key)!);
+    expect(results[i]!.code.replace(/\s+/g, ' ')).toBe(expected.replace(/\s+/g, ' '));
+  }
+});
@@ -0,0 +1,52 @@
+import { expect, test } from 'bun:test';
+import { Optimizer } from '@oveo/optimizer';
+
+const chunks = [
+  'export const a = { zeta_: 1, alpha_: 2 };\na.zeta_ = 3;\n',
+  'export const b = { mid_: 1, alpha_: 2 };\n',
+];
+
+async function build(order: number[]) {
+  const optimizer = new Optimizer({ renameProperties: { pattern: '_$', deterministic: true } });
+  const results: string[] = [];
+  for (const i of order) {
+    results[i] = (await optimizer.renderChunk(chunks[i])).code;
+  }
+  optimizer.finalizePropertyMap();
+  return results.map((code) => optimizer.finalizeChunk(code, '').code);
+}
+
+test('property_map/deterministic', async () => {
+  const a = await build([0, 1]);
+  const b = await build([1, 0]);
+  expect(a).toEqual(b);
+  expect(a[0]).toBe('export const a = {\n\tn: 1,\n\te: 2\n};\na.n = 3;\n');
+  expect(a[1]).toBe('export const b = {\n\tt: 1,\n\te: 2\n};\n');
+});
+
+test('property_map/deterministic/private_names', async () => {
+  const optimizer = new Optimizer({ renameProperties: { pattern: '_$', deterministic: true } });
+  const result = await optimizer.renderChunk(
+    'export class A {\n' +
+      '  #fooBar_ = 1;\n' +
+      '  #get_() {\n' +
+      '    return this.#fooBar_;\n' +
+      '  }\n' +
+      '  fooBar_() {\n' +
+      '    return this.#get_();\n' +
+      '  }\n' +
+      '}\n',
+  );
+  optimizer.finalizePropertyMap();
+  expect(optimizer.finalizeChunk(result.code, '').code).toBe(
+    'export class A {\n' +
+      '\t#e = 1;\n' +
+      '\t#t() {\n' +
+      '\t\treturn this.#e;\n' +
+      '\t}\n' +
+      '\te() {\n' +
+      '\t\treturn this.#t();\n' +
+      '\t}\n' +
+      '}\n',
+  );
+});
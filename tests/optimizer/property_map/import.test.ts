@@ -0,0 +1,58 @@
+import { expect, test } from 'bun:test';
+import { Optimizer } from '@oveo/optimizer';
+
+const encoder = new TextEncoder();
+
+test('property_map/import/duplicate-value', () => {
+  const optimizer = new Optimizer({ renameProperties: { pattern: '_$' } });
+  expect(() => {
+    optimizer.importPropertyMap(encoder.encode('key1_=a\nkey2_=a\n'));
+  }).toThrow("duplicate value 'a' at line '2'");
+});
+
+test('property_map/import/reserved-keyword', () => {
+  const optimizer = new Optimizer({ renameProperties: { pattern: '_$' } });
+  expect(() => {
+    optimizer.importPropertyMap(encoder.encode('key1_=a\nkey2_=if\n'));
+  }).toThrow("reserved keyword 'if' at line '2'");
+});
+
+test('property_map/reset', async () => {
+  const optimizer = new Optimizer({ renameProperties: { pattern: '_$' } });
+  const before = await optimizer.renderChunk('export const o = { foo_: 1, bar_: 2 };\n');
+  expect(before.code).toBe('export const o = {\n\te: 1,\n\tt: 2\n};\n');
+
+  optimizer.resetPropertyMap();
+  expect(optimizer.updatePropertyMap()).toBeUndefined();
+
+  const after = await optimizer.renderChunk('export const o = { bar_: 1 };\n');
+  expect(after.code).toBe('export const o = { e: 1 };\n');
+});
+
+test('property_map/import/header', async () => {
+  const optimizer = new Optimizer({ renameProperties: { pattern: '_$' } });
+  optimizer.importPropertyMap(encoder.encode('# oveo-propmap v1\nkey1_=a\n'));
+  const result = await optimizer.renderChunk('export const o = { key1_: 1 };\n');
+  expect(result.code).toBe('export const o = { a: 1 };\n');
+});
+
+test('property_map/import/missing-header', async () => {
+  const optimizer = new Optimizer({ renameProperties: { pattern: '_$' } });
+  optimizer.importPropertyMap(encoder.encode('key1_=a\n'));
+  const result = await optimizer.renderChunk('export const o = { key1_: 1 };\n');
+  expect(result.code).toBe('export const o = { a: 1 };\n');
+});
+
+test('property_map/import/unsupported-version', () => {
+  const optimizer = new Optimizer({ renameProperties: { pattern: '_$' } });
+  expect(() => {
+    optimizer.importPropertyMap(encoder.encode('# oveo-propmap v2\nkey1_=a\n'));
+  }).toThrow("unsupported version 'v2', expected 'v1'");
+});
+
+test('property_map/import/invalid-header', () => {
+  const optimizer = new Optimizer({ renameProperties: { pattern: '_$' } });
+  expect(() => {
+    optimizer.importPropertyMap(encoder.encode('# propmap\nkey1_=a\n'));
+  }).toThrow("invalid header '# propmap'");
+});
@@ -0,0 +1,21 @@
+import { expect, test } from 'bun:test';
+import { Optimizer } from '@oveo/optimizer';
+
+const encoder = new TextEncoder();
+
+test('property_map/keep_names', async () => {
+  const optimizer = new Optimizer({ renameProperties: { pattern: '.', keepNames: ['render'] } });
+  const result = await optimizer.renderChunk(
+    'export function update(el, o) {\n  el.className = o.foo;\n  el.textContent = o.render();\n}\n',
+  );
+  expect(result.code).toBe(
+    'export function update(el, o) {\n\tel.className = o.e;\n\tel.textContent = o.render();\n}\n',
+  );
+});
+
+test('property_map/keep_names/import', () => {
+  const optimizer = new Optimizer({ renameProperties: { pattern: '_$', keepNames: ['render'] } });
+  expect(() => {
+    optimizer.importPropertyMap(encoder.encode('key1_=e\nkey2_=render\n'));
+  }).toThrow("reserved name 'render' at line '2'");
+});
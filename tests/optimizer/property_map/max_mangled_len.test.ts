@@ -0,0 +1,42 @@
+import { expect, test } from 'bun:test';
+import { Optimizer } from '@oveo/optimizer';
+
+// 54 single character names are available, the last 6 properties would need
+// 2 character names.
+const keys = Array.from({ length: 60 }, (_, i) => `p${i}_`);
+const chunk = `export const o = { ${keys.map((k, i) => `${k}: ${i}`).join(', ')} };\n`;
+
+test('property_map/max_mangled_len', async () => {
+  const optimizer = new Optimizer({ renameProperties: { pattern: '_$', maxMangledLen: 1 } });
+  const result = await optimizer.renderChunk(chunk);
+  for (const k of keys.slice(0, 54)) {
+    expect(result.code).not.toContain(k);
+  }
+  for (const k of keys.slice(54)) {
+    expect(result.code).toContain(`${k}: `);
+  }
+  const map = new TextDecoder().decode(optimizer.updatePropertyMap()!);
+  for (const line of map.trim().split('\n').slice(1)) {
+    expect(line.split('=')[1]).toHaveLength(1);
+  }
+  expect(optimizer.propertyMapStats().count).toBe(54);
+  expect(optimizer.propertyMapStats().skipped).toBe(6);
+});
+
+test('property_map/max_mangled_len/deterministic', async () => {
+  const optimizer = new Optimizer({
+    renameProperties: { pattern: '_$', maxMangledLen: 1, deterministic: true },
+  });
+  const result = await optimizer.renderChunk(chunk);
+  optimizer.finalizePropertyMap();
+  const final = optimizer.finalizeChunk(result.code, '');
+  // Names are assigned in a sorted order of keys.
+  const sorted = [...keys].sort();
+  for (const k of sorted.slice(0, 54)) {
+    expect(final.code).not.toContain(`${k}: `);
+  }
+  for (const k of sorted.slice(54)) {
+    expect(final.code).toContain(`${k}: `);
+  }
+  expect(optimizer.propertyMapStats().skipped).toBe(6);
+});
@@ -0,0 +1,32 @@
+import { expect, test } from 'bun:test';
+import { Optimizer } from '@oveo/optimizer';
+
+const decoder = new TextDecoder();
+
+const chunks = [
+  'export const o = { foo_: 1, bar_: 2, baz_: 3 };\n',
+  'export const p = { baz_: 1, qux_: 2, bar_: 3 };\n',
+];
+
+async function build(order: number[]) {
+  const optimizer = new Optimizer({ renameProperties: { pattern: '_$', naming: 'hash' } });
+  const results: string[] = [];
+  for (const i of order) {
+    results[i] = (await optimizer.renderChunk(chunks[i])).code;
+  }
+  return { results, map: decoder.decode(optimizer.updatePropertyMap()!) };
+}
+
+test('property_map/naming/hash', async () => {
+  const a = await build([0, 1]);
+  const b = await build([1, 0]);
+  expect(a.results).toEqual(b.results);
+  expect(a.map).toBe(b.map);
+  expect(a.map).toBe('# oveo-propmap v1\nbar_=y\nbaz_=L\nfoo_=X\nqux_=H\n');
+});
+
+test('property_map/naming/invalid', () => {
+  expect(() => new Optimizer({ renameProperties: { pattern: '_$', naming: 'random' } })).toThrow(
+    'Invalid property naming: random',
+  );
+});
@@ -0,0 +1,25 @@
+import { expect, test } from 'bun:test';
+import { Optimizer } from '@oveo/optimizer';
+
+const encoder = new TextEncoder();
+
+test('property_map/reserved', async () => {
+  const optimizer = new Optimizer({ renameProperties: { pattern: '_$', reserved: ['e', 't'] } });
+  const result = await optimizer.renderChunk('export const o = { foo_: 1, bar_: 2, baz_: 3 };\n');
+  expect(result.code).toBe('export const o = {\n\tn: 1,\n\tr: 2,\n\ti: 3\n};\n');
+});
+
+test('property_map/reserved/reset', async () => {
+  const optimizer = new Optimizer({ renameProperties: { pattern: '_$', reserved: ['e'] } });
+  await optimizer.renderChunk('export const o = { foo_: 1 };\n');
+  optimizer.resetPropertyMap();
+  const result = await optimizer.renderChunk('export const o = { bar_: 1 };\n');
+  expect(result.code).toBe('export const o = { t: 1 };\n');
+});
+
+test('property_map/reserved/import', () => {
+  const optimizer = new Optimizer({ renameProperties: { pattern: '_$', reserved: ['e'] } });
+  expect(() => {
+    optimizer.importPropertyMap(encoder.encode('key1_=t\nkey2_=e\n'));
+  }).toThrow("reserved name 'e' at line '2'");
+});
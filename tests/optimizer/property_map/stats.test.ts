@@ -0,0 +1,28 @@
+import { expect, test } from 'bun:test';
+import { Optimizer } from '@oveo/optimizer';
+
+test('property_map/stats', async () => {
+  const optimizer = new Optimizer({ renameProperties: { pattern: '_$' } });
+  expect(optimizer.propertyMapStats()).toEqual({
+    count: 0,
+    averageOriginalLen: 0,
+    averageMangledLen: 0,
+    skipped: 0,
+  });
+
+  await optimizer.renderChunk('export const o = { foo_: 1, barbaz_: 2 };\no.foo_ = 3;\n');
+  expect(optimizer.propertyMapStats()).toEqual({
+    count: 2,
+    averageOriginalLen: 5.5,
+    averageMangledLen: 1,
+    skipped: 0,
+  });
+});
+
+test('property_map/stats/deterministic', async () => {
+  const optimizer = new Optimizer({ renameProperties: { pattern: '_$', deterministic: true } });
+  await optimizer.renderChunk('export const o = { foo_: 1 };\n');
+  expect(optimizer.propertyMapStats().count).toBe(0);
+  optimizer.finalizePropertyMap();
+  expect(optimizer.propertyMapStats().count).toBe(1);
+});
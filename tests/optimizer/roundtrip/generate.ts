@@ -0,0 +1,238 @@
+// Generates small random programs that are syntactically and semantically
+// valid, programs are deterministic for the same seed.
+
+class Random {
+  state: number;
+
+  constructor(seed: number) {
+    this.state = seed >>> 0;
+  }
+
+  // mulberry32
+  next(): number {
+    let t = (this.state = (this.state + 0x6d2b79f5) >>> 0);
+    t = Math.imul(t ^ (t >>> 15), t | 1);
+    t ^= t + Math.imul(t ^ (t >>> 7), t | 61);
+    return ((t ^ (t >>> 14)) >>> 0) / 4294967296;
+  }
+
+  int(n: number): number {
+    return Math.floor(this.next() * n);
+  }
+
+  pick<T>(items: readonly T[]): T {
+    return items[this.int(items.length)]!;
+  }
+}
+
+const GLOBALS = ['Math', 'Object', 'console', 'document', 'JSON', 'undefined'] as const;
+const KEYS = ['a', 'b', 'foo_', 'bar', 'length', 'default'] as const;
+const STRINGS = ['', 'x', 'hello world', 'a"b', "it's", '\\n'] as const;
+const BINARY = ['+', '-', '*', '/', '%', '===', '!==', '<', '>=', '&&', '||', '??', 'in', '|'] as const;
+const UNARY = ['!', '-', '+', '~', 'typeof ', 'void '] as const;
+
+interface Context {
+  rnd: Random;
+  // Counter shared by all nested contexts, so that names are unique.
+  ids: { next: number };
+  // Bindings that are visible in the current scope.
+  bindings: string[];
+  inFunction: boolean;
+  inLoop: boolean;
+}
+
+function literal(ctx: Context): string {
+  switch (ctx.rnd.int(6)) {
+    case 0:
+      return String(ctx.rnd.int(1000));
+    case 1:
+      return String(ctx.rnd.int(100) / 8);
+    case 2:
+      return JSON.stringify(ctx.rnd.pick(STRINGS));
+    case 3:
+      return ctx.rnd.pick(['true', 'false']);
+    case 4:
+      return 'null';
+    default:
+      return `${ctx.rnd.int(100)}n`;
+  }
+}
+
+function reference(ctx: Context): string {
+  if (ctx.bindings.length > 0 && ctx.rnd.int(3) > 0) {
+    return ctx.rnd.pick(ctx.bindings);
+  }
+  return ctx.rnd.pick(GLOBALS);
+}
+
+function expression(ctx: Context, depth: number): string {
+  if (depth <= 0) {
+    return ctx.rnd.int(2) === 0 ? literal(ctx) : reference(ctx);
+  }
+  const d = depth - 1;
+  switch (ctx.rnd.int(14)) {
+    case 0:
+      return literal(ctx);
+    case 1:
+      return reference(ctx);
+    case 2:
+      // Binary expressions are parenthesized, `??` can't be mixed with `||`.
+      return `(${expression(ctx, d)} ${ctx.rnd.pick(BINARY)} ${expression(ctx, d)})`;
+    case 3:
+      return `(${ctx.rnd.pick(UNARY)}${expression(ctx, d)})`;
+    case 4:
+      return `${reference(ctx)}(${args(ctx, d)})`;
+    case 5:
+      return `${reference(ctx)}.${ctx.rnd.pick(KEYS)}`;
+    case 6:
+      return `${reference(ctx)}[${expression(ctx, d)}]`;
+    case 7:
+      return object(ctx, d);
+    case 8:
+      return `[${args(ctx, d)}]`;
+    case 9:
+      return arrow(ctx, d);
+    case 10:
+      return `(${expression(ctx, d)} ? ${expression(ctx, d)} : ${expression(ctx, d)})`;
+    case 11:
+      return `new ${reference(ctx)}(${args(ctx, d)})`;
+    case 12:
+      return `\`a\${${expression(ctx, d)}}b\``;
+    default:
+      return `${reference(ctx)}?.${ctx.rnd.pick(KEYS)}`;
+  }
+}
+
+function args(ctx: Context, depth: number): string {
+  const items: string[] = [];
+  for (let i = ctx.rnd.int(4); i > 0; i--) {
+    items.push(ctx.rnd.int(8) === 0 ? `...${reference(ctx)}` : expression(ctx, depth));
+  }
+  return items.join(', ');
+}
+
+function object(ctx: Context, depth: number): string {
+  const props: string[] = [];
+  for (let i = ctx.rnd.int(4); i > 0; i--) {
+    switch (ctx.rnd.int(5)) {
+      case 0:
+        props.push(`[${expression(ctx, depth)}]: ${expression(ctx, depth)}`);
+        break;
+      case 1:
+        props.push(`${ctx.rnd.pick(KEYS)}() { return ${expression(ctx, depth)}; }`);
+        break;
+      case 2:
+        props.push(`...${reference(ctx)}`);
+        break;
+      case 3:
+        props.push(`${JSON.stringify(ctx.rnd.pick(STRINGS))}: ${expression(ctx, depth)}`);
+        break;
+      default:
+        props.push(`${ctx.rnd.pick(KEYS)}: ${expression(ctx, depth)}`);
+    }
+  }
+  return `({ ${props.join(', ')} })`;
+}
+
+function arrow(ctx: Context, depth: number): string {
+  const param = `p${ctx.ids.next++}`;
+  const inner = { ...ctx, bindings: [...ctx.bindings, param], inFunction: true, inLoop: false };
+  const body =
+    ctx.rnd.int(2) === 0 ? `(${expression(inner, depth)})` : `{ ${block(inner, depth, 2)} }`;
+  return `((${param}) => ${body})`;
+}
+
+function statement(ctx: Context, depth: number): string {
+  const d = depth - 1;
+  switch (ctx.rnd.int(depth > 0 ? 10 : 3)) {
+    case 0: {
+      const name = `v${ctx.ids.next++}`;
+      const s = `${ctx.rnd.pick(['const', 'let', 'var'])} ${name} = ${expression(ctx, 2)};`;
+      ctx.bindings.push(name);
+      return s;
+    }
+    case 1:
+      return `(${expression(ctx, 3)});`;
+    case 2:
+      if (ctx.inFunction && ctx.rnd.int(3) === 0) {
+        return `return ${expression(ctx, 2)};`;
+      }
+      if (ctx.inLoop && ctx.rnd.int(3) === 0) {
+        return ctx.rnd.pick(['break;', 'continue;']);
+      }
+      return `${reference(ctx)}.${ctx.rnd.pick(KEYS)} = ${expression(ctx, 2)};`;
+    case 3:
+      return `if (${expression(ctx, 2)}) { ${block(ctx, d, 3)} } else { ${block(ctx, d, 2)} }`;
+    case 4: {
+      const i = `i${ctx.ids.next++}`;
+      const inner = { ...ctx, bindings: [...ctx.bindings, i], inLoop: true };
+      const s = `for (let ${i} = 0; ${i} < ${ctx.rnd.int(10)}; ${i}++) { ${block(inner, d, 3)} }`;
+      return s;
+    }
+    case 5: {
+      const inner = { ...ctx, inLoop: true };
+      const s = `while (${expression(ctx, 1)}) { ${block(inner, d, 2)} break; }`;
+      return s;
+    }
+    case 6: {
+      const name = `f${ctx.ids.next++}`;
+      ctx.bindings.push(name);
+      const params = [`a${ctx.ids.next++}`, `b${ctx.ids.next++}`];
+      const inner = {
+        ...ctx,
+        bindings: [...ctx.bindings, ...params],
+        inFunction: true,
+        inLoop: false,
+      };
+      const s = `function ${name}(${params.join(', ')}) { ${block(inner, d, 4)} return ${expression(inner, 2)}; }`;
+      return s;
+    }
+    case 7: {
+      const name = `C${ctx.ids.next++}`;
+      ctx.bindings.push(name);
+      const inner = { ...ctx, bindings: [...ctx.bindings], inFunction: true, inLoop: false };
+      const s = `class ${name} { #x = ${literal(ctx)}; static s = ${expression(ctx, 1)}; m(v) { return this.#x + ${expression(inner, 2)}; } }`;
+      return s;
+    }
+    case 8: {
+      const inner = { ...ctx, bindings: [...ctx.bindings] };
+      const s = `{ ${block(inner, d, 3)} }`;
+      return s;
+    }
+    default: {
+      const e = `e${ctx.ids.next++}`;
+      const inner = { ...ctx, bindings: [...ctx.bindings, e] };
+      const s = `try { ${block(ctx, d, 2)} } catch (${e}) { ${block(inner, d, 2)} } finally { ${block(ctx, d, 1)} }`;
+      return s;
+    }
+  }
+}
+
+// Statements in a block, bindings declared in the block aren't visible
+// outside of it.
+function block(ctx: Context, depth: number, max: number): string {
+  const inner = { ...ctx, bindings: [...ctx.bindings] };
+  const statements: string[] = [];
+  for (let i = ctx.rnd.int(max + 1); i > 0; i--) {
+    statements.push(statement(inner, depth));
+  }
+  return statements.join(' ');
+}
+
+export function generateProgram(seed: number): string {
+  const ctx: Context = {
+    rnd: new Random(seed),
+    ids: { next: 0 },
+    bindings: [],
+    inFunction: false,
+    inLoop: false,
+  };
+  const statements: string[] = [];
+  for (let i = 1 + ctx.rnd.int(8); i > 0; i--) {
+    statements.push(statement(ctx, 3));
+  }
+  if (ctx.bindings.length > 0) {
+    statements.push(`export { ${ctx.bindings.join(', ')} };`);
+  }
+  return statements.join('\n') + '\n';
+}
@@ -0,0 +1,41 @@
+import { expect, test } from 'bun:test';
+import { readdir } from 'node:fs/promises';
+import * as path from 'node:path';
+import { Optimizer } from '@oveo/optimizer';
+
+import { generateProgram } from './generate.js';
+
+// With all options disabled, chunks should be rendered without any changes
+// except for codegen formatting.
+const optimizer = new Optimizer({});
+
+// Inputs are compared in the canonical codegen form, so that two programs
+// with the same AST are always printed the same way.
+function canonical(code: string): string {
+  return optimizer.finalizeChunk(code, '').code;
+}
+
+async function expectRoundtrip(input: string) {
+  const result = await optimizer.renderChunk(input);
+  expect(canonical(result.code)).toBe(canonical(input));
+}
+
+const SEEDS = 200;
+
+for (let seed = 0; seed < SEEDS; seed++) {
+  test(`roundtrip/random/${seed}`, async () => {
+    await expectRoundtrip(generateProgram(seed));
+  });
+}
+
+// Inputs from other test suites.
+const root = path.join(import.meta.dir, '..');
+const entries = await readdir(root, { recursive: true });
+for (const entry of entries) {
+  if (path.basename(entry) !== 'input.js') continue;
+  const input = await Bun.file(path.join(root, entry)).text();
+
+  test(`roundtrip/corpus/${path.dirname(entry)}`, async () => {
+    await expectRoundtrip(input);
+  });
+}
@@ -0,0 +1,31 @@
+import { expect, test } from 'bun:test';
+import { readdir } from 'node:fs/promises';
+import * as path from 'node:path';
+import { Optimizer, type OptimizerOptions } from '@oveo/optimizer';
+
+const suites: [string, OptimizerOptions][] = [
+  ['chunk/dedupe', { hoist: true, dedupe: true }],
+  ['chunk/pure', { pure: true }],
+  ['module/intrinsics', { dedupe: true }],
+  ['chunk/rename_key_objects', { renameProperties: { pattern: '_$', keyObjectsOnly: true } }],
+];
+
+for (const [suite, options] of suites) {
+  const units = path.join(import.meta.dir, '..', suite, 'data');
+  const entries = await readdir(units, { recursive: true });
+  for (const entry of entries) {
+    try {
+      const input = await Bun.file(path.join(units, entry, 'input.js')).text();
+
+      test(`single_phase/${suite}/${entry}`, async () => {
+        const twoPhase = new Optimizer(options);
+        const singlePhase = new Optimizer({ ...options, singlePhase: true });
+        const moduleResult = await twoPhase.transform(input, 'js');
+        const chunkResult = await twoPhase.renderChunk(moduleResult.code);
+        const result = await singlePhase.transform(input, 'js');
+        expect(result.code).not.toContain('__oveo__');
+        expect(result.code).toBe(chunkResult.code);
+      });
+    } catch {}
+  }
+}
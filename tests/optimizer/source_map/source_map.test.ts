@@ -0,0 +1,51 @@
+import { expect, test } from 'bun:test';
+import { Optimizer } from '@oveo/optimizer';
+
+const optimizer = new Optimizer({});
+
+const input = `export const a = 1;\n`;
+
+// Maps the first generated line to the second line in `a.ts`.
+const inputSourceMap = JSON.stringify({
+  version: 3,
+  sources: ['a.ts'],
+  sourcesContent: ['// a\nexport const a: number = 1;\n'],
+  names: [],
+  mappings: 'AACA',
+});
+
+test('source_map/file-name', async () => {
+  const result = await optimizer.transform(input, 'js', { fileName: 'src/a.js' });
+  const map = JSON.parse(result.map);
+  expect(map.file).toBe('src/a.js');
+  expect(map.sources).toEqual(['src/a.js']);
+});
+
+test('source_map/input-source-map', async () => {
+  const result = await optimizer.transform(input, 'js', { fileName: 'a.js', inputSourceMap });
+  const map = JSON.parse(result.map);
+  expect(map.file).toBe('a.js');
+  expect(map.sources).toEqual(['a.ts']);
+  expect(map.sourcesContent).toEqual(['// a\nexport const a: number = 1;\n']);
+  expect(map.mappings.startsWith('AACA')).toBe(true);
+});
+
+test('source_map/input-source-map-sync', () => {
+  const result = optimizer.transformSync(input, 'js', { inputSourceMap });
+  const map = JSON.parse(result.map);
+  expect(map.sources).toEqual(['a.ts']);
+});
+
+test('source_map/render-chunk-input-source-map', async () => {
+  const result = await optimizer.renderChunk(input, { fileName: 'chunk.js', inputSourceMap });
+  const map = JSON.parse(result.map);
+  expect(map.file).toBe('chunk.js');
+  expect(map.sources).toEqual(['a.ts']);
+  expect(map.mappings.startsWith('AACA')).toBe(true);
+});
+
+test('source_map/render-chunk-input-source-map-sync', () => {
+  const result = optimizer.renderChunkSync(input, { inputSourceMap });
+  const map = JSON.parse(result.map);
+  expect(map.sources).toEqual(['a.ts']);
+});
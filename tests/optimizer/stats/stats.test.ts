@@ -0,0 +1,36 @@
+import { expect, test } from 'bun:test';
+import { Optimizer } from '@oveo/optimizer';
+
+const input = `
+import { dedupe } from "oveo";
+const a = dedupe({ x: [1, 2, 3] });
+const b = dedupe({ x: [1, 2, 3] });
+function f() {
+  return [dedupe([1, 2, 3]), Math.PI, Math.E, document.foo_, { bar_: 1, foo_: 2 }];
+}
+`;
+
+test('stats', async () => {
+  const optimizer = new Optimizer({
+    dedupe: true,
+    globals: { include: ['js', 'web'], hoist: true },
+    renameProperties: { pattern: '_$' },
+  });
+  const moduleResult = await optimizer.transform(input, 'js');
+  const chunkResult = await optimizer.renderChunk(moduleResult.code);
+  // `{ x: [1, 2, 3] }` in `b` and `[1, 2, 3]` in `f()`, the nested array in
+  // `b` is replaced together with the enclosing object.
+  expect(chunkResult.stats.duplicatesCollapsed).toBe(2);
+  // `Math`, `Math.PI`, `Math.E` and `document`.
+  expect(chunkResult.stats.globalsHoisted).toBe(4);
+  expect(chunkResult.stats.propertiesRenamed).toBe(3);
+});
+
+test('stats/disabled', async () => {
+  const optimizer = new Optimizer({});
+  const moduleResult = await optimizer.transform(input, 'js');
+  const chunkResult = await optimizer.renderChunk(moduleResult.code);
+  expect(chunkResult.stats.duplicatesCollapsed).toBe(0);
+  expect(chunkResult.stats.globalsHoisted).toBe(0);
+  expect(chunkResult.stats.propertiesRenamed).toBe(0);
+});
@@ -0,0 +1,20 @@
+import { expect, test } from 'bun:test';
+import { Optimizer } from '@oveo/optimizer';
+
+const optimizer = new Optimizer({ hoist: true, globals: { include: ['js'], hoist: true } });
+
+test('sync/transform', async () => {
+  const input = `import { hoist } from "oveo";\nfunction test() {\n  return hoist(() => 1);\n}\n`;
+  const result = optimizer.transformSync(input, 'js');
+  expect(result.code).toBe((await optimizer.transform(input, 'js')).code);
+});
+
+test('sync/render-chunk', async () => {
+  const input = `function test() {\n  return Math.random();\n}\n`;
+  const result = optimizer.renderChunkSync(input);
+  expect(result.code).toBe((await optimizer.renderChunk(input)).code);
+});
+
+test('sync/error', () => {
+  expect(() => optimizer.transformSync('const', 'js')).toThrow();
+});
@@ -0,0 +1,21 @@
+import { expect, test } from 'bun:test';
+import { Optimizer } from '@oveo/optimizer';
+
+const input = `
+import { dedupe } from "oveo";
+export function f() {
+  dedupe([1, 2, 3]);
+  return 1;
+}
+`;
+
+// Chunk is rendered with options that don't match annotations emitted by the
+// transform phase.
+test('unmatched_annotations/expression_statement', async () => {
+  const moduleResult = await new Optimizer({ dedupe: true }).transform(input, 'js');
+  expect(moduleResult.code).toContain('__oveo__(');
+  const chunkResult = await new Optimizer({ pure: true }).renderChunk(moduleResult.code);
+  expect(chunkResult.code).toBe('export function f() {\n\treturn 1;\n}\n');
+  expect(chunkResult.code).not.toContain('void 0;');
+  expect(chunkResult.warnings).toEqual(['unable to resolve annotation at 2:1']);
+});
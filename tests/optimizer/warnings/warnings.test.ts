@@ -0,0 +1,102 @@
+import { expect, test } from 'bun:test';
+import { Optimizer } from '@oveo/optimizer';
+
+const input = `
+import { inline } from "oveo";
+const px = (v) => \`\${v}px\`;
+export function size(el, w) {
+  el.style.width = inline(px(10));
+  el.style.height = inline(px(w()));
+}
+`;
+
+test('warnings', async () => {
+  const optimizer = new Optimizer({ inline: true });
+  const moduleResult = await optimizer.transform(input, 'js');
+  const chunkResult = await optimizer.renderChunk(moduleResult.code);
+  expect(chunkResult.code).toBe(
+    'const px = (v) => `${v}px`;\n' +
+      'export function size(el, w) {\n' +
+      '\tel.style.width = `${10}px`;\n' +
+      '\tel.style.height = px(w());\n' +
+      '}\n',
+  );
+  expect(chunkResult.warnings).toEqual(['unable to inline call at 4:28']);
+});
+
+// Expression that references a local binding can't be hoisted, other
+// expressions are still hoisted.
+test('warnings/hoist', async () => {
+  const optimizer = new Optimizer({ hoist: true });
+  const moduleResult = await optimizer.transform(
+    `import { hoist } from "oveo";\n` +
+      `export function f(x) {\n` +
+      `  return [hoist(() => x), hoist(() => 1)];\n` +
+      `}\n`,
+    'js',
+  );
+  expect(moduleResult.warnings).toEqual(['unable to hoist expression at 3:16']);
+  const chunkResult = await optimizer.renderChunk(moduleResult.code);
+  expect(chunkResult.code).toBe(
+    'const _HOISTED_ = () => 1;\n' +
+      'export function f(x) {\n' +
+      '\treturn [() => x, _HOISTED_];\n' +
+      '}\n',
+  );
+  expect(chunkResult.warnings).toEqual([]);
+});
+
+test('warnings/annotations', async () => {
+  const optimizer = new Optimizer({ dedupe: true, renameProperties: {} });
+  const moduleResult = await optimizer.transform(
+    `import { key, dedupe } from "oveo";\n` +
+      `export const f = (k) => key(k);\n` +
+      `export const g = () => dedupe(function () {});\n`,
+    'js',
+  );
+  const chunkResult = await optimizer.renderChunk(moduleResult.code);
+  expect(chunkResult.code).toBe(
+    'export const f = (k) => k;\n' + 'export const g = () => function() {};\n',
+  );
+  expect(chunkResult.warnings).toEqual([
+    'unable to rename non-constant property key at 1:33',
+    'unable to dedupe expression at 2:32',
+  ]);
+});
+
+test('warnings/empty', async () => {
+  const optimizer = new Optimizer({ inline: true });
+  const chunkResult = await optimizer.renderChunk('export const a = 1;\n');
+  expect(chunkResult.warnings).toEqual([]);
+});
+
+test('warnings/on-warning', async () => {
+  const optimizer = new Optimizer({ inline: true });
+  const moduleResult = await optimizer.transform(input, 'js');
+  const warning = new Promise<string>((resolve) => {
+    optimizer.renderChunk(moduleResult.code, { onWarning: resolve });
+  });
+  expect(await warning).toBe('unable to inline call at 4:28');
+});
+
+test('warnings/render-chunks', async () => {
+  const optimizer = new Optimizer({ inline: true });
+  const moduleResult = await optimizer.transform(input, 'js');
+  const sources = ['export const a = 1;\n', moduleResult.code];
+  const warning = new Promise<{ index: number; message: string }>((resolve) => {
+    optimizer.renderChunks(sources, undefined, resolve);
+  });
+  expect(await warning).toEqual({ index: 1, message: 'unable to inline call at 4:28' });
+
+  const rendered: { index: number; warnings: string[] }[] = [];
+  const done = new Promise<void>((resolve) => {
+    optimizer.renderChunks(sources, (chunk) => {
+      rendered.push(chunk);
+      if (rendered.length === 2) {
+        resolve();
+      }
+    });
+  });
+  await done;
+  expect(rendered.map((chunk) => chunk.warnings)).toEqual([[], ['unable to inline call at 4:28']]);
+});